@@ -26,6 +26,9 @@ use std::time::Instant;
 
 use clap::{App, Arg};
 
+use rust_graph::generic::{DefaultId, Directed, GraphType, Undirected};
+use rust_graph::graph_impl::static_graph::StaticGraphBuilder;
+use rust_graph::io::csv::CSVReader;
 use rust_graph::io::read_from_csv;
 use rust_graph::io::serde::Serialize;
 use rust_graph::{DiGraphMap, UnGraphMap};
@@ -93,7 +96,21 @@ fn main() {
 
     let start = Instant::now();
 
-    if is_directed {
+    if !reorder_node_id && !reorder_label_id {
+        // Without reordering, the graph is built directly, instead of through `GraphMap`.
+        let reader = match separator {
+            Some(sep) => CSVReader::with_separator(node_file, edge_file, sep),
+            None => CSVReader::new(node_file, edge_file),
+        }
+        .headers(has_headers)
+        .flexible(is_flexible);
+
+        if is_directed {
+            build_static::<Directed>(&reader, out_file)
+        } else {
+            build_static::<Undirected>(&reader, out_file)
+        }
+    } else if is_directed {
         let mut g = DiGraphMap::<String, String>::new();
         println!("Reading graph");
         read_from_csv(
@@ -141,3 +158,18 @@ fn main() {
         duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1e-9
     );
 }
+
+fn build_static<Ty: GraphType>(reader: &CSVReader<DefaultId, String, String>, out_file: &Path) {
+    let mut builder = StaticGraphBuilder::<DefaultId, String, String, Ty>::new();
+
+    println!("Reading graph");
+    builder.add_nodes(reader.node_iter().expect("Error when loading csv"));
+    builder
+        .add_edges(reader.edge_iter().expect("Error when loading csv"))
+        .expect("Error when sorting edges");
+
+    println!("Building graph");
+    let static_graph = builder.build().expect("Error when building graph");
+
+    static_graph.export(out_file).unwrap()
+}
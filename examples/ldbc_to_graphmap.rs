@@ -36,7 +36,7 @@ fn main() {
     let start = Instant::now();
 
     println!("Loading {:?}", &ldbc_dir);
-    let g = read_ldbc_from_path::<DefaultId, Undirected, _>(ldbc_dir);
+    let g = read_ldbc_from_path::<DefaultId, Undirected, _>(ldbc_dir).expect("Read LDBC error");
     let num_of_nodes = g.node_count();
     let num_of_edges = g.edge_count();
 
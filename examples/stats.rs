@@ -22,6 +22,7 @@ extern crate rust_graph;
 
 use std::time::Instant;
 
+use rust_graph::algorithm::TriangleCount;
 use rust_graph::io::serde::Deserialize;
 use rust_graph::prelude::*;
 use rust_graph::{UnGraphMap, UnStaticGraph};
@@ -41,6 +42,18 @@ fn main() {
 
         println!("Max degree: {}", max_degree);
 
+        let triangles = TriangleCount::from_edge_vec(g.get_edge_vec());
+
+        println!("Triangles: {}", triangles.get_count());
+        println!(
+            "Average clustering coefficient: {}",
+            triangles.average_clustering()
+        );
+        println!(
+            "Global clustering coefficient: {}",
+            triangles.global_clustering()
+        );
+
         let node_labels_counter = g.get_node_label_counter();
         let edge_labels_counter = g.get_edge_label_counter();
 
@@ -24,6 +24,17 @@ use fixedbitset::FixedBitSet;
 
 use prelude::*;
 
+/// An event of the traversal returned by `Dfs::next_event`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent<Id: IdType> {
+    /// The node is visited for the first time
+    Discover(Id),
+    /// The edge `(from, to)` leads back to `to`, which is being visited, i.e. it closes a cycle
+    BackEdge(Id, Id),
+    /// All the nodes reachable from the node have been visited
+    Finish(Id),
+}
+
 /// A depth first search (Dfs) of a graph.
 ///
 /// The traversal starts at a given node and only traverses nodes reachable
@@ -31,6 +42,10 @@ use prelude::*;
 ///
 /// `Dfs` is not recursive.
 ///
+/// Besides iterating over the nodes, `next_event` reports when each node is discovered and
+/// finished, and the edges back to the nodes that are not finished yet, in a proper depth first
+/// order. The two ways of traversal should not be mixed on the same `Dfs`.
+///
 /// Example:
 ///
 /// ```
@@ -62,6 +77,15 @@ pub struct Dfs<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType
     discovered: FixedBitSet,
     /// The reference to the graph that algorithm is running on
     graph: &'a GeneralGraph<Id, NL, EL, L>,
+    /// The nodes being visited by `next_event`, each with the height of the stack
+    /// below its neighbors
+    path: Vec<(Id, usize)>,
+    /// The map of nodes on `path`
+    on_path: FixedBitSet,
+    /// The map of nodes finished by `next_event`
+    finished: FixedBitSet,
+    /// The back edges of the last discovered node, yet to be returned by `next_event`
+    back_edges: Vec<(Id, Id)>,
 }
 
 impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType> Dfs<'a, Id, NL, EL, L> {
@@ -79,23 +103,33 @@ impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType> Dfs<'a,
         discovered: FixedBitSet,
         graph: &'a G,
     ) -> Self {
+        let capacity = discovered.len();
+
         Dfs {
             stack,
             discovered,
             graph,
+            path: Vec::new(),
+            on_path: FixedBitSet::with_capacity(capacity),
+            finished: FixedBitSet::with_capacity(capacity),
+            back_edges: Vec::new(),
         }
     }
 
     /// Create a new **Dfs**.
     pub fn with_capacity<G: GeneralGraph<Id, NL, EL, L>>(graph: &'a G) -> Self {
-        let mut discovered: FixedBitSet =
-            FixedBitSet::with_capacity(graph.max_seen_id().unwrap().id() + 1);
+        let capacity = graph.max_seen_id().unwrap().id() + 1;
+        let mut discovered: FixedBitSet = FixedBitSet::with_capacity(capacity);
         discovered.insert_range(..);
 
         Dfs {
             stack: Vec::new(),
             discovered,
             graph,
+            path: Vec::new(),
+            on_path: FixedBitSet::with_capacity(capacity),
+            finished: FixedBitSet::with_capacity(capacity),
+            back_edges: Vec::new(),
         }
     }
 
@@ -104,6 +138,10 @@ impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType> Dfs<'a,
         self.discovered.clear();
         self.stack.clear();
         self.discovered.insert_range(..);
+        self.path.clear();
+        self.on_path.clear();
+        self.finished.clear();
+        self.back_edges.clear();
     }
 
     /// Return the next node in the Dfs, or **None** if the traversal is done.
@@ -128,6 +166,58 @@ impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType> Dfs<'a,
         }
     }
 
+    /// Return the next event in the Dfs, or **None** if the traversal is done.
+    ///
+    /// Unlike `next`, a node is only discovered when it is popped from the stack, so a node
+    /// pushed earlier is still visited from the node that reaches it first in depth first order,
+    /// and the nodes are finished in the order that a recursive search would finish them.
+    pub fn next_event(&mut self) -> Option<DfsEvent<Id>> {
+        if let Some((from, to)) = self.back_edges.pop() {
+            return Some(DfsEvent::BackEdge(from, to));
+        }
+
+        loop {
+            if let Some(&(node, height)) = self.path.last() {
+                if height == self.stack.len() {
+                    self.path.pop();
+                    self.on_path.set(node.id(), false);
+                    self.finished.insert(node.id());
+                    return Some(DfsEvent::Finish(node));
+                }
+            }
+
+            if self.stack.is_empty() {
+                match self.next_unvisited_node() {
+                    Some(id) => {
+                        self.stack.push(id);
+                        self.discovered.set(id.id(), false);
+                    }
+                    None => return None,
+                }
+            }
+
+            let current_node = self.stack.pop().unwrap();
+            if self.finished.contains(current_node.id()) {
+                continue;
+            }
+
+            self.path.push((current_node, self.stack.len()));
+            self.on_path.insert(current_node.id());
+
+            for neighbour in self.graph.neighbors_iter(current_node) {
+                if self.on_path.contains(neighbour.id()) {
+                    self.back_edges.push((current_node, neighbour));
+                } else if !self.finished.contains(neighbour.id()) {
+                    self.discovered.set(neighbour.id(), false);
+                    self.stack.push(neighbour);
+                }
+            }
+            self.back_edges.reverse();
+
+            return Some(DfsEvent::Discover(current_node));
+        }
+    }
+
     /// Randomly pick a unvisited node from the map.
     fn next_unvisited_node(&self) -> Option<Id> {
         for node in self.discovered.ones() {
@@ -24,10 +24,25 @@ pub mod conn_subgraphs;
 pub mod dfs;
 pub mod graph_minus;
 pub mod graph_union;
+pub mod page_rank;
+pub mod shortest_path;
+pub mod strongly_conn_comp;
+pub mod subgraph_matching;
+pub mod topo_sort;
+pub mod triangle_count;
 
 pub use algorithm::bfs::Bfs;
 pub use algorithm::conn_comp::ConnComp;
 pub use algorithm::conn_subgraphs::ConnSubgraph;
-pub use algorithm::dfs::Dfs;
+pub use algorithm::dfs::{Dfs, DfsEvent};
 pub use algorithm::graph_minus::graph_minus;
 pub use algorithm::graph_union::graph_union;
+pub use algorithm::page_rank::PageRank;
+pub use algorithm::shortest_path::ShortestPath;
+pub use algorithm::strongly_conn_comp::StronglyConnComp;
+pub use algorithm::subgraph_matching::SubgraphMatcher;
+pub use algorithm::topo_sort::{is_acyclic, topological_sort, topological_sort_dfs};
+pub use algorithm::triangle_count::TriangleCount;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
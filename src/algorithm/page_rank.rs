@@ -0,0 +1,201 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use generic::IdType;
+use graph_impl::static_graph::AdjacencyTrait;
+
+/// PageRank of a graph stored in CSR format.
+///
+/// The computation walks the neighbors of an `AdjacencyTrait`, so it runs on `StaticGraph`
+/// (compressed or not) as well as on `StaticGraphMmap` without materialising the graph.
+/// For a directed graph, the in-edges are required by `run` to pull the scores along the edge
+/// direction; an undirected graph stores each edge in both directions, so `run_undirected`
+/// uses its edges as in-edges.
+///
+/// Scores are updated until the L1 distance of two consecutive iterations is below the
+/// tolerance, or the maximum number of iterations is reached. The probability mass of nodes
+/// without out-edges is redistributed according to the personalization vector, which is
+/// uniform unless given.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::algorithm::PageRank;
+/// use rust_graph::graph_impl::EdgeVec;
+/// use rust_graph::prelude::*;
+/// use rust_graph::DiStaticGraph;
+///
+/// let edge_vec = EdgeVec::new(vec![0, 1, 2, 3], vec![1, 2, 0]);
+/// let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 3], vec![2, 0, 1]);
+/// let graph = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+///
+/// let in_edge_vec = graph.get_in_edge_vec().as_ref().unwrap();
+/// let ranks = PageRank::new().run(graph.get_edge_vec(), in_edge_vec);
+///
+/// for rank in ranks {
+///     assert!((rank - 1.0 / 3.0).abs() < 1e-6);
+/// }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct PageRank {
+    /// The probability of following an edge rather than jumping
+    damping: f64,
+    /// The L1 distance between two iterations under which the scores are converged
+    tolerance: f64,
+    /// The maximum number of iterations
+    max_iterations: usize,
+    /// The jumping probability of each node, indexed by node id
+    personalization: Option<Vec<f64>>,
+}
+
+impl Default for PageRank {
+    fn default() -> Self {
+        PageRank {
+            damping: 0.85,
+            tolerance: 1e-6,
+            max_iterations: 100,
+            personalization: None,
+        }
+    }
+}
+
+impl PageRank {
+    /// Create a new **PageRank** with damping 0.85, tolerance 1e-6 and at most 100 iterations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn damping(mut self, damping: f64) -> Self {
+        if !(0.0..=1.0).contains(&damping) {
+            panic!("Invalid damping factor {}.", damping);
+        }
+        self.damping = damping;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the personalization vector, indexed by node id. It does not need to be normalized,
+    /// but must have non-negative values and a positive sum.
+    pub fn personalization(mut self, personalization: Vec<f64>) -> Self {
+        self.personalization = Some(personalization);
+        self
+    }
+
+    /// Compute the scores of all nodes of a directed graph, indexed by node id.
+    /// **in_edges** are the edges of **edges** in the reverse direction.
+    pub fn run<Id: IdType, L: IdType, E: AdjacencyTrait<Id, L>>(
+        &self,
+        edges: &E,
+        in_edges: &E,
+    ) -> Vec<f64> {
+        let num_nodes = edges.num_nodes();
+        if num_nodes == 0 {
+            return Vec::new();
+        }
+
+        if in_edges.num_nodes() != num_nodes || in_edges.num_edges() != edges.num_edges() {
+            panic!(
+                "{} nodes and {} in-edges do not match {} nodes and {} edges.",
+                in_edges.num_nodes(),
+                in_edges.num_edges(),
+                num_nodes,
+                edges.num_edges()
+            );
+        }
+
+        let jump = self.normalized_personalization(num_nodes);
+
+        let mut ranks = jump.clone();
+        let mut next_ranks = vec![0.0; num_nodes];
+
+        for i in 0..self.max_iterations {
+            let dangling: f64 = (0..num_nodes)
+                .filter(|&n| edges.degree(Id::new(n)) == 0)
+                .map(|n| ranks[n])
+                .sum();
+
+            let mut diff = 0.0;
+            for n in 0..num_nodes {
+                let pulled: f64 = in_edges
+                    .neighbors_iter(Id::new(n))
+                    .map(|nbr| ranks[nbr.id()] / edges.degree(nbr) as f64)
+                    .sum();
+                next_ranks[n] =
+                    self.damping * (pulled + dangling * jump[n]) + (1.0 - self.damping) * jump[n];
+                diff += (next_ranks[n] - ranks[n]).abs();
+            }
+
+            ::std::mem::swap(&mut ranks, &mut next_ranks);
+
+            if diff < self.tolerance {
+                debug!("PageRank converged after {} iterations", i + 1);
+                return ranks;
+            }
+        }
+
+        warn!(
+            "PageRank did not converge within {} iterations",
+            self.max_iterations
+        );
+
+        ranks
+    }
+
+    /// Compute the scores of all nodes of an undirected graph, indexed by node id.
+    /// Each edge of **edges** must be stored in both directions.
+    pub fn run_undirected<Id: IdType, L: IdType, E: AdjacencyTrait<Id, L>>(
+        &self,
+        edges: &E,
+    ) -> Vec<f64> {
+        self.run(edges, edges)
+    }
+
+    fn normalized_personalization(&self, num_nodes: usize) -> Vec<f64> {
+        match self.personalization {
+            Some(ref personalization) => {
+                if personalization.len() != num_nodes {
+                    panic!(
+                        "Unequal length: there are {} nodes, but {} personalization values",
+                        num_nodes,
+                        personalization.len()
+                    );
+                }
+
+                let sum: f64 = personalization.iter().sum();
+                if personalization.iter().any(|&x| x < 0.0) || sum <= 0.0 {
+                    panic!("Personalization values must be non-negative with a positive sum.");
+                }
+
+                personalization.iter().map(|&x| x / sum).collect()
+            }
+            None => vec![1.0 / num_nodes as f64; num_nodes],
+        }
+    }
+}
@@ -0,0 +1,271 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Parallel algorithms over graphs in CSR format, enabled by the `parallel` feature.
+//!
+//! The algorithms work directly on the neighbors of an `AdjacencyTrait` (e.g. the `EdgeVec`
+//! or `CompressedEdgeVec` of a `StaticGraph`, or the `EdgeVecMmap` of a `StaticGraphMmap`) and use
+//! [rayon](https://github.com/rayon-rs/rayon) to spread the nodes over all available threads.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use algorithm::triangle_count::{greater_than, merge_intersect};
+use algorithm::TriangleCount;
+use generic::IdType;
+use graph_impl::static_graph::AdjacencyTrait;
+
+/// Statistics of the degrees of a graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegreeStats {
+    pub num_nodes: usize,
+    pub num_edges: usize,
+    pub min_degree: usize,
+    pub max_degree: usize,
+    pub avg_degree: f64,
+    /// The number of nodes of each degree, indexed by degree
+    pub distribution: Vec<usize>,
+}
+
+/// Compute the degree statistics of a graph in parallel.
+pub fn par_degree_stats<Id, L, E>(edges: &E) -> DegreeStats
+where
+    Id: IdType,
+    L: IdType,
+    E: AdjacencyTrait<Id, L> + Sync,
+{
+    let num_nodes = edges.num_nodes();
+    let degrees: Vec<usize> = (0..num_nodes)
+        .into_par_iter()
+        .map(|n| edges.degree(Id::new(n)))
+        .collect();
+
+    let max_degree = degrees.par_iter().cloned().max().unwrap_or(0);
+    let min_degree = degrees.par_iter().cloned().min().unwrap_or(0);
+
+    let distribution = degrees
+        .par_iter()
+        .fold(
+            || vec![0; max_degree + 1],
+            |mut counts, &degree| {
+                counts[degree] += 1;
+                counts
+            },
+        ).reduce(
+            || vec![0; max_degree + 1],
+            |mut counts, other| {
+                for (count, other) in counts.iter_mut().zip(other) {
+                    *count += other;
+                }
+                counts
+            },
+        );
+
+    let num_edges = edges.num_edges();
+    let avg_degree = if num_nodes == 0 {
+        0.0
+    } else {
+        num_edges as f64 / num_nodes as f64
+    };
+
+    DegreeStats {
+        num_nodes,
+        num_edges,
+        min_degree,
+        max_degree,
+        avg_degree,
+        distribution,
+    }
+}
+
+/// Count the triangles of an undirected graph in parallel.
+/// See `TriangleCount::from_edge_vec` for the requirements on **edges**.
+pub fn par_triangle_count<Id, L, E>(edges: &E) -> TriangleCount<Id>
+where
+    Id: IdType,
+    L: IdType,
+    E: AdjacencyTrait<Id, L> + Sync,
+{
+    let num_nodes = edges.num_nodes();
+    let node_counts: Vec<AtomicUsize> = (0..num_nodes).map(|_| AtomicUsize::new(0)).collect();
+
+    let count = (0..num_nodes)
+        .into_par_iter()
+        .map(|u| {
+            let u = Id::new(u);
+            let u_nbrs = edges.neighbors(u);
+            let mut count = 0;
+
+            for &v in greater_than(&u_nbrs, u) {
+                let common = merge_intersect(
+                    greater_than(&u_nbrs, v).iter().cloned(),
+                    edges.neighbors_iter(v).filter(|&w| w > v),
+                );
+
+                if !common.is_empty() {
+                    node_counts[u.id()].fetch_add(common.len(), Ordering::Relaxed);
+                    node_counts[v.id()].fetch_add(common.len(), Ordering::Relaxed);
+                    for w in &common {
+                        node_counts[w.id()].fetch_add(1, Ordering::Relaxed);
+                    }
+                    count += common.len();
+                }
+            }
+
+            count
+        }).sum();
+
+    let degrees = (0..num_nodes)
+        .into_par_iter()
+        .map(|u| {
+            let u = Id::new(u);
+            edges.neighbors_iter(u).filter(|&v| v != u).count()
+        }).collect();
+
+    TriangleCount::from_parts(
+        count,
+        (0..num_nodes).map(Id::new).collect(),
+        node_counts.into_iter().map(|c| c.into_inner()).collect(),
+        degrees,
+    )
+}
+
+/// Level-synchronous breadth first search from **source** in parallel.
+///
+/// Every level expands the current frontier in parallel, and a node joins the next frontier
+/// if it is the first one to claim it. Return the number of hops from **source** to each node,
+/// indexed by node id, where unreachable nodes have `None`.
+pub fn par_bfs<Id, L, E>(edges: &E, source: Id) -> Vec<Option<usize>>
+where
+    Id: IdType,
+    L: IdType,
+    E: AdjacencyTrait<Id, L> + Sync,
+{
+    let num_nodes = edges.num_nodes();
+    if !edges.has_node(source) {
+        panic!("Node {:?} is not in the graph.", source);
+    }
+
+    let distances: Vec<AtomicUsize> = (0..num_nodes)
+        .map(|_| AtomicUsize::new(usize::MAX))
+        .collect();
+    distances[source.id()].store(0, Ordering::Relaxed);
+
+    let mut frontier = vec![source];
+    let mut level = 0;
+
+    while !frontier.is_empty() {
+        level += 1;
+        frontier = frontier
+            .par_iter()
+            .fold(Vec::new, |mut next, &node| {
+                for neighbor in edges.neighbors_iter(node) {
+                    if distances[neighbor.id()]
+                        .compare_exchange(usize::MAX, level, Ordering::Relaxed, Ordering::Relaxed)
+                        .is_ok()
+                    {
+                        next.push(neighbor);
+                    }
+                }
+                next
+            }).reduce(Vec::new, |mut next, mut other| {
+                next.append(&mut other);
+                next
+            });
+    }
+
+    distances
+        .into_iter()
+        .map(|d| match d.into_inner() {
+            usize::MAX => None,
+            d => Some(d),
+        }).collect()
+}
+
+/// Detect the connected components of a graph in parallel, treating every edge as undirected.
+///
+/// The components are merged by a lock-free union-find over all edges, which always links
+/// the larger root to the smaller one. Return the root of each node, indexed by node id,
+/// which is the smallest node id of its component.
+pub fn par_conn_comp<Id, L, E>(edges: &E) -> Vec<Id>
+where
+    Id: IdType,
+    L: IdType,
+    E: AdjacencyTrait<Id, L> + Sync,
+{
+    let num_nodes = edges.num_nodes();
+    let parents: Vec<AtomicUsize> = (0..num_nodes).map(AtomicUsize::new).collect();
+
+    (0..num_nodes).into_par_iter().for_each(|u| {
+        for v in edges.neighbors_iter(Id::new(u)) {
+            union(&parents, u, v.id());
+        }
+    });
+
+    (0..num_nodes)
+        .into_par_iter()
+        .map(|n| Id::new(find(&parents, n)))
+        .collect()
+}
+
+/// Count the components found by `par_conn_comp`.
+pub fn count_components<Id: IdType>(roots: &[Id]) -> usize {
+    roots
+        .par_iter()
+        .enumerate()
+        .filter(|&(n, root)| root.id() == n)
+        .count()
+}
+
+// A parent only ever moves to a smaller id, so relaxed loads never miss a root for good.
+fn find(parents: &[AtomicUsize], mut node: usize) -> usize {
+    loop {
+        let parent = parents[node].load(Ordering::Relaxed);
+        if parent == node {
+            return node;
+        }
+
+        let grandparent = parents[parent].load(Ordering::Relaxed);
+        let _ = parents[node].compare_exchange(
+            parent,
+            grandparent,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+        node = grandparent;
+    }
+}
+
+fn union(parents: &[AtomicUsize], u: usize, v: usize) {
+    loop {
+        let (ru, rv) = (find(parents, u), find(parents, v));
+        if ru == rv {
+            return;
+        }
+
+        let (high, low) = if ru > rv { (ru, rv) } else { (rv, ru) };
+        if parents[high]
+            .compare_exchange(high, low, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            return;
+        }
+    }
+}
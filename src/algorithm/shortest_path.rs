@@ -0,0 +1,303 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use generic::EdgeType;
+use prelude::*;
+
+/// Single-source shortest paths of a graph.
+///
+/// The result holds, for every node reachable from the source, its distance to the source
+/// and its predecessor on one shortest path, from which the path itself can be reconstructed.
+/// Directed graphs are traversed along the edge direction, undirected graphs in both directions.
+///
+/// Three algorithms are provided:
+/// * `bfs` computes the number of hops, ignoring any edge weight;
+/// * `dijkstra` takes a weight function over `EdgeType`, whose values must be non-negative;
+/// * `bellman_ford` takes the same weight function and allows negative weights.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::DiGraphMap;
+/// use rust_graph::algorithm::ShortestPath;
+///
+/// let mut graph = DiGraphMap::<Void, u32>::new();
+///
+/// graph.add_edge(0, 1, Some(4));
+/// graph.add_edge(0, 2, Some(1));
+/// graph.add_edge(2, 1, Some(2));
+///
+/// let edge_labels = graph.get_edge_label_map().clone();
+/// let sp = ShortestPath::dijkstra(&graph, 0, |e| {
+///     *edge_labels.get_item(e.get_label_id().unwrap().id()).unwrap()
+/// });
+///
+/// assert_eq!(sp.distance(1), Some(3));
+/// assert_eq!(sp.path_to(1), Some(vec![0, 2, 1]));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct ShortestPath<Id: IdType, W = usize> {
+    /// The source node
+    source: Id,
+    /// The distance from the source to each reachable node
+    distances: HashMap<Id, W>,
+    /// The predecessor of each reachable node except the source
+    predecessors: HashMap<Id, Id>,
+}
+
+impl<Id: IdType> ShortestPath<Id, usize> {
+    /// Compute the hop distance from **source** to every reachable node by a breadth first search.
+    pub fn bfs<NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+        source: Id,
+    ) -> Self {
+        let mut sp = ShortestPath::empty(graph, source);
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+
+        while let Some(current) = queue.pop_front() {
+            let dist = sp.distances[&current] + 1;
+            for neighbor in graph.neighbors_iter(current) {
+                if let Entry::Vacant(entry) = sp.distances.entry(neighbor) {
+                    entry.insert(dist);
+                    sp.predecessors.insert(neighbor, current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        sp
+    }
+}
+
+impl<Id: IdType, W: Copy + PartialOrd + Add<Output = W> + Default> ShortestPath<Id, W> {
+    /// Compute the shortest paths from **source** using Dijkstra's algorithm, where the weight
+    /// of each edge is given by **weight**. `W::default()` is taken as the zero distance.
+    ///
+    /// The weights must be non-negative, otherwise the result is unspecified.
+    pub fn dijkstra<NL, EL, L, F>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+        source: Id,
+        mut weight: F,
+    ) -> Self
+    where
+        NL: Eq + Hash,
+        EL: Eq + Hash,
+        L: IdType,
+        F: FnMut(&EdgeType<Id, L>) -> W,
+    {
+        let mut sp = ShortestPath::empty(graph, source);
+        let mut visited = HashSet::new();
+        let mut heap = BinaryHeap::new();
+        heap.push(MinScored(W::default(), source));
+
+        while let Some(MinScored(dist, current)) = heap.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+
+            for neighbor in graph.neighbors_iter(current) {
+                if visited.contains(&neighbor) {
+                    continue;
+                }
+
+                let next_dist = dist + weight(&graph.get_edge(current, neighbor));
+                if sp.relax(current, neighbor, next_dist) {
+                    heap.push(MinScored(next_dist, neighbor));
+                }
+            }
+        }
+
+        sp
+    }
+
+    /// Compute the shortest paths from **source** using the Bellman-Ford algorithm, where the
+    /// weight of each edge is given by **weight**. `W::default()` is taken as the zero distance.
+    ///
+    /// Negative weights are allowed. Return `None` if a negative cycle is reachable
+    /// from **source**. Note that in an undirected graph a negative edge is such a cycle.
+    pub fn bellman_ford<NL, EL, L, F>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+        source: Id,
+        mut weight: F,
+    ) -> Option<Self>
+    where
+        NL: Eq + Hash,
+        EL: Eq + Hash,
+        L: IdType,
+        F: FnMut(&EdgeType<Id, L>) -> W,
+    {
+        let mut sp = ShortestPath::empty(graph, source);
+        let is_directed = graph.is_directed();
+
+        let mut edges = Vec::with_capacity(graph.edge_count());
+        for edge in graph.edges() {
+            let w = weight(&edge);
+            edges.push((edge.get_start(), edge.get_target(), w));
+            if !is_directed {
+                edges.push((edge.get_target(), edge.get_start(), w));
+            }
+        }
+
+        for _ in 1..graph.node_count() {
+            let mut updated = false;
+            for &(start, target, w) in &edges {
+                if let Some(&dist) = sp.distances.get(&start) {
+                    updated |= sp.relax(start, target, dist + w);
+                }
+            }
+            if !updated {
+                return Some(sp);
+            }
+        }
+
+        for &(start, target, w) in &edges {
+            if let Some(&dist) = sp.distances.get(&start) {
+                if sp.relax(start, target, dist + w) {
+                    return None;
+                }
+            }
+        }
+
+        Some(sp)
+    }
+
+    /// Record **dist** as the distance of **target** via **start** if it is shorter than
+    /// the current one.
+    fn relax(&mut self, start: Id, target: Id, dist: W) -> bool {
+        let shorter = match self.distances.get(&target) {
+            Some(&current) => dist < current,
+            None => true,
+        };
+
+        if shorter {
+            self.distances.insert(target, dist);
+            self.predecessors.insert(target, start);
+        }
+
+        shorter
+    }
+}
+
+impl<Id: IdType, W: Copy + Default> ShortestPath<Id, W> {
+    fn empty<NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+        source: Id,
+    ) -> Self {
+        if !graph.has_node(source) {
+            panic!("Starting node doesn't exist on graph")
+        }
+
+        let mut distances = HashMap::new();
+        distances.insert(source, W::default());
+
+        ShortestPath {
+            source,
+            distances,
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Return the source node.
+    pub fn source(&self) -> Id {
+        self.source
+    }
+
+    /// Return the distance from the source to **id**, or `None` if it is unreachable.
+    pub fn distance(&self, id: Id) -> Option<W> {
+        self.distances.get(&id).cloned()
+    }
+
+    /// Return the predecessor of **id** on the shortest path, or `None` if **id** is the
+    /// source or unreachable.
+    pub fn predecessor(&self, id: Id) -> Option<Id> {
+        self.predecessors.get(&id).cloned()
+    }
+
+    /// Return whether **id** is reachable from the source.
+    pub fn is_reachable(&self, id: Id) -> bool {
+        self.distances.contains_key(&id)
+    }
+
+    /// Return the map of distances of all reachable nodes.
+    pub fn distances(&self) -> &HashMap<Id, W> {
+        &self.distances
+    }
+
+    /// Return the map of predecessors of all reachable nodes except the source.
+    pub fn predecessors(&self) -> &HashMap<Id, Id> {
+        &self.predecessors
+    }
+
+    /// Reconstruct the shortest path from the source to **target**, both included.
+    /// Return `None` if **target** is unreachable.
+    pub fn path_to(&self, target: Id) -> Option<Vec<Id>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(&pred) = self.predecessors.get(&current) {
+            path.push(pred);
+            current = pred;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Return the distance and predecessor maps.
+    pub fn into_result(self) -> (HashMap<Id, W>, HashMap<Id, Id>) {
+        (self.distances, self.predecessors)
+    }
+}
+
+/// A pair of distance and node, ordered by the distance only and reversed,
+/// so that `BinaryHeap` pops the nearest node first.
+struct MinScored<W, Id>(W, Id);
+
+impl<W: PartialOrd, Id> PartialEq for MinScored<W, Id> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd, Id> Eq for MinScored<W, Id> {}
+
+impl<W: PartialOrd, Id> PartialOrd for MinScored<W, Id> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd, Id> Ord for MinScored<W, Id> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
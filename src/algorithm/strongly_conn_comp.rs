@@ -0,0 +1,214 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+
+use graph_impl::graph_map::TypedDiGraphMap;
+use prelude::*;
+
+/// Detection of Strongly Connected Components (StronglyConnComp) of a directed graph.
+///
+/// `StronglyConnComp` implements Kosaraju's algorithm and is not recursive.
+/// A first depth first search along the out-edges records the order in which nodes finish.
+/// A second search along the in-edges (`DiGraphTrait::in_neighbors_iter`), starting from nodes
+/// in reverse finishing order, then collects one component per tree.
+///
+/// Components are numbered from 0 in a topological order of the condensation: an edge between
+/// two components always goes from the smaller id to the larger one.
+///
+/// Like `ConnComp`, `StronglyConnComp` does not itself borrow the graph.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::DiGraphMap;
+/// use rust_graph::algorithm::StronglyConnComp;
+///
+/// let mut graph = DiGraphMap::<Void>::new();
+///
+/// graph.add_edge(0, 1, None);
+/// graph.add_edge(1, 0, None);
+/// graph.add_edge(1, 2, None);
+///
+/// let scc = StronglyConnComp::new(&graph);
+///
+/// assert_eq!(scc.get_count(), 2);
+/// assert!(scc.is_strongly_connected(0, 1));
+/// assert!(!scc.is_strongly_connected(1, 2));
+///
+/// let dag = scc.condensation(&graph);
+/// let c0 = scc.get_component(0).unwrap();
+/// let c2 = scc.get_component(2).unwrap();
+/// assert!(dag.has_edge(DefaultId::new(c0), DefaultId::new(c2)));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct StronglyConnComp<Id: IdType> {
+    /// The component id of each node
+    components: HashMap<Id, usize>,
+    /// The number of strongly connected components found
+    count: usize,
+}
+
+impl<Id: IdType> StronglyConnComp<Id> {
+    /// Create a new **StronglyConnComp** and run the detection on the given directed graph.
+    pub fn new<NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+    ) -> Self {
+        let digraph = match graph.as_digraph() {
+            Some(digraph) => digraph,
+            None => panic!("Strongly connected components are only defined on directed graphs."),
+        };
+
+        let mut scc = StronglyConnComp {
+            components: HashMap::with_capacity(graph.node_count()),
+            count: 0,
+        };
+
+        let finished = Self::finishing_order(graph);
+        let capacity = graph.max_seen_id().map_or(0, |id| id.id() + 1);
+        let mut discovered = FixedBitSet::with_capacity(capacity);
+        let mut stack = Vec::new();
+
+        for &root in finished.iter().rev() {
+            if discovered.contains(root.id()) {
+                continue;
+            }
+
+            discovered.insert(root.id());
+            stack.push(root);
+
+            while let Some(node) = stack.pop() {
+                scc.components.insert(node, scc.count);
+                for in_neighbor in digraph.in_neighbors_iter(node) {
+                    if !discovered.contains(in_neighbor.id()) {
+                        discovered.insert(in_neighbor.id());
+                        stack.push(in_neighbor);
+                    }
+                }
+            }
+
+            scc.count += 1;
+        }
+
+        scc
+    }
+
+    /// Return the nodes in the order their depth first search along the out-edges finishes.
+    fn finishing_order<NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+        graph: &GeneralGraph<Id, NL, EL, L>,
+    ) -> Vec<Id> {
+        let capacity = graph.max_seen_id().map_or(0, |id| id.id() + 1);
+        let mut discovered = FixedBitSet::with_capacity(capacity);
+        let mut finished = Vec::with_capacity(graph.node_count());
+        let mut stack: Vec<(Id, Iter<Id>)> = Vec::new();
+
+        for root in graph.node_indices() {
+            if discovered.contains(root.id()) {
+                continue;
+            }
+
+            discovered.insert(root.id());
+            stack.push((root, graph.neighbors_iter(root)));
+
+            while !stack.is_empty() {
+                let next = stack
+                    .last_mut()
+                    .unwrap()
+                    .1
+                    .find(|n| !discovered.contains(n.id()));
+
+                match next {
+                    Some(node) => {
+                        discovered.insert(node.id());
+                        stack.push((node, graph.neighbors_iter(node)));
+                    }
+                    None => {
+                        let (node, _) = stack.pop().unwrap();
+                        finished.push(node);
+                    }
+                }
+            }
+        }
+
+        finished
+    }
+
+    /// Get the number of strongly connected components.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the component id of a node.
+    pub fn get_component(&self, node: Id) -> Option<usize> {
+        self.components.get(&node).cloned()
+    }
+
+    /// Get the map of each node to its component id.
+    pub fn get_component_map(&self) -> &HashMap<Id, usize> {
+        &self.components
+    }
+
+    /// Check if two nodes belong to the same strongly connected component.
+    pub fn is_strongly_connected(&self, node0: Id, node1: Id) -> bool {
+        match (self.get_component(node0), self.get_component(node1)) {
+            (Some(c0), Some(c1)) => c0 == c1,
+            _ => false,
+        }
+    }
+
+    /// Get the nodes of every component, indexed by component id.
+    pub fn get_components(&self) -> Vec<Vec<Id>> {
+        let mut components = vec![Vec::new(); self.count];
+        for (&node, &component) in &self.components {
+            components[component].push(node);
+        }
+
+        components
+    }
+
+    /// Build the condensation of the graph, i.e. the DAG with one node per component, whose id
+    /// is the component id, and an edge between two components if any edge of the graph
+    /// goes from one to the other.
+    pub fn condensation<NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+        &self,
+        graph: &GeneralGraph<Id, NL, EL, L>,
+    ) -> TypedDiGraphMap<Id, Void> {
+        let mut dag = TypedDiGraphMap::new();
+
+        for component in 0..self.count {
+            dag.add_node(Id::new(component), None);
+        }
+
+        for (start, target) in graph.edge_indices() {
+            let c0 = self.components[&start];
+            let c1 = self.components[&target];
+            if c0 != c1 {
+                dag.add_edge(Id::new(c0), Id::new(c1), None);
+            }
+        }
+
+        dag
+    }
+}
@@ -0,0 +1,432 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+
+use prelude::*;
+
+/// Labeled subgraph matching of a query graph against a data graph.
+///
+/// `SubgraphMatcher` is a backtracking search in the spirit of VF2/Ullmann and is not recursive.
+/// The candidates of each query node are first filtered by node label and degree. Query nodes are
+/// then matched in an order that starts from the node with the fewest candidates and prefers
+/// nodes adjacent to the matched ones, so that most candidates come from the neighbors of an
+/// already matched data node. Every query edge is checked against the data graph, including its
+/// direction for directed graphs and its label.
+///
+/// A query node (or edge) without label matches data nodes (or edges) of any label.
+///
+/// By default the matches are isomorphic, i.e. distinct query nodes are mapped to distinct data
+/// nodes. Calling `homomorphism(true)` lifts this restriction (and the degree filter).
+///
+/// Matches are produced lazily as maps from query node ids to data node ids.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::UnGraphMap;
+/// use rust_graph::algorithm::SubgraphMatcher;
+///
+/// let mut data = UnGraphMap::<&str>::new();
+/// data.add_node(0, Some("a"));
+/// data.add_node(1, Some("b"));
+/// data.add_node(2, Some("b"));
+/// data.add_edge(0, 1, None);
+/// data.add_edge(0, 2, None);
+/// let data = data.into_static();
+///
+/// let mut query = UnGraphMap::<&str>::new();
+/// query.add_node(0, Some("a"));
+/// query.add_node(1, Some("b"));
+/// query.add_edge(0, 1, None);
+///
+/// let matches: Vec<_> = SubgraphMatcher::new(&query, &data).collect();
+/// assert_eq!(matches.len(), 2);
+///
+/// let matches: Vec<_> = SubgraphMatcher::new(&query, &data).limit(1).collect();
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0][&0], 0);
+/// ```
+///
+pub struct SubgraphMatcher<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType = Id> {
+    /// The query graph
+    query: &'a GeneralGraph<Id, NL, EL, L>,
+    /// The data graph
+    data: &'a GeneralGraph<Id, NL, EL, L>,
+    /// Whether distinct query nodes can be mapped to the same data node
+    is_homomorphism: bool,
+    /// The maximum number of matches to produce
+    limit: Option<usize>,
+    /// The number of matches produced so far
+    found: usize,
+    /// The search plan, computed at the first call of `next`
+    plan: Option<MatchingPlan<Id, L>>,
+    /// The candidates of each level of the search and the position of the next one to try
+    stack: Vec<(Vec<Id>, usize)>,
+    /// The data nodes matched to the query nodes of the levels above the current one
+    mapping: Vec<Id>,
+    /// The data nodes in `mapping`
+    used: FixedBitSet,
+}
+
+/// The query nodes in matching order, together with the constraints of each of them.
+struct MatchingPlan<Id: IdType, L: IdType> {
+    /// The query nodes in matching order
+    order: Vec<Id>,
+    /// The data nodes compatible with each query node, in matching order
+    candidates: Vec<FixedBitSet>,
+    /// The level of a matched neighbor whose data neighbors are the candidates of each level,
+    /// and whether the edge goes from that neighbor (`true`) or to it (`false`)
+    parents: Vec<Option<(usize, bool)>>,
+    /// The query edges between each level and itself or the levels above, as the other level,
+    /// whether the edge goes from this level (`true`) or to it (`false`), and the required label
+    back_edges: Vec<Vec<(usize, bool, Option<L>)>>,
+}
+
+impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType>
+    SubgraphMatcher<'a, Id, NL, EL, L>
+{
+    /// Create a new **SubgraphMatcher** looking for the **query** graph in the **data** graph.
+    pub fn new<Q: GeneralGraph<Id, NL, EL, L>, G: GeneralGraph<Id, NL, EL, L>>(
+        query: &'a Q,
+        data: &'a G,
+    ) -> Self {
+        if query.is_directed() != data.is_directed() {
+            panic!("Query and data graphs must be both directed or both undirected.")
+        }
+
+        SubgraphMatcher {
+            query,
+            data,
+            is_homomorphism: false,
+            limit: None,
+            found: 0,
+            plan: None,
+            stack: Vec::new(),
+            mapping: Vec::new(),
+            used: FixedBitSet::with_capacity(0),
+        }
+    }
+
+    /// Whether to look for homomorphisms instead of isomorphisms.
+    pub fn homomorphism(mut self, is_homomorphism: bool) -> Self {
+        self.is_homomorphism = is_homomorphism;
+        self
+    }
+
+    /// Stop after **limit** matches.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Build the search plan and the candidates of the first level.
+    fn init(&mut self) {
+        let plan = self.build_plan();
+        self.used = FixedBitSet::with_capacity(self.data_capacity());
+
+        let is_empty = plan.order.is_empty();
+        self.plan = Some(plan);
+
+        if !is_empty {
+            let candidates = self.level_candidates(0);
+            self.stack.push((candidates, 0));
+        }
+    }
+
+    fn data_capacity(&self) -> usize {
+        self.data.max_seen_id().map_or(0, |id| id.id() + 1)
+    }
+
+    fn build_plan(&self) -> MatchingPlan<Id, L> {
+        let query_nodes: Vec<Id> = self.query.node_indices().collect();
+        let mut candidates: HashMap<Id, FixedBitSet> = HashMap::new();
+
+        for &u in &query_nodes {
+            candidates.insert(u, self.node_candidates(u));
+        }
+
+        // Order the query nodes: start from the one with fewest candidates, then prefer nodes
+        // adjacent to the ordered ones.
+        let mut order: Vec<Id> = Vec::with_capacity(query_nodes.len());
+        let mut levels: HashMap<Id, usize> = HashMap::new();
+
+        while order.len() < query_nodes.len() {
+            let is_connected = |u: &Id| {
+                self.query
+                    .neighbors_iter(*u)
+                    .chain(self.in_neighbors(self.query, *u))
+                    .any(|w| levels.contains_key(&w))
+            };
+
+            let next = {
+                let remaining = query_nodes.iter().filter(|u| !levels.contains_key(u));
+                let connected: Vec<&Id> = remaining.clone().filter(|u| is_connected(u)).collect();
+                let pool: Vec<&Id> = if connected.is_empty() {
+                    remaining.collect()
+                } else {
+                    connected
+                };
+
+                *pool
+                    .into_iter()
+                    .min_by_key(|u| {
+                        (
+                            candidates[*u].count_ones(..),
+                            Reverse(self.query.degree(**u)),
+                        )
+                    })
+                    .unwrap()
+            };
+
+            levels.insert(next, order.len());
+            order.push(next);
+        }
+
+        let mut parents = Vec::with_capacity(order.len());
+        let mut back_edges = Vec::with_capacity(order.len());
+
+        for (level, &u) in order.iter().enumerate() {
+            let mut edges = Vec::new();
+            let mut parent = None;
+
+            for &w in &order[..=level] {
+                let other = levels[&w];
+                for &is_forward in &[true, false] {
+                    let (start, target) = if is_forward { (u, w) } else { (w, u) };
+
+                    if !self.query.has_edge(start, target)
+                        || (!is_forward && (!self.query.is_directed() || u == w))
+                    {
+                        continue;
+                    }
+
+                    let label = match self.query.get_edge_label(start, target) {
+                        Some(label) => match self.data.get_edge_label_map().find_index(label) {
+                            Some(label_id) => Some(L::new(label_id)),
+                            None => return MatchingPlan::empty(),
+                        },
+                        None => None,
+                    };
+
+                    edges.push((other, is_forward, label));
+
+                    if parent.is_none() && other != level {
+                        parent = Some((other, !is_forward || !self.query.is_directed()));
+                    }
+                }
+            }
+
+            parents.push(parent);
+            back_edges.push(edges);
+        }
+
+        let candidates = order
+            .iter()
+            .map(|u| candidates.remove(u).unwrap())
+            .collect();
+
+        MatchingPlan {
+            order,
+            candidates,
+            parents,
+            back_edges,
+        }
+    }
+
+    /// Return the data nodes compatible with **u** by label and degree.
+    fn node_candidates(&self, u: Id) -> FixedBitSet {
+        let mut candidates = FixedBitSet::with_capacity(self.data_capacity());
+
+        let label = match self.query.get_node_label(u) {
+            Some(label) => match self.data.get_node_label_map().find_index(label) {
+                Some(label_id) => Some(L::new(label_id)),
+                None => return candidates,
+            },
+            None => None,
+        };
+
+        let degree = self.query.degree(u);
+        let in_degree = self.query.as_digraph().map_or(0, |g| g.in_degree(u));
+
+        for v in self.data.node_indices() {
+            if label.is_some() && self.data.get_node(v).get_label_id() != label {
+                continue;
+            }
+
+            if !self.is_homomorphism
+                && (self.data.degree(v) < degree
+                    || self.data.as_digraph().map_or(0, |g| g.in_degree(v)) < in_degree)
+            {
+                continue;
+            }
+
+            candidates.insert(v.id());
+        }
+
+        candidates
+    }
+
+    /// Return the candidates of **level** given the data nodes matched above it.
+    fn level_candidates(&self, level: usize) -> Vec<Id> {
+        let plan = self.plan.as_ref().unwrap();
+        let candidates = &plan.candidates[level];
+
+        match plan.parents[level] {
+            Some((parent, is_out)) => {
+                let v = self.mapping[parent];
+                let neighbors: Vec<Id> = if is_out {
+                    self.data.neighbors_iter(v).collect()
+                } else {
+                    self.in_neighbors(self.data, v).collect()
+                };
+
+                neighbors
+                    .into_iter()
+                    .filter(|w| candidates.contains(w.id()))
+                    .collect()
+            }
+            None => candidates.ones().map(Id::new).collect(),
+        }
+    }
+
+    /// Whether **v** can be matched to the query node of **level**.
+    fn is_feasible(&self, level: usize, v: Id) -> bool {
+        if !self.is_homomorphism && self.used.contains(v.id()) {
+            return false;
+        }
+
+        let plan = self.plan.as_ref().unwrap();
+
+        plan.back_edges[level]
+            .iter()
+            .all(|&(other, is_forward, label)| {
+                let w = if other == level {
+                    v
+                } else {
+                    self.mapping[other]
+                };
+                let (start, target) = if is_forward { (v, w) } else { (w, v) };
+
+                self.data.has_edge(start, target)
+                    && (label.is_none()
+                        || self.data.get_edge(start, target).get_label_id() == label)
+            })
+    }
+
+    fn current_match(&self) -> HashMap<Id, Id> {
+        let plan = self.plan.as_ref().unwrap();
+
+        plan.order
+            .iter()
+            .cloned()
+            .zip(self.mapping.iter().cloned())
+            .collect()
+    }
+
+    fn in_neighbors<'b>(&self, graph: &'b GeneralGraph<Id, NL, EL, L>, id: Id) -> Iter<'b, Id> {
+        match graph.as_digraph() {
+            Some(g) => g.in_neighbors_iter(id),
+            None => Iter::empty(),
+        }
+    }
+}
+
+impl<Id: IdType, L: IdType> MatchingPlan<Id, L> {
+    /// A plan for a query that has no match at all.
+    fn empty() -> Self {
+        MatchingPlan {
+            order: Vec::new(),
+            candidates: Vec::new(),
+            parents: Vec::new(),
+            back_edges: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Id: IdType, NL: Eq + Hash + 'a, EL: Eq + Hash + 'a, L: IdType> Iterator
+    for SubgraphMatcher<'a, Id, NL, EL, L>
+{
+    type Item = HashMap<Id, Id>;
+
+    /// Return the next match, or **None** if the search is done.
+    fn next(&mut self) -> Option<HashMap<Id, Id>> {
+        if self.plan.is_none() {
+            self.init();
+        }
+
+        if let Some(limit) = self.limit {
+            if self.found >= limit {
+                return None;
+            }
+        }
+
+        let num_levels = self.plan.as_ref().unwrap().order.len();
+
+        while let Some(level) = self.stack.len().checked_sub(1) {
+            let candidate = {
+                let (ref candidates, ref mut next) = self.stack[level];
+                if *next < candidates.len() {
+                    *next += 1;
+                    Some(candidates[*next - 1])
+                } else {
+                    None
+                }
+            };
+
+            let v = match candidate {
+                Some(v) => v,
+                None => {
+                    self.stack.pop();
+                    if let Some(v) = self.mapping.pop() {
+                        self.used.set(v.id(), false);
+                    }
+                    continue;
+                }
+            };
+
+            if !self.is_feasible(level, v) {
+                continue;
+            }
+
+            self.mapping.push(v);
+            self.used.set(v.id(), true);
+
+            if level + 1 == num_levels {
+                let result = self.current_match();
+                self.mapping.pop();
+                self.used.set(v.id(), false);
+                self.found += 1;
+
+                return Some(result);
+            }
+
+            let next_candidates = self.level_candidates(level + 1);
+            self.stack.push((next_candidates, 0));
+        }
+
+        None
+    }
+}
@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use algorithm::dfs::{Dfs, DfsEvent};
+use prelude::*;
+
+/// Topological sort of a directed graph using Kahn's algorithm.
+///
+/// Nodes whose in-degree (`DiGraphTrait::in_degree`) drops to zero are emitted one by one,
+/// starting from the nodes without in-edges in the order of `node_indices`.
+/// Return the nodes in topological order, or a witness cycle `[v0, v1, ..., vk]` (with edges
+/// `v0 -> v1 -> ... -> vk -> v0`) if the graph is not acyclic.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::DiGraphMap;
+/// use rust_graph::algorithm::topological_sort;
+///
+/// let mut graph = DiGraphMap::<Void>::new();
+///
+/// graph.add_edge(2, 1, None);
+/// graph.add_edge(1, 0, None);
+///
+/// assert_eq!(topological_sort(&graph), Ok(vec![2, 1, 0]));
+///
+/// graph.add_edge(0, 2, None);
+///
+/// assert!(topological_sort(&graph).is_err());
+/// ```
+///
+pub fn topological_sort<Id: IdType, NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+    graph: &GeneralGraph<Id, NL, EL, L>,
+) -> Result<Vec<Id>, Vec<Id>> {
+    let digraph = as_digraph(graph);
+
+    let mut in_degrees: HashMap<Id, usize> = graph
+        .node_indices()
+        .map(|id| (id, digraph.in_degree(id)))
+        .collect();
+    let mut queue: VecDeque<Id> = graph
+        .node_indices()
+        .filter(|id| in_degrees[id] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(graph.node_count());
+
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for neighbor in graph.neighbors_iter(node) {
+            let degree = in_degrees.get_mut(&neighbor).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    if order.len() == graph.node_count() {
+        return Ok(order);
+    }
+
+    // Every remaining node has a remaining in-neighbor, so walking backwards along them
+    // must eventually run into a cycle.
+    let mut walk: Vec<Id> = Vec::new();
+    let mut positions: HashMap<Id, usize> = HashMap::new();
+    let mut node = graph.node_indices().find(|id| in_degrees[id] > 0).unwrap();
+
+    while !positions.contains_key(&node) {
+        positions.insert(node, walk.len());
+        walk.push(node);
+        node = digraph
+            .in_neighbors_iter(node)
+            .find(|n| in_degrees[n] > 0)
+            .unwrap();
+    }
+
+    let mut cycle = walk.split_off(positions[&node]);
+    cycle.reverse();
+
+    Err(cycle)
+}
+
+/// Topological sort of a directed graph using a depth first search.
+///
+/// The search is driven by the events of `Dfs::next_event`. Nodes are emitted in reverse
+/// order of finishing, and a back edge reveals a cycle.
+/// Return the nodes in topological order, or a witness cycle `[v0, v1, ..., vk]` (with edges
+/// `v0 -> v1 -> ... -> vk -> v0`) if the graph is not acyclic.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::DiGraphMap;
+/// use rust_graph::algorithm::topological_sort_dfs;
+///
+/// let mut graph = DiGraphMap::<Void>::new();
+///
+/// graph.add_edge(0, 1, None);
+/// graph.add_edge(1, 2, None);
+///
+/// assert_eq!(topological_sort_dfs(&graph), Ok(vec![0, 1, 2]));
+///
+/// graph.add_edge(2, 1, None);
+///
+/// let cycle = topological_sort_dfs(&graph).unwrap_err();
+/// assert_eq!(cycle.len(), 2);
+/// ```
+///
+pub fn topological_sort_dfs<Id, NL, EL, L, G>(graph: &G) -> Result<Vec<Id>, Vec<Id>>
+where
+    Id: IdType,
+    NL: Eq + Hash,
+    EL: Eq + Hash,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+{
+    as_digraph(graph);
+
+    if graph.node_count() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut dfs = Dfs::with_capacity(graph);
+    let mut path = Vec::new();
+    let mut finished = Vec::with_capacity(graph.node_count());
+
+    while let Some(event) = dfs.next_event() {
+        match event {
+            DfsEvent::Discover(node) => path.push(node),
+            DfsEvent::BackEdge(_, to) => {
+                let start = path.iter().rposition(|&n| n == to).unwrap();
+                return Err(path.split_off(start));
+            }
+            DfsEvent::Finish(node) => {
+                path.pop();
+                finished.push(node);
+            }
+        }
+    }
+
+    finished.reverse();
+
+    Ok(finished)
+}
+
+/// Check if a directed graph has no cycle.
+pub fn is_acyclic<Id, NL, EL, L, G>(graph: &G) -> bool
+where
+    Id: IdType,
+    NL: Eq + Hash,
+    EL: Eq + Hash,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+{
+    topological_sort_dfs(graph).is_ok()
+}
+
+fn as_digraph<Id: IdType, NL: Eq + Hash, EL: Eq + Hash, L: IdType>(
+    graph: &GeneralGraph<Id, NL, EL, L>,
+) -> &DiGraphTrait<Id, L> {
+    match graph.as_digraph() {
+        Some(digraph) => digraph,
+        None => panic!("Topological sort is only defined on directed graphs."),
+    }
+}
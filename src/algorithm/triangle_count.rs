@@ -0,0 +1,277 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+
+use generic::{IdType, NodeType, WeightType};
+use graph_impl::graph_map::{NodeMapTrait, TypedUnGraphMap};
+use graph_impl::static_graph::AdjacencyTrait;
+use prelude::*;
+
+/// Exact triangle counting and clustering coefficient of an undirected graph.
+///
+/// Each triangle `(u, v, w)` with `u < v < w` is found exactly once, by intersecting the
+/// neighbors of `u` and `v` that are greater than `v`. The sorted neighbors of `EdgeVec`
+/// (as well as `EdgeVecMmap` and `CompressedEdgeVec`) and the ordered neighbors of `NodeMap`
+/// allow the intersection to be done by merging. Self-loops are ignored.
+///
+/// Example:
+///
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::UnGraphMap;
+/// use rust_graph::algorithm::TriangleCount;
+///
+/// let mut graph = UnGraphMap::<Void>::new();
+///
+/// graph.add_edge(0, 1, None);
+/// graph.add_edge(1, 2, None);
+/// graph.add_edge(2, 0, None);
+/// graph.add_edge(2, 3, None);
+///
+/// let tc = TriangleCount::from_graph_map(&graph);
+///
+/// assert_eq!(tc.get_count(), 1);
+/// assert_eq!(tc.get_node_count(2), Some(1));
+/// assert_eq!(tc.local_clustering(0), Some(1.0));
+/// assert_eq!(tc.global_clustering(), 0.6);
+///
+/// let tc = TriangleCount::from_edge_vec(graph.into_static().get_edge_vec());
+///
+/// assert_eq!(tc.get_count(), 1);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TriangleCount<Id: IdType> {
+    /// The number of triangles in the graph
+    count: usize,
+    /// The nodes of the graph
+    nodes: Vec<Id>,
+    /// Whether each id is a node of the graph, indexed by node id
+    is_node: FixedBitSet,
+    /// The number of triangles each node is in, indexed by node id
+    node_counts: Vec<usize>,
+    /// The degree of each node excluding self-loops, indexed by node id
+    degrees: Vec<usize>,
+}
+
+impl<Id: IdType> TriangleCount<Id> {
+    /// Count the triangles of an undirected graph in CSR format, whose neighbors are
+    /// sorted and stored in both directions.
+    ///
+    /// The edges of a directed graph are not supported: an edge stored in both directions
+    /// is taken as one undirected edge, while one stored in a single direction is only seen
+    /// from its smaller end, so the counts match neither the directed nor the underlying
+    /// undirected graph. Convert such a graph to an undirected one first.
+    pub fn from_edge_vec<L: IdType, E: AdjacencyTrait<Id, L>>(edges: &E) -> Self {
+        let num_nodes = edges.num_nodes();
+        let mut tc = TriangleCount::with_nodes((0..num_nodes).map(Id::new).collect(), num_nodes);
+
+        for u in 0..num_nodes {
+            let u = Id::new(u);
+            let u_nbrs = edges.neighbors(u);
+            tc.degrees[u.id()] = u_nbrs.iter().filter(|&&v| v != u).count();
+
+            for &v in greater_than(&u_nbrs, u) {
+                let common = merge_intersect(
+                    greater_than(&u_nbrs, v).iter().cloned(),
+                    edges.neighbors_iter(v).filter(|&w| w > v),
+                );
+                tc.add_triangles(u, v, common);
+            }
+        }
+
+        tc
+    }
+
+    /// Count the triangles of an undirected `GraphMap`.
+    pub fn from_graph_map<NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType>(
+        graph: &TypedUnGraphMap<Id, NL, EL, L, W>,
+    ) -> Self {
+        let capacity = graph.max_seen_id().map_or(0, |id| id.id() + 1);
+        let mut tc = TriangleCount::with_nodes(graph.node_indices().collect(), capacity);
+
+        for node in graph.nodes() {
+            let u = match node {
+                NodeType::NodeMap(node) => node,
+                _ => panic!("Unknown error."),
+            };
+            let u_id = u.get_id();
+            tc.degrees[u_id.id()] = u.neighbors_iter().filter(|&v| v != u_id).count();
+
+            for v_id in u.non_less_neighbors_iter().filter(|&v| v != u_id) {
+                let v = match graph.get_node(v_id) {
+                    NodeType::NodeMap(node) => node,
+                    _ => panic!("Node {:?} do not exist.", v_id),
+                };
+                let common = merge_intersect(
+                    u.non_less_neighbors_iter().filter(|&w| w > v_id),
+                    v.non_less_neighbors_iter().filter(|&w| w > v_id),
+                );
+                tc.add_triangles(u_id, v_id, common);
+            }
+        }
+
+        tc
+    }
+
+    /// Create a **TriangleCount** from results computed in parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn from_parts(
+        count: usize,
+        nodes: Vec<Id>,
+        node_counts: Vec<usize>,
+        degrees: Vec<usize>,
+    ) -> Self {
+        let is_node = node_set(&nodes, node_counts.len());
+
+        TriangleCount {
+            count,
+            nodes,
+            is_node,
+            node_counts,
+            degrees,
+        }
+    }
+
+    fn with_nodes(nodes: Vec<Id>, capacity: usize) -> Self {
+        let is_node = node_set(&nodes, capacity);
+
+        TriangleCount {
+            count: 0,
+            nodes,
+            is_node,
+            node_counts: vec![0; capacity],
+            degrees: vec![0; capacity],
+        }
+    }
+
+    fn add_triangles(&mut self, u: Id, v: Id, common: Vec<Id>) {
+        self.count += common.len();
+        self.node_counts[u.id()] += common.len();
+        self.node_counts[v.id()] += common.len();
+        for w in common {
+            self.node_counts[w.id()] += 1;
+        }
+    }
+
+    /// Return the number of triangles in the graph.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    /// Return the number of triangles containing **id**, or `None` if it is not in the graph.
+    pub fn get_node_count(&self, id: Id) -> Option<usize> {
+        if self.is_node.contains(id.id()) {
+            Some(self.node_counts[id.id()])
+        } else {
+            None
+        }
+    }
+
+    /// Return the local clustering coefficient of **id**, i.e. the fraction of pairs of its
+    /// neighbors that are adjacent. It is 0 for nodes with degree less than 2.
+    pub fn local_clustering(&self, id: Id) -> Option<f64> {
+        self.get_node_count(id).map(|count| {
+            let degree = self.degrees[id.id()];
+            if degree < 2 {
+                0.0
+            } else {
+                2.0 * count as f64 / (degree * (degree - 1)) as f64
+            }
+        })
+    }
+
+    /// Return the average of the local clustering coefficients of all nodes.
+    pub fn average_clustering(&self) -> f64 {
+        if self.nodes.is_empty() {
+            return 0.0;
+        }
+
+        let sum: f64 = self
+            .nodes
+            .iter()
+            .map(|&id| self.local_clustering(id).unwrap())
+            .sum();
+
+        sum / self.nodes.len() as f64
+    }
+
+    /// Return the global clustering coefficient (transitivity), i.e. three times the number
+    /// of triangles divided by the number of connected triples.
+    pub fn global_clustering(&self) -> f64 {
+        let triples: usize = self
+            .nodes
+            .iter()
+            .map(|&id| {
+                let degree = self.degrees[id.id()];
+                degree * degree.saturating_sub(1) / 2
+            }).sum();
+
+        if triples == 0 {
+            0.0
+        } else {
+            3.0 * self.count as f64 / triples as f64
+        }
+    }
+}
+
+fn node_set<Id: IdType>(nodes: &[Id], capacity: usize) -> FixedBitSet {
+    let mut is_node = FixedBitSet::with_capacity(capacity);
+    for id in nodes {
+        is_node.insert(id.id());
+    }
+
+    is_node
+}
+
+/// Return the suffix of a sorted slice whose elements are greater than **id**.
+#[inline]
+pub(crate) fn greater_than<Id: IdType>(nbrs: &[Id], id: Id) -> &[Id] {
+    match nbrs.binary_search(&id) {
+        Ok(i) => &nbrs[i + 1..],
+        Err(i) => &nbrs[i..],
+    }
+}
+
+/// Return the common elements of two sorted iterators.
+pub(crate) fn merge_intersect<Id: IdType, I: Iterator<Item = Id>, J: Iterator<Item = Id>>(
+    mut a: I,
+    mut b: J,
+) -> Vec<Id> {
+    let mut common = Vec::new();
+    let (mut x, mut y) = (a.next(), b.next());
+
+    while let (Some(i), Some(j)) = (x, y) {
+        if i < j {
+            x = a.next();
+        } else if i > j {
+            y = b.next();
+        } else {
+            common.push(i);
+            x = a.next();
+            y = b.next();
+        }
+    }
+
+    common
+}
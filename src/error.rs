@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! The error type shared by the fallible loaders of this crate, e.g. mmap, bincode and LDBC.
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+use bincode;
+use csv;
+
+/// A specialized `Result` type whose error is `GraphError`.
+pub type Result<T> = result::Result<T, GraphError>;
+
+#[derive(Debug)]
+pub enum GraphError {
+    /// Failure of reading or writing a file
+    Io(io::Error),
+    /// Failure of (de)serializing with bincode
+    Bincode(bincode::Error),
+    /// Failure of reading or writing a csv file
+    Csv(csv::Error),
+    /// The content of a file is inconsistent, e.g. a truncated mmap dump
+    InvalidData(String),
+    /// The argument of a loader is not valid, e.g. a file instead of a directory
+    InvalidInput(String),
+    /// The file was written in another format or with other types, e.g. a different `Id` width
+    FormatMismatch(String),
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GraphError::Io(ref e) => write!(f, "IO error: {}", e),
+            GraphError::Bincode(ref e) => write!(f, "Bincode error: {}", e),
+            GraphError::Csv(ref e) => write!(f, "CSV error: {}", e),
+            GraphError::InvalidData(ref s) => write!(f, "Invalid data: {}", s),
+            GraphError::InvalidInput(ref s) => write!(f, "Invalid input: {}", s),
+            GraphError::FormatMismatch(ref s) => write!(f, "Format mismatch: {}", s),
+        }
+    }
+}
+
+impl error::Error for GraphError {
+    fn source(&self) -> Option<&(error::Error + 'static)> {
+        match *self {
+            GraphError::Io(ref e) => Some(e),
+            GraphError::Bincode(ref e) => Some(e),
+            GraphError::Csv(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GraphError {
+    fn from(e: io::Error) -> Self {
+        GraphError::Io(e)
+    }
+}
+
+impl From<bincode::Error> for GraphError {
+    fn from(e: bincode::Error) -> Self {
+        GraphError::Bincode(e)
+    }
+}
+
+impl From<csv::Error> for GraphError {
+    fn from(e: csv::Error) -> Self {
+        GraphError::Csv(e)
+    }
+}
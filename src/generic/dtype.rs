@@ -33,6 +33,9 @@ pub type DefaultId = usize;
 
 pub type DefaultTy = Directed;
 
+/// The default data type for edge weights is `f64`.
+pub type DefaultWeight = f64;
+
 pub type Void = ();
 
 pub trait GraphType: Debug + PartialEq + Eq + Copy + Clone + Hash + Serialize {
@@ -123,4 +126,14 @@ macro_rules! impl_id_type {
     )
 }
 
-impl_id_type!(u8, u16, u32, usize,);
+impl_id_type!(u8, u16, u32, u64, usize,);
+
+/// Data type of the per-edge property (e.g. a numeric weight, or any other value) attached
+/// to an edge.
+///
+/// Whether an edge has a property is tracked separately from the value, so every value of the
+/// type is a valid property. `StaticGraphMmap` maps the properties from a file, so it is
+/// further restricted to `Copy` types.
+pub trait WeightType: 'static + Clone + Debug + PartialEq + Send + Sync + Serialize {}
+
+impl<T: 'static + Clone + Debug + PartialEq + Send + Sync + Serialize> WeightType for T {}
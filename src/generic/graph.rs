@@ -27,7 +27,7 @@ use counter::Counter;
 
 use generic::{
     EdgeTrait, EdgeType, IdType, Iter, MapTrait, MutEdgeType, MutNodeType, NodeTrait, NodeType,
-    OwnedEdgeType, OwnedNodeType,
+    OwnedEdgeType, OwnedNodeType, WeightType,
 };
 use graph_impl::graph_map::new_general_graphmap;
 use graph_impl::GraphImpl;
@@ -249,6 +249,30 @@ pub trait MutGraphLabelTrait<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType
     fn update_edge_label(&mut self, start: Id, target: Id, label: Option<EL>) -> bool;
 }
 
+/// Trait for graphs whose edges may carry a weight (or any other `WeightType` property).
+pub trait GraphWeightTrait<Id: IdType, W: WeightType, L: IdType>: GraphTrait<Id, L> {
+    /// Lookup the weight of the edge, `None` if the edge does not exist or has no weight.
+    fn get_edge_weight(&self, start: Id, target: Id) -> Option<W>;
+
+    /// Whether if any edge in the graph carries a weight.
+    fn has_edge_weights(&self) -> bool;
+
+    /// Return an iterator over all weighted edges in the graph.
+    #[inline]
+    fn weighted_edge_indices(&self) -> Iter<(Id, Id, W)> {
+        Iter::new(Box::new(self.edge_indices().filter_map(move |(s, d)| {
+            self.get_edge_weight(s, d).map(|w| (s, d, w))
+        })))
+    }
+}
+
+pub trait MutGraphWeightTrait<Id: IdType, W: WeightType, L: IdType>:
+    GraphWeightTrait<Id, W, L>
+{
+    /// Update the weight of the edge (`start`,`target)`. Return `false` if the edge does not exist.
+    fn update_edge_weight(&mut self, start: Id, target: Id, weight: Option<W>) -> bool;
+}
+
 /// Trait for undirected graphs.
 pub trait UnGraphTrait<Id: IdType, L: IdType>: GraphTrait<Id, L> {}
 
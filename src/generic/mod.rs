@@ -25,13 +25,15 @@ pub mod iter;
 pub mod map;
 pub mod node;
 
-pub use generic::dtype::{DefaultId, DefaultTy, Directed, GraphType, IdType, Undirected, Void};
+pub use generic::dtype::{
+    DefaultId, DefaultTy, DefaultWeight, Directed, GraphType, IdType, Undirected, Void, WeightType,
+};
 pub use generic::edge::{
     Edge, EdgeTrait, EdgeType, MutEdge, MutEdgeTrait, MutEdgeType, OwnedEdgeType,
 };
 pub use generic::graph::{
-    DiGraphTrait, GeneralGraph, GraphLabelTrait, GraphTrait, MutGraphLabelTrait, MutGraphTrait,
-    UnGraphTrait,
+    DiGraphTrait, GeneralGraph, GraphLabelTrait, GraphTrait, GraphWeightTrait, MutGraphLabelTrait,
+    MutGraphTrait, MutGraphWeightTrait, UnGraphTrait,
 };
 pub use generic::iter::Iter;
 pub use generic::map::{MapTrait, MutMapTrait};
@@ -20,9 +20,9 @@
  */
 use std::hash::Hash;
 
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 
-use generic::{GraphType, IdType, MutGraphTrait};
+use generic::{GraphType, IdType, Iter, MutGraphTrait};
 use graph_impl::TypedGraphMap;
 use map::SetMap;
 
@@ -39,15 +39,30 @@ where
     EL: Hash + Eq + Clone,
     Ty: GraphType,
 {
-    let mut rng = thread_rng();
+    empty_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
 
+/// `empty_graph` with the random numbers drawn from `rng`.
+pub fn empty_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
     let node_label_map = SetMap::from_vec(node_label);
     let edge_label_map = SetMap::from_vec(edge_label);
 
     let mut g = TypedGraphMap::with_label_map(node_label_map, edge_label_map);
 
     for i in 0..n {
-        let label = random_node_label(&mut rng, &g);
+        let label = random_node_label(rng, &g);
         g.add_node(Id::new(i), label);
     }
 
@@ -65,15 +80,413 @@ where
     EL: Hash + Eq + Clone,
     Ty: GraphType,
 {
-    let mut rng = thread_rng();
+    complete_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
 
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
-    for (s, d) in complete_edge_pairs::<Ty>(n) {
-        let label = random_edge_label(&mut rng, &g);
-        g.add_edge(Id::new(s), Id::new(d), label);
+/// `complete_graph` with the random numbers drawn from `rng`.
+pub fn complete_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(n, complete_edge_pairs::<Ty>(n), node_label, edge_label, rng)
+}
+
+/// The path 0 - 1 - ... - (n - 1), directed away from 0 in a directed graph.
+pub fn path_graph<Id, NL, EL, Ty>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    path_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
+
+/// `path_graph` with the random numbers drawn from `rng`.
+pub fn path_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(n, path_edge_pairs(n), node_label, edge_label, rng)
+}
+
+/// The cycle 0 - 1 - ... - (n - 1) - 0, directed in this order in a directed graph. The edge
+/// closing the cycle is left out for `n < 3`, where it would be a self-loop or a duplicate.
+pub fn cycle_graph<Id, NL, EL, Ty>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    cycle_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
+
+/// `cycle_graph` with the random numbers drawn from `rng`.
+pub fn cycle_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(n, cycle_edge_pairs(0, n), node_label, edge_label, rng)
+}
+
+/// The star of `n` nodes centred at 0, directed out of 0 in a directed graph.
+pub fn star_graph<Id, NL, EL, Ty>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    star_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
+
+/// `star_graph` with the random numbers drawn from `rng`.
+pub fn star_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(n, (1..n).map(|i| (0, i)), node_label, edge_label, rng)
+}
+
+/// The wheel of `n` nodes, i.e. the hub 0 joined to every node of the cycle
+/// 1 - 2 - ... - (n - 1) - 1, directed as in `star_graph` and `cycle_graph`.
+pub fn wheel_graph<Id, NL, EL, Ty>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    wheel_graph_with_rng(n, node_label, edge_label, &mut thread_rng())
+}
+
+/// `wheel_graph` with the random numbers drawn from `rng`.
+pub fn wheel_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        n,
+        (1..n).map(|i| (0, i)).chain(cycle_edge_pairs(1, n)),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// The complete `k`-ary tree of `n` nodes numbered in breadth-first order, so that the parent
+/// of node `i > 0` is `(i - 1) / k`, directed from parents to children in a directed graph.
+pub fn kary_tree_graph<Id, NL, EL, Ty>(
+    k: usize,
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    kary_tree_graph_with_rng(k, n, node_label, edge_label, &mut thread_rng())
+}
+
+/// `kary_tree_graph` with the random numbers drawn from `rng`.
+pub fn kary_tree_graph_with_rng<Id, NL, EL, Ty, R>(
+    k: usize,
+    n: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if k == 0 {
+        panic!("k must be positive");
     }
 
-    g
+    graph_from_edge_pairs(
+        n,
+        (1..n).map(|i| ((i - 1) / k, i)),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// The `rows` × `cols` grid, where node `r * cols + c` is joined to the nodes on its right and
+/// below, i.e. with the edges directed towards the greater ids in a directed graph.
+pub fn grid_2d_graph<Id, NL, EL, Ty>(
+    rows: usize,
+    cols: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    grid_2d_graph_with_rng(rows, cols, node_label, edge_label, &mut thread_rng())
+}
+
+/// `grid_2d_graph` with the random numbers drawn from `rng`.
+pub fn grid_2d_graph_with_rng<Id, NL, EL, Ty, R>(
+    rows: usize,
+    cols: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        rows * cols,
+        lattice_edge_pairs(&[rows, cols], false),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// The `x` × `y` × `z` grid with node `(i * y + j) * z + k` at (i, j, k), directed as in
+/// `grid_2d_graph`.
+pub fn grid_3d_graph<Id, NL, EL, Ty>(
+    x: usize,
+    y: usize,
+    z: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    grid_3d_graph_with_rng(x, y, z, node_label, edge_label, &mut thread_rng())
+}
+
+/// `grid_3d_graph` with the random numbers drawn from `rng`.
+pub fn grid_3d_graph_with_rng<Id, NL, EL, Ty, R>(
+    x: usize,
+    y: usize,
+    z: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        x * y * z,
+        lattice_edge_pairs(&[x, y, z], false),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// `grid_2d_graph` whose last row and column are joined back to the first ones, directed
+/// towards the latter, in every dimension larger than 2.
+pub fn torus_2d_graph<Id, NL, EL, Ty>(
+    rows: usize,
+    cols: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    torus_2d_graph_with_rng(rows, cols, node_label, edge_label, &mut thread_rng())
+}
+
+/// `torus_2d_graph` with the random numbers drawn from `rng`.
+pub fn torus_2d_graph_with_rng<Id, NL, EL, Ty, R>(
+    rows: usize,
+    cols: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        rows * cols,
+        lattice_edge_pairs(&[rows, cols], true),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// `grid_3d_graph` wrapped around as in `torus_2d_graph`.
+pub fn torus_3d_graph<Id, NL, EL, Ty>(
+    x: usize,
+    y: usize,
+    z: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    torus_3d_graph_with_rng(x, y, z, node_label, edge_label, &mut thread_rng())
+}
+
+/// `torus_3d_graph` with the random numbers drawn from `rng`.
+pub fn torus_3d_graph_with_rng<Id, NL, EL, Ty, R>(
+    x: usize,
+    y: usize,
+    z: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        x * y * z,
+        lattice_edge_pairs(&[x, y, z], true),
+        node_label,
+        edge_label,
+        rng,
+    )
+}
+
+/// The complete bipartite graph between the nodes `0..n1` and `n1..n1 + n2`, directed from
+/// the former to the latter in a directed graph.
+pub fn complete_bipartite_graph<Id, NL, EL, Ty>(
+    n1: usize,
+    n2: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    complete_bipartite_graph_with_rng(n1, n2, node_label, edge_label, &mut thread_rng())
+}
+
+/// `complete_bipartite_graph` with the random numbers drawn from `rng`.
+pub fn complete_bipartite_graph_with_rng<Id, NL, EL, Ty, R>(
+    n1: usize,
+    n2: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    graph_from_edge_pairs(
+        n1 + n2,
+        (0..n1).flat_map(|s| (n1..n1 + n2).map(move |d| (s, d))),
+        node_label,
+        edge_label,
+        rng,
+    )
 }
 
 pub fn empty_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
@@ -95,3 +508,188 @@ where
 {
     complete_graph(n, Vec::new(), Vec::new())
 }
+
+pub fn path_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    path_graph(n, Vec::new(), Vec::new())
+}
+
+pub fn cycle_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    cycle_graph(n, Vec::new(), Vec::new())
+}
+
+pub fn star_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    star_graph(n, Vec::new(), Vec::new())
+}
+
+pub fn wheel_graph_unlabeled<Id, NL, EL, Ty>(n: usize) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    wheel_graph(n, Vec::new(), Vec::new())
+}
+
+pub fn kary_tree_graph_unlabeled<Id, NL, EL, Ty>(
+    k: usize,
+    n: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    kary_tree_graph(k, n, Vec::new(), Vec::new())
+}
+
+pub fn grid_2d_graph_unlabeled<Id, NL, EL, Ty>(
+    rows: usize,
+    cols: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    grid_2d_graph(rows, cols, Vec::new(), Vec::new())
+}
+
+pub fn grid_3d_graph_unlabeled<Id, NL, EL, Ty>(
+    x: usize,
+    y: usize,
+    z: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    grid_3d_graph(x, y, z, Vec::new(), Vec::new())
+}
+
+pub fn torus_2d_graph_unlabeled<Id, NL, EL, Ty>(
+    rows: usize,
+    cols: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    torus_2d_graph(rows, cols, Vec::new(), Vec::new())
+}
+
+pub fn torus_3d_graph_unlabeled<Id, NL, EL, Ty>(
+    x: usize,
+    y: usize,
+    z: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    torus_3d_graph(x, y, z, Vec::new(), Vec::new())
+}
+
+pub fn complete_bipartite_graph_unlabeled<Id, NL, EL, Ty>(
+    n1: usize,
+    n2: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    complete_bipartite_graph(n1, n2, Vec::new(), Vec::new())
+}
+
+fn graph_from_edge_pairs<Id, NL, EL, Ty, R, I>(
+    n: usize,
+    edge_pairs: I,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+    for (s, d) in edge_pairs {
+        let label = random_edge_label(rng, &g);
+        g.add_edge(Id::new(s), Id::new(d), label);
+    }
+
+    g
+}
+
+fn path_edge_pairs<'a>(n: usize) -> Iter<'a, (usize, usize)> {
+    Iter::new(Box::new((1..n).map(|i| (i - 1, i))))
+}
+
+/// The cycle through the nodes `start..n` in order.
+fn cycle_edge_pairs<'a>(start: usize, n: usize) -> Iter<'a, (usize, usize)> {
+    let closing_edge = if n >= start + 3 {
+        Some((n - 1, start))
+    } else {
+        None
+    };
+
+    Iter::new(Box::new(path_edge_pairs(n).skip(start).chain(closing_edge)))
+}
+
+/// The edges between the neighbours of a grid with the given dimensions, whose nodes are
+/// numbered in row-major order. If `is_periodic`, the last node is also joined to the first
+/// in every dimension larger than 2.
+fn lattice_edge_pairs(dims: &[usize], is_periodic: bool) -> Vec<(usize, usize)> {
+    let n = dims.iter().product::<usize>();
+
+    let mut strides = vec![1; dims.len()];
+    for i in (1..dims.len()).rev() {
+        strides[i - 1] = strides[i] * dims[i];
+    }
+
+    let mut edges = Vec::new();
+    for s in 0..n {
+        for (&dim, &stride) in dims.iter().zip(&strides) {
+            let position = s / stride % dim;
+            if position + 1 < dim {
+                edges.push((s, s + stride));
+            } else if is_periodic && dim > 2 {
+                edges.push((s, s - position * stride));
+            }
+        }
+    }
+
+    edges
+}
@@ -18,13 +18,17 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+use std::collections::HashSet;
 use std::hash::Hash;
+use std::marker::PhantomData;
 
 use itertools::Itertools;
-use rand::{Rng, ThreadRng};
+use rand::prng::ChaChaRng;
+use rand::{Rng, SeedableRng};
 
 use generic::{GraphLabelTrait, GraphType, IdType, Iter, MapTrait};
 use graph_impl::TypedGraphMap;
+use map::SetMap;
 
 pub fn complete_edge_pairs<'a, Ty>(n: usize) -> Iter<'a, (usize, usize)>
 where
@@ -41,8 +45,124 @@ where
     }
 }
 
-pub fn random_node_label<Id, NL, EL, Ty>(
-    rng: &mut ThreadRng,
+/// The edges of G(n,p), drawn one by one with `next_pair` in O(n + m) time for m edges by
+/// skipping over the possible edges that are not chosen, whose numbers follow a geometric
+/// distribution (Batagelj and Brandes, "Efficient generation of large random networks", 2005).
+pub struct GnpEdgePairs<Ty: GraphType> {
+    n: usize,
+    num_pairs: usize,
+    log_q: f64,
+    // The index of the next possible edge.
+    next: usize,
+    graph_type: PhantomData<Ty>,
+}
+
+impl<Ty: GraphType> GnpEdgePairs<Ty> {
+    pub fn new(n: usize, p: f32) -> Self {
+        if !(0f32..=1f32).contains(&p) {
+            panic!("p must be in the range of [0,1]");
+        }
+
+        GnpEdgePairs {
+            n,
+            num_pairs: if p > 0f32 { num_of_pairs::<Ty>(n) } else { 0 },
+            log_q: (1f64 - f64::from(p)).ln(),
+            next: 0,
+            graph_type: PhantomData,
+        }
+    }
+
+    pub fn next_pair<R: Rng>(&mut self, rng: &mut R) -> Option<(usize, usize)> {
+        if self.next >= self.num_pairs {
+            return None;
+        }
+
+        let r = rng.gen_range(0f64, 1f64);
+        let skip = ((1f64 - r).ln() / self.log_q).floor() as usize;
+        let index = self.next.saturating_add(skip);
+        if index >= self.num_pairs {
+            self.next = self.num_pairs;
+            return None;
+        }
+
+        self.next = index + 1;
+        Some(nth_edge_pair::<Ty>(self.n, index))
+    }
+}
+
+/// `m` distinct edges of G(n,m), drawn in O(m) time and space with Floyd's sampling of the
+/// indices of the possible edges, which never rejects a draw, even for `m` close to n².
+pub fn gnm_edge_pairs<'a, Ty, R>(n: usize, m: usize, rng: &mut R) -> Iter<'a, (usize, usize)>
+where
+    Ty: 'a + GraphType,
+    R: Rng,
+{
+    let num_pairs = num_of_pairs::<Ty>(n);
+    if m > num_pairs {
+        panic!("m is too large.");
+    }
+
+    let mut sampled = HashSet::with_capacity(m);
+    // Kept in the order of sampling, so that the edges only depend on `rng`.
+    let mut indices = Vec::with_capacity(m);
+    for j in num_pairs - m..num_pairs {
+        let index = rng.gen_range(0, j + 1);
+        let index = if sampled.insert(index) {
+            index
+        } else {
+            sampled.insert(j);
+            j
+        };
+        indices.push(index);
+    }
+
+    Iter::new(Box::new(
+        indices
+            .into_iter()
+            .map(move |index| nth_edge_pair::<Ty>(n, index)),
+    ))
+}
+
+/// The number of possible edges without self-loops among `n` nodes.
+fn num_of_pairs<Ty: GraphType>(n: usize) -> usize {
+    let num_pairs = n * n.saturating_sub(1);
+
+    if Ty::is_directed() {
+        num_pairs
+    } else {
+        num_pairs / 2
+    }
+}
+
+/// The edge pair at `index` of an enumeration of all `num_of_pairs`, i.e. `(s, d)` in the
+/// order of `s` and then `d` for directed graphs, and `(s, d)` with `s < d` in the order of
+/// `d` and then `s` for undirected graphs.
+fn nth_edge_pair<Ty: GraphType>(n: usize, index: usize) -> (usize, usize) {
+    if Ty::is_directed() {
+        let (s, d) = (index / (n - 1), index % (n - 1));
+        (s, if d >= s { d + 1 } else { d })
+    } else {
+        // The largest `d` with d * (d - 1) / 2 <= index.
+        let mut d = ((1f64 + (1f64 + 8f64 * index as f64).sqrt()) / 2f64) as usize;
+        while d * (d - 1) / 2 > index {
+            d -= 1;
+        }
+        while (d + 1) * d / 2 <= index {
+            d += 1;
+        }
+        (index - d * (d - 1) / 2, d)
+    }
+}
+
+/// A random number generator for the `*_with_rng` generators that yields the same graph for
+/// the same `seed` on every run and platform. ChaCha is used rather than `StdRng`, whose
+/// algorithm may change between releases of `rand`.
+pub fn seeded_rng(seed: u64) -> ChaChaRng {
+    ChaChaRng::seed_from_u64(seed)
+}
+
+pub fn random_node_label<Id, NL, EL, Ty, R>(
+    rng: &mut R,
     g: &TypedGraphMap<Id, NL, EL, Ty>,
 ) -> Option<NL>
 where
@@ -50,20 +170,13 @@ where
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    R: Rng,
 {
-    let labels = g.get_node_label_map();
-
-    if labels.is_empty() {
-        return None;
-    }
-
-    let random_index = rng.gen_range(0, labels.len());
-
-    labels.get_item(random_index).cloned()
+    random_label(rng, g.get_node_label_map())
 }
 
-pub fn random_edge_label<Id, NL, EL, Ty>(
-    rng: &mut ThreadRng,
+pub fn random_edge_label<Id, NL, EL, Ty, R>(
+    rng: &mut R,
     g: &TypedGraphMap<Id, NL, EL, Ty>,
 ) -> Option<EL>
 where
@@ -71,9 +184,16 @@ where
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+    R: Rng,
 {
-    let labels = g.get_edge_label_map();
+    random_label(rng, g.get_edge_label_map())
+}
 
+pub(crate) fn random_label<L, R>(rng: &mut R, labels: &SetMap<L>) -> Option<L>
+where
+    L: Hash + Eq + Clone,
+    R: Rng,
+{
     if labels.is_empty() {
         return None;
     }
@@ -23,8 +23,27 @@ pub mod helper;
 pub mod random;
 
 pub use graph_gen::general::{
-    complete_graph, complete_graph_unlabeled, empty_graph, empty_graph_unlabeled,
+    complete_bipartite_graph, complete_bipartite_graph_unlabeled,
+    complete_bipartite_graph_with_rng, complete_graph, complete_graph_unlabeled,
+    complete_graph_with_rng, cycle_graph, cycle_graph_unlabeled, cycle_graph_with_rng,
+    empty_graph, empty_graph_unlabeled, empty_graph_with_rng, grid_2d_graph,
+    grid_2d_graph_unlabeled, grid_2d_graph_with_rng, grid_3d_graph, grid_3d_graph_unlabeled,
+    grid_3d_graph_with_rng, kary_tree_graph, kary_tree_graph_unlabeled, kary_tree_graph_with_rng,
+    path_graph, path_graph_unlabeled, path_graph_with_rng, star_graph, star_graph_unlabeled,
+    star_graph_with_rng, torus_2d_graph, torus_2d_graph_unlabeled, torus_2d_graph_with_rng,
+    torus_3d_graph, torus_3d_graph_unlabeled, torus_3d_graph_with_rng, wheel_graph,
+    wheel_graph_unlabeled, wheel_graph_with_rng,
 };
+pub use graph_gen::helper::seeded_rng;
 pub use graph_gen::random::{
-    random_gnm_graph, random_gnm_graph_unlabeled, random_gnp_graph, random_gnp_graph_unlabeled,
+    random_ba_graph, random_ba_graph_unlabeled, random_ba_graph_with_rng, random_bipartite_graph,
+    random_bipartite_graph_unlabeled, random_bipartite_graph_with_rng,
+    random_configuration_graph, random_configuration_graph_unlabeled,
+    random_configuration_graph_with_rng, random_gnm_graph, random_gnm_graph_into_builder,
+    random_gnm_graph_unlabeled, random_gnm_graph_with_rng, random_gnp_graph,
+    random_gnp_graph_unlabeled, random_gnp_graph_with_rng, random_rmat_graph,
+    random_rmat_graph_unlabeled, random_rmat_graph_with_rng, random_sparse_gnp_graph,
+    random_sparse_gnp_graph_into_builder, random_sparse_gnp_graph_unlabeled,
+    random_sparse_gnp_graph_with_rng, random_ws_graph, random_ws_graph_unlabeled,
+    random_ws_graph_with_rng, GRAPH500_RMAT_PROBS,
 };
@@ -18,18 +18,24 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+use std::collections::HashSet;
 use std::hash::Hash;
 
-use rand::seq::sample_iter;
 use rand::{thread_rng, Rng};
 
+use error::Result;
+use generic::GraphTrait;
 use generic::GraphType;
 use generic::IdType;
 use generic::MutGraphTrait;
 
-use graph_gen::general::empty_graph;
-use graph_gen::helper::{complete_edge_pairs, random_edge_label};
+use graph_gen::general::empty_graph_with_rng;
+use graph_gen::helper::{
+    complete_edge_pairs, gnm_edge_pairs, random_edge_label, random_label, GnpEdgePairs,
+};
+use graph_impl::static_graph::StaticGraphBuilder;
 use graph_impl::TypedGraphMap;
+use map::SetMap;
 
 pub fn random_gnp_graph<Id, NL, EL, Ty>(
     n: usize,
@@ -42,18 +48,34 @@ where
     NL: Hash + Eq + Clone,
     EL: Hash + Eq + Clone,
     Ty: GraphType,
+{
+    random_gnp_graph_with_rng(n, p, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_gnp_graph` with the random numbers drawn from `rng`.
+pub fn random_gnp_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
 {
     if p < 0f32 || p > 1f32 {
         panic!("p must be in the range of [0,1]");
     }
 
-    let mut rng = thread_rng();
-
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
 
     for (s, d) in complete_edge_pairs::<Ty>(n) {
         if rng.gen_range(0f32, 1f32) < p {
-            let label = random_edge_label(&mut rng, &g);
+            let label = random_edge_label(rng, &g);
             g.add_edge(Id::new(s), Id::new(d), label);
         }
     }
@@ -73,21 +95,504 @@ where
     EL: Hash + Eq + Clone,
     Ty: GraphType,
 {
-    let mut rng = thread_rng();
+    random_gnm_graph_with_rng(n, m, node_label, edge_label, &mut thread_rng())
+}
 
-    let mut g = empty_graph::<Id, NL, EL, Ty>(n, node_label, edge_label);
-    let sampled_edges = sample_iter(&mut rng, complete_edge_pairs::<Ty>(n), m);
+/// `random_gnm_graph` with the random numbers drawn from `rng`.
+pub fn random_gnm_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    m: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+
+    for (s, d) in gnm_edge_pairs::<Ty, R>(n, m, rng) {
+        let label = random_edge_label(rng, &g);
+        g.add_edge(Id::new(s), Id::new(d), label);
+    }
 
-    if let Ok(mut edges) = sampled_edges {
-        for (s, d) in edges.drain(..) {
-            let label = random_edge_label(&mut rng, &g);
+    g
+}
+
+/// G(n,p) for large and sparse graphs, which takes O(n + m) time for m edges where
+/// `random_gnp_graph` takes O(n²), see `GnpEdgePairs`.
+pub fn random_sparse_gnp_graph<Id, NL, EL, Ty>(
+    n: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_sparse_gnp_graph_with_rng(n, p, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_sparse_gnp_graph` with the random numbers drawn from `rng`.
+pub fn random_sparse_gnp_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    let mut pairs = GnpEdgePairs::<Ty>::new(n, p);
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+
+    while let Some((s, d)) = pairs.next_pair(rng) {
+        let label = random_edge_label(rng, &g);
+        g.add_edge(Id::new(s), Id::new(d), label);
+    }
+
+    g
+}
+
+/// Add a graph of `random_sparse_gnp_graph_with_rng` to `builder` instead of a `TypedGraphMap`,
+/// which may spill the edges to disk for graphs that do not fit in memory.
+pub fn random_sparse_gnp_graph_into_builder<Id, NL, EL, Ty, L, R>(
+    n: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    builder: &mut StaticGraphBuilder<Id, NL, EL, Ty, L>,
+    rng: &mut R,
+) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    L: IdType,
+    R: Rng,
+{
+    let mut pairs = GnpEdgePairs::<Ty>::new(n, p);
+    add_nodes_into_builder(n, node_label, builder, rng);
+
+    let edge_labels = SetMap::from_vec(edge_label);
+    while let Some((s, d)) = pairs.next_pair(rng) {
+        let label = random_label(rng, &edge_labels);
+        builder.add_edge(Id::new(s), Id::new(d), label)?;
+    }
+
+    Ok(())
+}
+
+/// Add a graph of `random_gnm_graph_with_rng` to `builder` instead of a `TypedGraphMap`,
+/// which may spill the edges to disk for graphs that do not fit in memory.
+pub fn random_gnm_graph_into_builder<Id, NL, EL, Ty, L, R>(
+    n: usize,
+    m: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    builder: &mut StaticGraphBuilder<Id, NL, EL, Ty, L>,
+    rng: &mut R,
+) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    L: IdType,
+    R: Rng,
+{
+    add_nodes_into_builder(n, node_label, builder, rng);
+
+    let edge_labels = SetMap::from_vec(edge_label);
+    for (s, d) in gnm_edge_pairs::<Ty, R>(n, m, rng) {
+        let label = random_label(rng, &edge_labels);
+        builder.add_edge(Id::new(s), Id::new(d), label)?;
+    }
+
+    Ok(())
+}
+
+fn add_nodes_into_builder<Id, NL, EL, Ty, L, R>(
+    n: usize,
+    node_label: Vec<NL>,
+    builder: &mut StaticGraphBuilder<Id, NL, EL, Ty, L>,
+    rng: &mut R,
+) where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    R: Rng,
+{
+    let node_labels = SetMap::from_vec(node_label);
+    for i in 0..n {
+        let label = random_label(rng, &node_labels);
+        builder.add_node(Id::new(i), label);
+    }
+}
+
+/// Each edge of `complete_bipartite_graph(n1, n2)` is chosen with probability `p`.
+pub fn random_bipartite_graph<Id, NL, EL, Ty>(
+    n1: usize,
+    n2: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_bipartite_graph_with_rng(n1, n2, p, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_bipartite_graph` with the random numbers drawn from `rng`.
+pub fn random_bipartite_graph_with_rng<Id, NL, EL, Ty, R>(
+    n1: usize,
+    n2: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if !(0f32..=1f32).contains(&p) {
+        panic!("p must be in the range of [0,1]");
+    }
+
+    let mut g = empty_graph_with_rng(n1 + n2, node_label, edge_label, rng);
+
+    for s in 0..n1 {
+        for d in n1..n1 + n2 {
+            if rng.gen_range(0f32, 1f32) < p {
+                let label = random_edge_label(rng, &g);
+                g.add_edge(Id::new(s), Id::new(d), label);
+            }
+        }
+    }
+
+    g
+}
+
+/// The R-MAT quadrant probabilities `[a, b, c, d]` of the Graph500 benchmark.
+pub const GRAPH500_RMAT_PROBS: [f32; 4] = [0.57, 0.19, 0.19, 0.05];
+
+/// Barabási–Albert preferential attachment: starting from `m` isolated nodes, each new node is
+/// joined to `m` distinct existing nodes chosen with probability proportional to their degree.
+pub fn random_ba_graph<Id, NL, EL, Ty>(
+    n: usize,
+    m: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_ba_graph_with_rng(n, m, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_ba_graph` with the random numbers drawn from `rng`.
+pub fn random_ba_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    m: usize,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if m < 1 || m >= n {
+        panic!("m must be in the range of [1,n)");
+    }
+
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+
+    // Every node appears here once per incident edge, so a uniform pick is a preferential one.
+    let mut repeated_nodes = Vec::with_capacity(2 * m * (n - m));
+    let mut targets = (0..m).collect::<Vec<_>>();
+
+    for s in m..n {
+        for &d in &targets {
+            let label = random_edge_label(rng, &g);
             g.add_edge(Id::new(s), Id::new(d), label);
         }
 
-        g
-    } else {
-        panic!("m is too large.");
+        repeated_nodes.append(&mut targets);
+        repeated_nodes.extend((0..m).map(|_| s));
+
+        while targets.len() < m {
+            let d = repeated_nodes[rng.gen_range(0, repeated_nodes.len())];
+            if !targets.contains(&d) {
+                targets.push(d);
+            }
+        }
+    }
+
+    g
+}
+
+/// Watts–Strogatz small world: a ring lattice joining each node to its `k / 2` nearest
+/// neighbours on either side, whose edges are then rewired to a random target with
+/// probability `p`, avoiding self-loops and duplicate edges.
+pub fn random_ws_graph<Id, NL, EL, Ty>(
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_ws_graph_with_rng(n, k, p, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_ws_graph` with the random numbers drawn from `rng`.
+pub fn random_ws_graph_with_rng<Id, NL, EL, Ty, R>(
+    n: usize,
+    k: usize,
+    p: f32,
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if k >= n {
+        panic!("k must be less than n");
+    }
+
+    if !(0f32..=1f32).contains(&p) {
+        panic!("p must be in the range of [0,1]");
+    }
+
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+
+    let key = |s: usize, d: usize| {
+        if Ty::is_directed() || s < d {
+            (s, d)
+        } else {
+            (d, s)
+        }
+    };
+
+    let mut edges = Vec::new();
+    for j in 1..=k / 2 {
+        for s in 0..n {
+            let d = (s + j) % n;
+            edges.push((s, d));
+            if Ty::is_directed() {
+                edges.push((d, s));
+            }
+        }
     }
+
+    let mut edge_set = edges
+        .iter()
+        .map(|&(s, d)| key(s, d))
+        .collect::<HashSet<_>>();
+    // The out-degree of `s` if directed, its degree otherwise.
+    let mut degrees = vec![k / 2 * 2; n];
+
+    for edge in &mut edges {
+        let (s, d) = *edge;
+
+        if degrees[s] >= n - 1 || rng.gen_range(0f32, 1f32) >= p {
+            continue;
+        }
+
+        let mut new_d = rng.gen_range(0, n);
+        while new_d == s || edge_set.contains(&key(s, new_d)) {
+            new_d = rng.gen_range(0, n);
+        }
+
+        edge_set.remove(&key(s, d));
+        edge_set.insert(key(s, new_d));
+        if !Ty::is_directed() {
+            degrees[d] -= 1;
+            degrees[new_d] += 1;
+        }
+
+        *edge = (s, new_d);
+    }
+
+    for (s, d) in edges {
+        let label = random_edge_label(rng, &g);
+        g.add_edge(Id::new(s), Id::new(d), label);
+    }
+
+    g
+}
+
+/// R-MAT (the Kronecker generator of Graph500) with `2^scale` nodes: each of the
+/// `edge_factor * 2^scale` edges recursively picks one of the four quadrants of the adjacency
+/// matrix with the probabilities `probs`, e.g. `GRAPH500_RMAT_PROBS`. The node ids are randomly
+/// permuted afterwards, and self-loops and duplicate edges are dropped.
+pub fn random_rmat_graph<Id, NL, EL, Ty>(
+    scale: usize,
+    edge_factor: usize,
+    probs: [f32; 4],
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_rmat_graph_with_rng(
+        scale,
+        edge_factor,
+        probs,
+        node_label,
+        edge_label,
+        &mut thread_rng(),
+    )
+}
+
+/// `random_rmat_graph` with the random numbers drawn from `rng`.
+pub fn random_rmat_graph_with_rng<Id, NL, EL, Ty, R>(
+    scale: usize,
+    edge_factor: usize,
+    probs: [f32; 4],
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if probs.iter().any(|&p| p < 0f32) || (probs.iter().sum::<f32>() - 1f32).abs() > 1e-4 {
+        panic!("probs must be non-negative and sum up to 1");
+    }
+
+    let n = 1 << scale;
+    let mut g = empty_graph_with_rng(n, node_label, edge_label, rng);
+
+    let mut permutation = (0..n).collect::<Vec<_>>();
+    rng.shuffle(&mut permutation);
+
+    let [a, b, c, _] = probs;
+    for _ in 0..edge_factor * n {
+        let (mut s, mut d) = (0, 0);
+        for bit in (0..scale).map(|i| 1 << i) {
+            let r = rng.gen_range(0f32, 1f32);
+            if r >= a + b + c {
+                s |= bit;
+                d |= bit;
+            } else if r >= a + b {
+                s |= bit;
+            } else if r >= a {
+                d |= bit;
+            }
+        }
+
+        let (s, d) = (Id::new(permutation[s]), Id::new(permutation[d]));
+        if s != d && !g.has_edge(s, d) {
+            let label = random_edge_label(rng, &g);
+            g.add_edge(s, d, label);
+        }
+    }
+
+    g
+}
+
+/// The (erased) configuration model: node `i` gets `degrees[i]` edge stubs, which are paired
+/// uniformly at random, dropping self-loops and duplicate edges. In a directed graph,
+/// `degrees[i]` is the sum of the in- and out-degree of node `i`.
+pub fn random_configuration_graph<Id, NL, EL, Ty>(
+    degrees: &[usize],
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_configuration_graph_with_rng(degrees, node_label, edge_label, &mut thread_rng())
+}
+
+/// `random_configuration_graph` with the random numbers drawn from `rng`.
+pub fn random_configuration_graph_with_rng<Id, NL, EL, Ty, R>(
+    degrees: &[usize],
+    node_label: Vec<NL>,
+    edge_label: Vec<EL>,
+    rng: &mut R,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    R: Rng,
+{
+    if degrees.iter().sum::<usize>() % 2 != 0 {
+        panic!("The sum of degrees must be even");
+    }
+
+    let mut g = empty_graph_with_rng(degrees.len(), node_label, edge_label, rng);
+
+    let mut stubs = degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &degree)| (0..degree).map(move |_| i))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut stubs);
+
+    for pair in stubs.chunks(2) {
+        let (s, d) = (Id::new(pair[0]), Id::new(pair[1]));
+        if s != d && !g.has_edge(s, d) {
+            let label = random_edge_label(rng, &g);
+            g.add_edge(s, d, label);
+        }
+    }
+
+    g
 }
 
 pub fn random_gnp_graph_unlabeled<Id, NL, EL, Ty>(n: usize, p: f32) -> TypedGraphMap<Id, NL, EL, Ty>
@@ -100,6 +605,19 @@ where
     random_gnp_graph(n, p, Vec::new(), Vec::new())
 }
 
+pub fn random_sparse_gnp_graph_unlabeled<Id, NL, EL, Ty>(
+    n: usize,
+    p: f32,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_sparse_gnp_graph(n, p, Vec::new(), Vec::new())
+}
+
 pub fn random_gnm_graph_unlabeled<Id, NL, EL, Ty>(
     n: usize,
     m: usize,
@@ -112,3 +630,70 @@ where
 {
     random_gnm_graph(n, m, Vec::new(), Vec::new())
 }
+
+pub fn random_bipartite_graph_unlabeled<Id, NL, EL, Ty>(
+    n1: usize,
+    n2: usize,
+    p: f32,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_bipartite_graph(n1, n2, p, Vec::new(), Vec::new())
+}
+
+pub fn random_ba_graph_unlabeled<Id, NL, EL, Ty>(
+    n: usize,
+    m: usize,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_ba_graph(n, m, Vec::new(), Vec::new())
+}
+
+pub fn random_ws_graph_unlabeled<Id, NL, EL, Ty>(
+    n: usize,
+    k: usize,
+    p: f32,
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_ws_graph(n, k, p, Vec::new(), Vec::new())
+}
+
+pub fn random_rmat_graph_unlabeled<Id, NL, EL, Ty>(
+    scale: usize,
+    edge_factor: usize,
+    probs: [f32; 4],
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_rmat_graph(scale, edge_factor, probs, Vec::new(), Vec::new())
+}
+
+pub fn random_configuration_graph_unlabeled<Id, NL, EL, Ty>(
+    degrees: &[usize],
+) -> TypedGraphMap<Id, NL, EL, Ty>
+where
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+{
+    random_configuration_graph(degrees, Vec::new(), Vec::new())
+}
@@ -0,0 +1,830 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use itertools::Itertools;
+
+use generic::{
+    DefaultId, DefaultTy, DiGraphTrait, Directed, EdgeTrait, EdgeType, GeneralGraph,
+    GraphLabelTrait, GraphTrait, GraphType, GraphWeightTrait, IdType, Iter, MutEdgeType,
+    MutGraphLabelTrait, MutGraphTrait, MutMapTrait, MutNodeTrait, MutNodeType, NodeTrait, NodeType,
+    OwnedEdgeType, OwnedNodeType, UnGraphTrait, Undirected, WeightType,
+};
+use graph_impl::graph_map::{MutNodeMapTrait, NodeMap, NodeMapTrait};
+use graph_impl::static_graph::StaticNode;
+use graph_impl::{Edge, EdgeVec, GraphImpl, TypedStaticGraph};
+use map::SetMap;
+
+pub type TypedUnDeltaGraph<
+    Id,
+    NL,
+    EL = NL,
+    L = Id,
+    G = TypedStaticGraph<Id, NL, EL, Undirected, L>,
+> = TypedDeltaGraph<Id, NL, EL, Undirected, L, G>;
+pub type TypedDiDeltaGraph<Id, NL, EL = NL, L = Id, G = TypedStaticGraph<Id, NL, EL, Directed, L>> =
+    TypedDeltaGraph<Id, NL, EL, Directed, L, G>;
+pub type DeltaGraph<NL, EL = NL, Ty = DefaultTy, L = DefaultId> =
+    TypedDeltaGraph<DefaultId, NL, EL, Ty, L>;
+pub type UnDeltaGraph<NL, EL = NL, L = DefaultId> = DeltaGraph<NL, EL, Undirected, L>;
+pub type DiDeltaGraph<NL, EL = NL, L = DefaultId> = DeltaGraph<NL, EL, Directed, L>;
+
+/// A mutable graph made of a static graph `G`, which is never modified, and a delta of the
+/// nodes and edges inserted or deleted since.
+///
+/// An edge is either kept by the delta or by `G`, but never by both. When an edge of `G` is
+/// deleted or updated, it is hidden from `G`, and in the latter case, added to the delta.
+/// Thus, the neighbors of a node are the disjoint union of the two, which are merged in order.
+pub struct TypedDeltaGraph<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType = Id,
+    G: GeneralGraph<Id, NL, EL, L> = TypedStaticGraph<Id, NL, EL, Ty, L>,
+> {
+    base: G,
+    // The nodes touched by the delta, with their labels and the neighbors inserted.
+    delta: BTreeMap<Id, NodeMap<Id, L>>,
+    // The nodes of `base` that are deleted.
+    removed_nodes: BTreeSet<Id>,
+    // The edges of `base` that are deleted or moved to `delta`,
+    // maintained in both directions for undirected graph.
+    removed_edges: BTreeSet<(Id, Id)>,
+    // The edges of `base` moved to `delta`, whose weights are still kept by `base`,
+    // maintained in both directions for undirected graph.
+    moved_edges: BTreeSet<(Id, Id)>,
+    num_nodes: usize,
+    num_edges: usize,
+    max_id: Option<Id>,
+    // A map of node labels, which extends the one of `base`.
+    node_label_map: SetMap<NL>,
+    // A map of edge labels, which extends the one of `base`.
+    edge_label_map: SetMap<EL>,
+    // A marker of the graph type, namely, directed or undirected.
+    graph_type: PhantomData<Ty>,
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq + Clone,
+    EL: Hash + Eq + Clone,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    /// Wrap **base** with an empty delta.
+    pub fn new(base: G) -> Self {
+        if base.is_directed() != Ty::is_directed() {
+            panic!(
+                "The base graph should be {}.",
+                if Ty::is_directed() {
+                    "directed"
+                } else {
+                    "undirected"
+                }
+            );
+        }
+
+        TypedDeltaGraph {
+            delta: BTreeMap::new(),
+            removed_nodes: BTreeSet::new(),
+            removed_edges: BTreeSet::new(),
+            moved_edges: BTreeSet::new(),
+            num_nodes: base.node_count(),
+            num_edges: base.edge_count(),
+            max_id: base.max_seen_id(),
+            node_label_map: base.get_node_label_map().clone(),
+            edge_label_map: base.get_edge_label_map().clone(),
+            graph_type: PhantomData,
+            base,
+        }
+    }
+
+    /// Fold the delta into a new `TypedStaticGraph`, in which the node ids are kept.
+    ///
+    /// The edges of the base graph keep their weights, even if their labels are updated,
+    /// while the edges inserted in the delta have none.
+    pub fn compact<W: WeightType>(&self) -> TypedStaticGraph<Id, NL, EL, Ty, L, W>
+    where
+        G: GraphWeightTrait<Id, W, L>,
+    {
+        let num_ids = self.max_id.map_or(0, |id| id.id() + 1);
+
+        let mut offsets = Vec::with_capacity(num_ids + 1);
+        let mut edges = Vec::new();
+        let mut edge_labels = Vec::new();
+        let mut edge_weights = Vec::new();
+        let mut labels = Vec::with_capacity(num_ids);
+
+        let (mut in_offsets, mut in_edges) = if self.is_directed() {
+            (Some(Vec::with_capacity(num_ids + 1)), Some(Vec::new()))
+        } else {
+            (None, None)
+        };
+
+        offsets.push(0);
+        if let Some(ref mut _in_offsets) = in_offsets {
+            _in_offsets.push(0);
+        }
+
+        for n in 0..num_ids {
+            let node = Id::new(n);
+
+            if self.has_node(node) {
+                for nbr in self.neighbors_iter(node) {
+                    edges.push(nbr);
+                    edge_labels.push(
+                        self.get_edge(node, nbr)
+                            .get_label_id()
+                            .unwrap_or_else(L::max_value),
+                    );
+                    edge_weights.push(self.base_edge_weight(node, nbr));
+                }
+
+                if let Some(ref mut _in_edges) = in_edges {
+                    _in_edges.extend(self.merged_in_neighbors_iter(node));
+                }
+
+                labels.push(
+                    self.get_node(node)
+                        .get_label_id()
+                        .unwrap_or_else(L::max_value),
+                );
+            } else {
+                labels.push(L::max_value());
+            }
+
+            offsets.push(edges.len());
+            if let (Some(_in_offsets), Some(_in_edges)) = (in_offsets.as_mut(), in_edges.as_ref()) {
+                _in_offsets.push(_in_edges.len());
+            }
+        }
+
+        let edge_labels = if edge_labels.iter().any(|l| *l != L::max_value()) {
+            Some(edge_labels)
+        } else {
+            None
+        };
+
+        let labels = if labels.iter().any(|l| *l != L::max_value()) {
+            Some(labels)
+        } else {
+            None
+        };
+
+        let in_edge_vec = if let (Some(_in_offsets), Some(_in_edges)) = (in_offsets, in_edges) {
+            Some(EdgeVec::new(_in_offsets, _in_edges))
+        } else {
+            None
+        };
+
+        let graph = TypedStaticGraph::from_raw(
+            self.num_nodes,
+            self.num_edges,
+            EdgeVec::from_raw(offsets, edges, edge_labels),
+            in_edge_vec,
+            labels,
+            self.node_label_map.clone(),
+            self.edge_label_map.clone(),
+        );
+
+        if edge_weights.iter().any(|w| w.is_some()) {
+            graph.with_edge_weights(edge_weights)
+        } else {
+            graph
+        }
+    }
+
+    /// The weight of the edge in `base`, if it is still kept by `base` or moved to `delta`.
+    #[inline]
+    fn base_edge_weight<W: WeightType>(&self, start: Id, target: Id) -> Option<W>
+    where
+        G: GraphWeightTrait<Id, W, L>,
+    {
+        if self.base_has_edge(start, target) || self.moved_edges.contains(&(start, target)) {
+            self.base.get_edge_weight(start, target)
+        } else {
+            None
+        }
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    /// Return the static graph below the delta.
+    #[inline(always)]
+    pub fn get_base(&self) -> &G {
+        &self.base
+    }
+
+    /// Whether any node or edge has been inserted, deleted or updated.
+    #[inline]
+    pub fn has_delta(&self) -> bool {
+        !(self.delta.is_empty() && self.removed_nodes.is_empty() && self.removed_edges.is_empty())
+    }
+
+    #[inline]
+    fn in_base(&self, id: Id) -> bool {
+        !self.removed_nodes.contains(&id) && self.base.has_node(id)
+    }
+
+    #[inline]
+    fn base_has_edge(&self, start: Id, target: Id) -> bool {
+        !self.removed_edges.contains(&(start, target)) && self.base.has_edge(start, target)
+    }
+
+    #[inline]
+    fn delta_has_edge(&self, start: Id, target: Id) -> bool {
+        match self.delta.get(&start) {
+            Some(node) => node.has_neighbor(target),
+            None => false,
+        }
+    }
+
+    /// The number of edges of `base` hidden from the neighbors of **id**.
+    #[inline]
+    fn num_removed_edges(&self, id: Id) -> usize {
+        self.removed_edges
+            .range((id, Id::new(0))..=(id, Id::max_value()))
+            .count()
+    }
+
+    /// Get the node in the delta, which is added with its current label if not there.
+    fn touch(&mut self, id: Id) -> &mut NodeMap<Id, L> {
+        if !self.delta.contains_key(&id) {
+            let label = if self.in_base(id) {
+                self.base.get_node(id).get_label_id()
+            } else {
+                None
+            };
+            self.delta.insert(id, NodeMap::new(id, label));
+        }
+
+        self.delta.get_mut(&id).unwrap()
+    }
+
+    fn hide_base_edge(&mut self, start: Id, target: Id) {
+        self.removed_edges.insert((start, target));
+        if !self.is_directed() {
+            self.removed_edges.insert((target, start));
+        }
+    }
+
+    /// Hide the edge from `base`, and record that it is moved to the delta.
+    fn move_base_edge(&mut self, start: Id, target: Id) {
+        self.hide_base_edge(start, target);
+        self.moved_edges.insert((start, target));
+        if !self.is_directed() {
+            self.moved_edges.insert((target, start));
+        }
+    }
+
+    fn insert_delta_edge(&mut self, start: Id, target: Id, label: Option<L>) {
+        self.touch(start).add_edge(target, label);
+
+        if self.is_directed() {
+            self.touch(target).add_in_edge(start);
+        } else if start != target {
+            self.touch(target).add_edge(start, label);
+        }
+    }
+
+    /// Move the edge from `base` to the delta, keeping its label.
+    fn move_to_delta(&mut self, start: Id, target: Id) {
+        let label = self.base.get_edge(start, target).get_label_id();
+        self.move_base_edge(start, target);
+        self.insert_delta_edge(start, target, label);
+    }
+
+    fn merged_in_neighbors_iter(&self, id: Id) -> Iter<Id> {
+        if !self.is_directed() {
+            return self.neighbors_iter(id);
+        }
+
+        let base_in_neighbors = match self.base.as_digraph() {
+            Some(base) if self.in_base(id) => Iter::new(Box::new(
+                base.in_neighbors_iter(id)
+                    .filter(move |n| !self.removed_edges.contains(&(*n, id))),
+            )),
+            _ => Iter::empty(),
+        };
+
+        match self.delta.get(&id) {
+            Some(node) => Iter::new(Box::new(
+                base_in_neighbors.merge(node.in_neighbors.iter().cloned()),
+            )),
+            None => base_in_neighbors,
+        }
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> GraphTrait<Id, L> for TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    #[inline]
+    fn get_node(&self, id: Id) -> NodeType<Id, L> {
+        match self.delta.get(&id) {
+            Some(node) => NodeType::StaticNode(StaticNode::new(id, node.get_label_id())),
+            None if self.in_base(id) => self.base.get_node(id),
+            None => NodeType::None,
+        }
+    }
+
+    #[inline]
+    fn get_edge(&self, start: Id, target: Id) -> EdgeType<Id, L> {
+        if let Some(label) = self.delta.get(&start).and_then(|n| n.get_neighbor(target)) {
+            return EdgeType::Edge(Edge::new(start, target, label));
+        }
+
+        if self.base_has_edge(start, target) {
+            self.base.get_edge(start, target)
+        } else {
+            EdgeType::None
+        }
+    }
+
+    #[inline]
+    fn has_node(&self, id: Id) -> bool {
+        self.delta.contains_key(&id) || self.in_base(id)
+    }
+
+    #[inline]
+    fn has_edge(&self, start: Id, target: Id) -> bool {
+        self.delta_has_edge(start, target) || self.base_has_edge(start, target)
+    }
+
+    #[inline]
+    fn node_count(&self) -> usize {
+        self.num_nodes
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.num_edges
+    }
+
+    #[inline(always)]
+    fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// The nodes are sorted by id, even if `base` does not return them in order.
+    #[inline]
+    fn node_indices(&self) -> Iter<Id> {
+        let mut base_nodes = self
+            .base
+            .node_indices()
+            .filter(|n| !self.removed_nodes.contains(n) && !self.delta.contains_key(n))
+            .collect_vec();
+        base_nodes.sort_unstable();
+
+        Iter::new(Box::new(
+            base_nodes.into_iter().merge(self.delta.keys().cloned()),
+        ))
+    }
+
+    #[inline]
+    fn edge_indices(&self) -> Iter<(Id, Id)> {
+        let is_directed = self.is_directed();
+        Iter::new(Box::new(self.node_indices().flat_map(move |n| {
+            self.neighbors_iter(n)
+                .filter(move |&nbr| is_directed || nbr >= n)
+                .map(move |nbr| (n, nbr))
+        })))
+    }
+
+    #[inline]
+    fn nodes(&self) -> Iter<NodeType<Id, L>> {
+        Iter::new(Box::new(self.node_indices().map(move |n| self.get_node(n))))
+    }
+
+    #[inline]
+    fn edges(&self) -> Iter<EdgeType<Id, L>> {
+        Iter::new(Box::new(
+            self.edge_indices().map(move |(s, d)| self.get_edge(s, d)),
+        ))
+    }
+
+    #[inline]
+    fn degree(&self, id: Id) -> usize {
+        if !self.has_node(id) {
+            panic!("Node {:?} do not exist.", id);
+        }
+
+        let base_degree = if self.in_base(id) {
+            self.base.degree(id) - self.num_removed_edges(id)
+        } else {
+            0
+        };
+
+        match self.delta.get(&id) {
+            Some(node) => base_degree + node.degree(),
+            None => base_degree,
+        }
+    }
+
+    #[inline]
+    fn neighbors_iter(&self, id: Id) -> Iter<Id> {
+        if !self.has_node(id) {
+            panic!("Node {:?} do not exist.", id);
+        }
+
+        let base_neighbors = if self.in_base(id) {
+            Iter::new(Box::new(
+                self.base
+                    .neighbors_iter(id)
+                    .filter(move |n| !self.removed_edges.contains(&(id, *n))),
+            ))
+        } else {
+            Iter::empty()
+        };
+
+        match self.delta.get(&id) {
+            Some(node) => Iter::new(Box::new(
+                base_neighbors.merge(node.neighbors.keys().cloned()),
+            )),
+            None => base_neighbors,
+        }
+    }
+
+    #[inline]
+    fn neighbors(&self, id: Id) -> Cow<[Id]> {
+        if !self.delta.contains_key(&id) && self.in_base(id) && self.num_removed_edges(id) == 0 {
+            self.base.neighbors(id)
+        } else {
+            self.neighbors_iter(id).collect::<Vec<_>>().into()
+        }
+    }
+
+    #[inline]
+    fn max_seen_id(&self) -> Option<Id> {
+        self.max_id
+    }
+
+    #[inline(always)]
+    fn implementation(&self) -> GraphImpl {
+        GraphImpl::DeltaGraph
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> GraphLabelTrait<Id, NL, EL, L> for TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    #[inline(always)]
+    fn get_node_label_map(&self) -> &SetMap<NL> {
+        &self.node_label_map
+    }
+
+    #[inline(always)]
+    fn get_edge_label_map(&self) -> &SetMap<EL> {
+        &self.edge_label_map
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> MutGraphTrait<Id, NL, EL, L> for TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    /// Add a node with the given id and label. If the node already exists,
+    /// update its label and return `false`.
+    #[inline]
+    fn add_node(&mut self, id: Id, label: Option<NL>) -> bool {
+        let label_id = label.map(|x| L::new(self.node_label_map.add_item(x)));
+        let is_new = !self.has_node(id);
+
+        if is_new {
+            // The edges of a removed node are all hidden, so it comes back without any.
+            self.removed_nodes.remove(&id);
+            self.num_nodes += 1;
+
+            match self.max_id {
+                Some(i) if i >= id => {}
+                _ => self.max_id = Some(id),
+            }
+        }
+
+        self.touch(id).set_label_id(label_id);
+
+        is_new
+    }
+
+    /// Get the node in the delta, which is added with its current label if not there.
+    ///
+    /// *Note*: Only the label of the returned `NodeMap` is maintained, its neighbors are not.
+    #[inline]
+    fn get_node_mut(&mut self, id: Id) -> MutNodeType<Id, L> {
+        if !self.has_node(id) {
+            return MutNodeType::None;
+        }
+
+        MutNodeType::NodeMapRef(self.touch(id))
+    }
+
+    #[inline]
+    fn remove_node(&mut self, id: Id) -> OwnedNodeType<Id, L> {
+        if !self.has_node(id) {
+            return OwnedNodeType::None;
+        }
+
+        let mut node = NodeMap::new(id, self.get_node(id).get_label_id());
+
+        let neighbors = self.neighbors_iter(id).collect_vec();
+        for neighbor in neighbors {
+            if let OwnedEdgeType::Edge(edge) = self.remove_edge(id, neighbor) {
+                node.add_edge(neighbor, edge.get_label_id());
+            }
+        }
+
+        if self.is_directed() {
+            let in_neighbors = self.merged_in_neighbors_iter(id).collect_vec();
+            for in_neighbor in in_neighbors {
+                self.remove_edge(in_neighbor, id);
+                node.add_in_edge(in_neighbor);
+            }
+        }
+
+        self.delta.remove(&id);
+        if self.base.has_node(id) {
+            self.removed_nodes.insert(id);
+        }
+        self.num_nodes -= 1;
+
+        OwnedNodeType::NodeMap(node)
+    }
+
+    /// Add the edge with given `start` and `target` vertices.
+    /// If either end does not exist, add a new node with corresponding id
+    /// and `None` label. If the edge already presents, update its label and return `false`,
+    /// otherwise add the new edge and return `true`.
+    #[inline]
+    fn add_edge(&mut self, start: Id, target: Id, label: Option<EL>) -> bool {
+        if !self.has_node(start) {
+            self.add_node(start, None);
+        }
+        if !self.has_node(target) {
+            self.add_node(target, None);
+        }
+
+        let label_id = label.map(|x| L::new(self.edge_label_map.add_item(x)));
+        let is_new = !self.has_edge(start, target);
+
+        if self.base_has_edge(start, target) {
+            self.move_base_edge(start, target);
+        }
+        self.insert_delta_edge(start, target, label_id);
+
+        if is_new {
+            self.num_edges += 1;
+        }
+
+        is_new
+    }
+
+    /// Get the edge in the delta, which is moved from the base graph with its label
+    /// if not there.
+    #[inline]
+    fn get_edge_mut(&mut self, start: Id, target: Id) -> MutEdgeType<Id, L> {
+        if !self.has_edge(start, target) {
+            return MutEdgeType::None;
+        }
+
+        if self.base_has_edge(start, target) {
+            self.move_to_delta(start, target);
+        }
+
+        let node = self.delta.get_mut(&start).unwrap();
+        node.get_neighbor_mut(target)
+    }
+
+    #[inline]
+    fn remove_edge(&mut self, start: Id, target: Id) -> OwnedEdgeType<Id, L> {
+        if !self.has_edge(start, target) {
+            return OwnedEdgeType::None;
+        }
+
+        let label = self.get_edge(start, target).get_label_id();
+
+        if self.base_has_edge(start, target) {
+            self.hide_base_edge(start, target);
+        } else {
+            self.delta.get_mut(&start).unwrap().remove_edge(target);
+            self.moved_edges.remove(&(start, target));
+
+            let is_directed = self.is_directed();
+            if !is_directed {
+                self.moved_edges.remove(&(target, start));
+            }
+            let node = self.delta.get_mut(&target).unwrap();
+            if is_directed {
+                node.remove_in_edge(start);
+            } else {
+                node.remove_edge(start);
+            }
+        }
+
+        self.num_edges -= 1;
+
+        OwnedEdgeType::Edge(Edge::new(start, target, label))
+    }
+
+    /// *Note*: All nodes are added to the delta.
+    #[inline]
+    fn nodes_mut(&mut self) -> Iter<MutNodeType<Id, L>> {
+        let nodes = self.node_indices().collect_vec();
+        for node in nodes {
+            self.touch(node);
+        }
+
+        Iter::new(Box::new(
+            self.delta.values_mut().map(MutNodeType::NodeMapRef),
+        ))
+    }
+
+    /// *Note*: All edges are moved from the base graph to the delta.
+    #[inline]
+    fn edges_mut(&mut self) -> Iter<MutEdgeType<Id, L>> {
+        let base_edges = self
+            .base
+            .edge_indices()
+            .filter(|&(s, d)| self.base_has_edge(s, d))
+            .collect_vec();
+        for (s, d) in base_edges {
+            self.move_to_delta(s, d);
+        }
+
+        if self.is_directed() {
+            Iter::new(Box::new(
+                self.delta.values_mut().flat_map(|n| n.neighbors_iter_mut()),
+            ))
+        } else {
+            Iter::new(Box::new(
+                self.delta
+                    .values_mut()
+                    .flat_map(|n| n.non_less_neighbors_iter_mut()),
+            ))
+        }
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    G: GeneralGraph<Id, NL, EL, L>,
+> MutGraphLabelTrait<Id, NL, EL, L> for TypedDeltaGraph<Id, NL, EL, Ty, L, G>
+{
+    #[inline]
+    fn update_node_label(&mut self, node_id: Id, label: Option<NL>) -> bool {
+        if !self.has_node(node_id) {
+            return false;
+        }
+
+        self.add_node(node_id, label);
+
+        true
+    }
+
+    #[inline]
+    fn update_edge_label(&mut self, start: Id, target: Id, label: Option<EL>) -> bool {
+        if !self.has_edge(start, target) {
+            return false;
+        }
+
+        self.add_edge(start, target, label);
+
+        true
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, G: GeneralGraph<Id, NL, EL, L>>
+    UnGraphTrait<Id, L> for TypedUnDeltaGraph<Id, NL, EL, L, G>
+{}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, G: GeneralGraph<Id, NL, EL, L>>
+    DiGraphTrait<Id, L> for TypedDiDeltaGraph<Id, NL, EL, L, G>
+{
+    #[inline]
+    fn in_degree(&self, id: Id) -> usize {
+        if !self.has_node(id) {
+            panic!("Node {:?} do not exist.", id);
+        }
+
+        self.merged_in_neighbors_iter(id).count()
+    }
+
+    #[inline]
+    fn in_neighbors_iter(&self, id: Id) -> Iter<Id> {
+        if !self.has_node(id) {
+            panic!("Node {:?} do not exist.", id);
+        }
+
+        self.merged_in_neighbors_iter(id)
+    }
+
+    #[inline]
+    fn in_neighbors(&self, id: Id) -> Cow<[Id]> {
+        self.in_neighbors_iter(id).collect::<Vec<_>>().into()
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, G: GeneralGraph<Id, NL, EL, L>>
+    GeneralGraph<Id, NL, EL, L> for TypedUnDeltaGraph<Id, NL, EL, L, G>
+{
+    #[inline(always)]
+    fn as_graph(&self) -> &GraphTrait<Id, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_labeled_graph(&self) -> &GraphLabelTrait<Id, NL, EL, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_general_graph(&self) -> &GeneralGraph<Id, NL, EL, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_mut_graph(&mut self) -> Option<&mut MutGraphTrait<Id, NL, EL, L>> {
+        Some(self)
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, G: GeneralGraph<Id, NL, EL, L>>
+    GeneralGraph<Id, NL, EL, L> for TypedDiDeltaGraph<Id, NL, EL, L, G>
+{
+    #[inline(always)]
+    fn as_graph(&self) -> &GraphTrait<Id, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_labeled_graph(&self) -> &GraphLabelTrait<Id, NL, EL, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_general_graph(&self) -> &GeneralGraph<Id, NL, EL, L> {
+        self
+    }
+
+    #[inline(always)]
+    fn as_digraph(&self) -> Option<&DiGraphTrait<Id, L>> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_mut_graph(&mut self) -> Option<&mut MutGraphTrait<Id, NL, EL, L>> {
+        Some(self)
+    }
+}
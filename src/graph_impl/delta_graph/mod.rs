@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! A mutable overlay on top of a static graph, e.g. `StaticGraph` or `StaticGraphMmap`.
+//!
+//! The nodes and edges inserted or deleted are kept in an in-memory delta, which is merged
+//! with the static graph on the fly, so that a few updates do not mean rebuilding the
+//! whole graph. Call `compact()` to fold the delta into a new `StaticGraph`.
+//!
+//! # Example
+//! ```
+//! use rust_graph::prelude::*;
+//! use rust_graph::graph_impl::{EdgeVec, UnDeltaGraph};
+//! use rust_graph::UnStaticGraph;
+//!
+//! let edge_vec = EdgeVec::new(vec![0, 1, 2], vec![1, 0]);
+//! let g = UnStaticGraph::<&str>::new(edge_vec, None, None, None);
+//!
+//! let mut g = UnDeltaGraph::new(g);
+//! g.add_edge(1, 2, Some("edge label"));
+//! g.remove_edge(0, 1);
+//!
+//! let g = g.compact();
+//! assert_eq!(g.edge_count(), 1);
+//! ```
+
+pub mod graph;
+
+pub use graph_impl::delta_graph::graph::{
+    DeltaGraph, DiDeltaGraph, TypedDeltaGraph, TypedDiDeltaGraph, TypedUnDeltaGraph,
+    UnDeltaGraph,
+};
@@ -29,18 +29,21 @@ use itertools::Itertools;
 use serde;
 
 use generic::{
-    DefaultId, DefaultTy, DiGraphTrait, Directed, EdgeType, GeneralGraph, GraphLabelTrait,
-    GraphTrait, GraphType, IdType, Iter, MapTrait, MutEdgeType, MutGraphLabelTrait, MutGraphTrait,
-    MutMapTrait, MutNodeTrait, MutNodeType, NodeTrait, NodeType, OwnedEdgeType, OwnedNodeType,
-    UnGraphTrait, Undirected,
+    DefaultId, DefaultTy, DefaultWeight, DiGraphTrait, Directed, EdgeType, GeneralGraph,
+    GraphLabelTrait, GraphTrait, GraphType, GraphWeightTrait, IdType, Iter, MapTrait, MutEdgeType,
+    MutGraphLabelTrait, MutGraphTrait, MutGraphWeightTrait, MutMapTrait, MutNodeTrait, MutNodeType,
+    NodeTrait, NodeType, OwnedEdgeType, OwnedNodeType, UnGraphTrait, Undirected, WeightType,
 };
 use graph_impl::graph_map::{Edge, MutNodeMapTrait, NodeMap, NodeMapTrait};
+use graph_impl::static_graph::edge_vec::check_offset_type;
 use graph_impl::{EdgeVec, GraphImpl, TypedStaticGraph};
 use io::serde::{Deserialize, Serialize};
 use map::SetMap;
 
-pub type TypedDiGraphMap<Id, NL, EL = NL, L = DefaultId> = TypedGraphMap<Id, NL, EL, Directed, L>;
-pub type TypedUnGraphMap<Id, NL, EL = NL, L = DefaultId> = TypedGraphMap<Id, NL, EL, Undirected, L>;
+pub type TypedDiGraphMap<Id, NL, EL = NL, L = DefaultId, W = DefaultWeight> =
+    TypedGraphMap<Id, NL, EL, Directed, L, W>;
+pub type TypedUnGraphMap<Id, NL, EL = NL, L = DefaultId, W = DefaultWeight> =
+    TypedGraphMap<Id, NL, EL, Undirected, L, W>;
 pub type GraphMap<NL, EL, Ty = DefaultTy, L = DefaultId> = TypedGraphMap<DefaultId, NL, EL, Ty, L>;
 
 /// Shortcut of creating a new directed graph where `L` is the data type of labels.
@@ -71,7 +74,14 @@ pub fn new_general_graphmap<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq +
 
 /// A graph data structure that nodes and edges are stored in hash maps.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TypedGraphMap<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType = Id> {
+pub struct TypedGraphMap<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType = Id,
+    W: WeightType = DefaultWeight,
+> {
     /// A map <node_id:node>.
     node_map: FnvHashMap<Id, NodeMap<Id, L>>,
     /// Num of edges.
@@ -80,16 +90,20 @@ pub struct TypedGraphMap<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType
     node_label_map: SetMap<NL>,
     /// A map of edge labels.
     edge_label_map: SetMap<EL>,
+    /// The weights of edges, indexed by the weight slots kept in the adjacency lists.
+    edge_weights: Vec<Option<W>>,
+    /// The slots of `edge_weights` that have been freed for reuse.
+    free_weight_slots: Vec<usize>,
     /// The maximum id has been seen until now.
     max_id: Option<Id>,
     /// A marker of thr graph type, namely, directed or undirected.
     graph_type: PhantomData<Ty>,
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> PartialEq
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> PartialEq
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
-    fn eq(&self, other: &TypedGraphMap<Id, NL, EL, Ty, L>) -> bool {
+    fn eq(&self, other: &TypedGraphMap<Id, NL, EL, Ty, L, W>) -> bool {
         if !self.node_count() == other.node_count() || !self.edge_count() == other.edge_count() {
             return false;
         }
@@ -101,7 +115,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Partial
         }
 
         for (s, d) in self.edge_indices() {
-            if !other.has_edge(s, d) || self.get_edge_label(s, d) != other.get_edge_label(s, d) {
+            if !other.has_edge(s, d)
+                || self.get_edge_label(s, d) != other.get_edge_label(s, d)
+                || self.get_edge_weight(s, d) != other.get_edge_weight(s, d)
+            {
                 return false;
             }
         }
@@ -110,12 +127,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Partial
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Eq
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> Eq
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Hash
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> Hash
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
         {
@@ -140,26 +157,28 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Hash
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Serialize
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> Serialize
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 where
     Id: serde::Serialize,
     NL: serde::Serialize,
     EL: serde::Serialize,
     L: serde::Serialize,
+    W: serde::Serialize,
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Deserialize
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> Deserialize
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 where
     Id: for<'de> serde::Deserialize<'de>,
     NL: for<'de> serde::Deserialize<'de>,
     EL: for<'de> serde::Deserialize<'de>,
     L: for<'de> serde::Deserialize<'de>,
+    W: for<'de> serde::Deserialize<'de>,
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     /// Constructs a new graph.
     pub fn new() -> Self {
@@ -168,6 +187,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             num_of_edges: 0,
             node_label_map: SetMap::new(),
             edge_label_map: SetMap::new(),
+            edge_weights: Vec::new(),
+            free_weight_slots: Vec::new(),
             max_id: None,
             graph_type: PhantomData,
         }
@@ -179,6 +200,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             num_of_edges: 0,
             node_label_map: SetMap::with_capacity(node_labels),
             edge_label_map: SetMap::with_capacity(edge_labels),
+            edge_weights: Vec::new(),
+            free_weight_slots: Vec::new(),
             max_id: None,
             graph_type: PhantomData,
         }
@@ -186,6 +209,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
 
     pub fn shrink_to_fit(&mut self) {
         self.node_map.shrink_to_fit();
+        self.edge_weights.shrink_to_fit();
+        self.free_weight_slots.shrink_to_fit();
     }
 
     /// Constructs a new graph using existing label-id mapping.
@@ -215,6 +240,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             num_of_edges: 0,
             node_label_map,
             edge_label_map,
+            edge_weights: Vec::new(),
+            free_weight_slots: Vec::new(),
             max_id: None,
             graph_type: PhantomData,
         }
@@ -244,18 +271,57 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         let count = self.edge_indices().count();
         self.num_of_edges = count;
     }
+
+    /// Remove the weights of all edges.
+    pub fn remove_edge_weights(&mut self) {
+        for node in self.node_map.values_mut() {
+            for adjacency in node.neighbors.values_mut() {
+                adjacency.weight = None;
+            }
+        }
+        self.edge_weights = Vec::new();
+        self.free_weight_slots = Vec::new();
+    }
+
+    /// The slot of the weight of the edge (`start`, `target`), if it has one.
+    #[inline(always)]
+    fn weight_slot(&self, start: Id, target: Id) -> Option<usize> {
+        self.node_map
+            .get(&start)
+            .and_then(|node| node.weight_slot(target))
+    }
+
+    /// Store `weight` in a free slot and return the slot.
+    fn alloc_weight_slot(&mut self, weight: W) -> usize {
+        match self.free_weight_slots.pop() {
+            Some(slot) => {
+                self.edge_weights[slot] = Some(weight);
+                slot
+            }
+            None => {
+                self.edge_weights.push(Some(weight));
+                self.edge_weights.len() - 1
+            }
+        }
+    }
+
+    /// Drop the weight in `slot` and make the slot available for reuse.
+    fn free_weight_slot(&mut self, slot: usize) {
+        self.edge_weights[slot] = None;
+        self.free_weight_slots.push(slot);
+    }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Default
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType> Default
+    for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    MutGraphTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    MutGraphTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     /// Add a node with `id` and `label`. If the node of the `id` already presents,
     /// replace the node's label with the new `label` and return `false`.
@@ -299,6 +365,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     fn remove_node(&mut self, id: Id) -> OwnedNodeType<Id, L> {
         match self.node_map.remove(&id) {
             Some(node) => {
+                // The edges of an undirected graph share the slot in both directions.
+                let mut weight_slots: Vec<_> =
+                    node.neighbors.values().filter_map(|x| x.weight).collect();
+
                 if self.is_directed() {
                     for neighbor in node.neighbors_iter() {
                         let nodemap = self.node_map.get_mut(&neighbor).unwrap();
@@ -306,6 +376,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
                     }
                     for in_neighbor in node.in_neighbors_iter() {
                         let nodemap = self.node_map.get_mut(&in_neighbor).unwrap();
+                        weight_slots.extend(nodemap.weight_slot(id));
                         nodemap.remove_edge(id);
                     }
                 } else {
@@ -315,6 +386,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
                     }
                 }
 
+                for slot in weight_slots {
+                    self.free_weight_slot(slot);
+                }
+
                 self.num_of_edges -= node.degree() + node.in_degree();
 
                 OwnedNodeType::NodeMap(node)
@@ -374,6 +449,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             return OwnedEdgeType::None;
         }
 
+        if let Some(slot) = self.weight_slot(start, target) {
+            self.free_weight_slot(slot);
+        }
+
         let edge;
 
         {
@@ -419,8 +498,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTrait<Id, L>
-    for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    GraphTrait<Id, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     #[inline]
     fn get_node(&self, id: Id) -> NodeType<Id, L> {
@@ -552,8 +631,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTr
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    GraphLabelTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    GraphLabelTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     #[inline(always)]
     fn get_node_label_map(&self) -> &SetMap<NL> {
@@ -566,8 +645,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    MutGraphLabelTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    MutGraphLabelTrait<Id, NL, EL, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     #[inline]
     fn update_node_label(&mut self, node_id: Id, label: Option<NL>) -> bool {
@@ -592,12 +671,64 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> UnGraphTrait<Id, L>
-    for TypedUnGraphMap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    GraphWeightTrait<Id, W, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
+{
+    #[inline]
+    fn get_edge_weight(&self, start: Id, target: Id) -> Option<W> {
+        self.weight_slot(start, target)
+            .and_then(|slot| self.edge_weights[slot].clone())
+    }
+
+    #[inline(always)]
+    fn has_edge_weights(&self) -> bool {
+        self.edge_weights.len() > self.free_weight_slots.len()
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    MutGraphWeightTrait<Id, W, L> for TypedGraphMap<Id, NL, EL, Ty, L, W>
+{
+    #[inline]
+    fn update_edge_weight(&mut self, start: Id, target: Id, weight: Option<W>) -> bool {
+        if !self.has_edge(start, target) {
+            return false;
+        }
+
+        let slot = match (self.weight_slot(start, target), weight) {
+            (Some(slot), Some(w)) => {
+                self.edge_weights[slot] = Some(w);
+                return true;
+            }
+            (Some(slot), None) => {
+                self.free_weight_slot(slot);
+                None
+            }
+            (None, Some(w)) => Some(self.alloc_weight_slot(w)),
+            (None, None) => return true,
+        };
+
+        self.node_map
+            .get_mut(&start)
+            .unwrap()
+            .set_weight_slot(target, slot);
+        if !self.is_directed() {
+            self.node_map
+                .get_mut(&target)
+                .unwrap()
+                .set_weight_slot(start, slot);
+        }
+
+        true
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType> UnGraphTrait<Id, L>
+    for TypedUnGraphMap<Id, NL, EL, L, W>
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> DiGraphTrait<Id, L>
-    for TypedDiGraphMap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType> DiGraphTrait<Id, L>
+    for TypedDiGraphMap<Id, NL, EL, L, W>
 {
     #[inline]
     fn in_degree(&self, id: Id) -> usize {
@@ -627,8 +758,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> DiGraphTrait<Id, L>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, EL, L>
-    for TypedUnGraphMap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType> GeneralGraph<Id, NL, EL, L>
+    for TypedUnGraphMap<Id, NL, EL, L, W>
 {
     #[inline(always)]
     fn as_graph(&self) -> &GraphTrait<Id, L> {
@@ -651,8 +782,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, E
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, EL, L>
-    for TypedDiGraphMap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType> GeneralGraph<Id, NL, EL, L>
+    for TypedDiGraphMap<Id, NL, EL, L, W>
 {
     #[inline(always)]
     fn as_graph(&self) -> &GraphTrait<Id, L> {
@@ -680,15 +811,15 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, E
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    TypedGraphMap<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    TypedGraphMap<Id, NL, EL, Ty, L, W>
 {
     pub fn reorder_id(
         self,
         reorder_node_id: bool,
         reorder_node_label: bool,
         reorder_edge_label: bool,
-    ) -> ReorderResult<Id, NL, EL, Ty, L> {
+    ) -> ReorderResult<Id, NL, EL, Ty, L, W> {
         let node_id_map: Option<SetMap<_>> = if reorder_node_id {
             Some(
                 self.node_map
@@ -766,14 +897,14 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             let new_neighbors = if node_id_map.is_some() || edge_label_map.is_some() {
                 node.neighbors
                     .into_iter()
-                    .map(|(n, l)| {
+                    .map(|(n, mut adjacency)| {
                         let new_n = if let Some(ref map) = node_id_map {
                             Id::new(map.find_index(&n).unwrap())
                         } else {
                             n
                         };
 
-                        let new_l = l.map(|i| {
+                        adjacency.label = adjacency.label.map(|i| {
                             if let Some(ref map) = edge_label_map {
                                 L::new(map.find_index(&i).unwrap())
                             } else {
@@ -781,7 +912,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
                             }
                         });
 
-                        (new_n, new_l)
+                        (new_n, adjacency)
                     }).collect()
             } else {
                 node.neighbors
@@ -822,20 +953,38 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
 
         let new_max_id = new_node_map.keys().max().map(|i| *i);
 
+        // The weight slots move along with the adjacency lists, so the weights are kept as is.
         TypedGraphMap {
             node_map: new_node_map,
             num_of_edges,
             edge_label_map: new_edge_label_map,
             node_label_map: new_node_label_map,
+            edge_weights: self.edge_weights,
+            free_weight_slots: self.free_weight_slots,
             max_id: new_max_id,
             graph_type: PhantomData,
         }
     }
 
-    pub fn into_static(mut self) -> TypedStaticGraph<Id, NL, EL, Ty, L> {
+    pub fn into_static(self) -> TypedStaticGraph<Id, NL, EL, Ty, L, W> {
+        self.into_static_with_offset()
+    }
+
+    /// Convert into a `TypedStaticGraph` whose `EdgeVec`s have offsets of type `O`,
+    /// e.g. `u32` to save memory when the number of edges fits in it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of edges does not fit in `O`.
+    pub fn into_static_with_offset<O: IdType>(
+        mut self,
+    ) -> TypedStaticGraph<Id, NL, EL, Ty, L, W, EdgeVec<Id, L, O>> {
         let num_of_nodes = self.node_count();
         let num_of_edges = self.edge_count();
 
+        // Check before moving anything, as an undirected graph keeps each edge twice.
+        check_offset_type::<O>(self.node_map.values().map(|n| n.neighbors.len()).sum());
+
         let mut offset = 0usize;
         let mut offset_vec = Vec::new();
         let mut edge_vec = Vec::new();
@@ -851,6 +1000,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             None
         };
 
+        let mut edge_weights = if self.has_edge_weights() {
+            Some(Vec::new())
+        } else {
+            None
+        };
+
         let (mut in_offset, mut in_offset_vec, mut in_edge_vec) = if self.is_directed() {
             (Some(0usize), Some(Vec::new()), Some(Vec::new()))
         } else {
@@ -860,10 +1015,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         let mut nid = Id::new(0);
         let max_nid = self.node_indices().max().unwrap();
 
-        offset_vec.push(offset);
+        offset_vec.push(O::new(offset));
 
         if let (Some(_in_offset), Some(_in_offset_vec)) = (in_offset, in_offset_vec.as_mut()) {
-            _in_offset_vec.push(_in_offset);
+            _in_offset_vec.push(O::new(_in_offset));
         }
 
         while nid <= max_nid {
@@ -871,10 +1026,20 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
                 let neighbors = mem::replace(&mut node.neighbors, BTreeMap::new());
                 offset += neighbors.len();
 
+                if let Some(ref mut _edge_weights) = edge_weights {
+                    for adjacency in neighbors.values() {
+                        // An undirected edge is kept in both directions, so it can not be taken.
+                        _edge_weights.push(match adjacency.weight {
+                            Some(slot) => self.edge_weights[slot].clone(),
+                            None => None,
+                        });
+                    }
+                }
+
                 if let Some(ref mut _edge_labels) = edge_labels {
-                    for (n, l) in neighbors {
+                    for (n, adjacency) in neighbors {
                         edge_vec.push(n);
-                        _edge_labels.push(match l {
+                        _edge_labels.push(match adjacency.label {
                             Some(_l) => _l,
                             None => L::max_value(),
                         });
@@ -902,10 +1067,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
                 _node_labels.push(L::max_value());
             }
 
-            offset_vec.push(offset);
+            offset_vec.push(O::new(offset));
 
             if let (Some(_in_offset), Some(_in_offset_vec)) = (in_offset, in_offset_vec.as_mut()) {
-                _in_offset_vec.push(_in_offset);
+                _in_offset_vec.push(O::new(_in_offset));
             }
 
             nid = nid.increment();
@@ -933,7 +1098,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         let node_label_map = self.node_label_map;
         let edge_label_map = self.edge_label_map;
 
-        TypedStaticGraph::from_raw(
+        let g = TypedStaticGraph::from_raw(
             num_of_nodes,
             num_of_edges,
             edge_vec,
@@ -941,7 +1106,15 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             node_labels,
             node_label_map,
             edge_label_map,
-        )
+        );
+
+        match edge_weights {
+            Some(mut _edge_weights) => {
+                _edge_weights.shrink_to_fit();
+                g.with_edge_weights(_edge_weights)
+            }
+            None => g,
+        }
     }
 }
 
@@ -962,16 +1135,23 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ReorderResult<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> {
+pub struct ReorderResult<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType = DefaultWeight,
+> {
     node_id_map: Option<SetMap<Id>>,
-    graph: Option<TypedGraphMap<Id, NL, EL, Ty, L>>,
+    graph: Option<TypedGraphMap<Id, NL, EL, Ty, L, W>>,
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    ReorderResult<Id, NL, EL, Ty, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType, W: WeightType>
+    ReorderResult<Id, NL, EL, Ty, L, W>
 {
     #[inline]
-    pub fn take_graph(&mut self) -> Option<TypedGraphMap<Id, NL, EL, Ty, L>> {
+    pub fn take_graph(&mut self) -> Option<TypedGraphMap<Id, NL, EL, Ty, L, W>> {
         self.graph.take()
     }
 
@@ -27,10 +27,18 @@ use graph_impl::graph_map::{Edge, MutEdge};
 pub struct NodeMap<Id: IdType, L: IdType = Id> {
     pub(crate) id: Id,
     pub(crate) label: Option<L>,
-    pub(crate) neighbors: BTreeMap<Id, Option<L>>,
+    pub(crate) neighbors: BTreeMap<Id, Adjacency<L>>,
     pub(crate) in_neighbors: BTreeSet<Id>,
 }
 
+/// An entry in the adjacency list of a `NodeMap`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Adjacency<L: IdType> {
+    pub(crate) label: Option<L>,
+    /// The slot of the edge's weight in the weights of the graph, if the edge has one.
+    pub(crate) weight: Option<usize>,
+}
+
 impl<Id: IdType, L: IdType> NodeMap<Id, L> {
     #[inline(always)]
     pub fn new(id: Id, label: Option<L>) -> Self {
@@ -41,6 +49,20 @@ impl<Id: IdType, L: IdType> NodeMap<Id, L> {
             in_neighbors: BTreeSet::new(),
         }
     }
+
+    /// The slot of the weight of the edge to `adj`, if the edge exists and has a weight.
+    #[inline]
+    pub(crate) fn weight_slot(&self, adj: Id) -> Option<usize> {
+        self.neighbors.get(&adj).and_then(|x| x.weight)
+    }
+
+    /// Set the slot of the weight of the edge to `adj`, which must exist.
+    #[inline]
+    pub(crate) fn set_weight_slot(&mut self, adj: Id, slot: Option<usize>) {
+        if let Some(adjacency) = self.neighbors.get_mut(&adj) {
+            adjacency.weight = slot;
+        }
+    }
 }
 
 pub trait NodeMapTrait<Id: IdType, L: IdType> {
@@ -130,7 +152,7 @@ impl<Id: IdType, L: IdType> NodeMapTrait<Id, L> for NodeMap<Id, L> {
 
     #[inline]
     fn get_neighbor(&self, id: Id) -> Option<Option<L>> {
-        self.neighbors.get(&id).map(|x| *x)
+        self.neighbors.get(&id).map(|x| x.label)
     }
 
     #[inline]
@@ -145,7 +167,7 @@ impl<Id: IdType, L: IdType> NodeMapTrait<Id, L> for NodeMap<Id, L> {
         Iter::new(Box::new(
             self.neighbors
                 .iter()
-                .map(move |(&n, &l)| Edge::new(nid, n, l)),
+                .map(move |(&n, x)| Edge::new(nid, n, x.label)),
         ))
     }
 
@@ -156,7 +178,7 @@ impl<Id: IdType, L: IdType> NodeMapTrait<Id, L> for NodeMap<Id, L> {
         Iter::new(Box::new(
             self.neighbors
                 .range(self.get_id()..)
-                .map(move |(&n, &l)| Edge::new(nid, n, l)),
+                .map(move |(&n, x)| Edge::new(nid, n, x.label)),
         ))
     }
 }
@@ -175,12 +197,15 @@ impl<Id: IdType, L: IdType> MutNodeMapTrait<Id, L> for NodeMap<Id, L> {
     #[inline]
     fn add_edge(&mut self, adj: Id, label: Option<L>) -> bool {
         let mut result = false;
-        let edge_label = self.neighbors.entry(adj).or_insert_with(|| {
+        let adjacency = self.neighbors.entry(adj).or_insert_with(|| {
             result = true;
 
-            None
+            Adjacency {
+                label: None,
+                weight: None,
+            }
         });
-        *edge_label = label;
+        adjacency.label = label;
 
         result
     }
@@ -193,7 +218,7 @@ impl<Id: IdType, L: IdType> MutNodeMapTrait<Id, L> for NodeMap<Id, L> {
     #[inline]
     fn remove_edge(&mut self, adj: Id) -> OwnedEdgeType<Id, L> {
         match self.neighbors.remove(&adj) {
-            Some(edge) => OwnedEdgeType::Edge(Edge::new(self.get_id(), adj, edge)),
+            Some(edge) => OwnedEdgeType::Edge(Edge::new(self.get_id(), adj, edge.label)),
             None => OwnedEdgeType::None,
         }
     }
@@ -202,7 +227,7 @@ impl<Id: IdType, L: IdType> MutNodeMapTrait<Id, L> for NodeMap<Id, L> {
     fn get_neighbor_mut(&mut self, id: Id) -> MutEdgeType<Id, L> {
         let nid = self.get_id();
         match self.neighbors.get_mut(&id) {
-            Some(edge) => MutEdgeType::EdgeRef(MutEdge::new(nid, id, edge)),
+            Some(edge) => MutEdgeType::EdgeRef(MutEdge::new(nid, id, &mut edge.label)),
             None => MutEdgeType::None,
         }
     }
@@ -210,8 +235,8 @@ impl<Id: IdType, L: IdType> MutNodeMapTrait<Id, L> for NodeMap<Id, L> {
     #[inline]
     fn neighbors_iter_mut(&mut self) -> Iter<MutEdgeType<Id, L>> {
         let nid = self.get_id();
-        Iter::new(Box::new(self.neighbors.iter_mut().map(move |(n, l)| {
-            MutEdgeType::EdgeRef(MutEdge::new(nid, *n, l))
+        Iter::new(Box::new(self.neighbors.iter_mut().map(move |(n, x)| {
+            MutEdgeType::EdgeRef(MutEdge::new(nid, *n, &mut x.label))
         })))
     }
 
@@ -219,7 +244,7 @@ impl<Id: IdType, L: IdType> MutNodeMapTrait<Id, L> for NodeMap<Id, L> {
     fn non_less_neighbors_iter_mut(&mut self) -> Iter<MutEdgeType<Id, L>> {
         let nid = self.get_id();
         Iter::new(Box::new(self.neighbors.range_mut(self.id..).map(
-            move |(n, l)| MutEdgeType::EdgeRef(MutEdge::new(nid, *n, l)),
+            move |(n, x)| MutEdgeType::EdgeRef(MutEdge::new(nid, *n, &mut x.label)),
         )))
     }
 }
@@ -18,9 +18,14 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+pub mod delta_graph;
 pub mod graph_map;
 pub mod static_graph;
 
+pub use graph_impl::delta_graph::{
+    DeltaGraph, DiDeltaGraph, TypedDeltaGraph, TypedDiDeltaGraph, TypedUnDeltaGraph,
+    UnDeltaGraph,
+};
 pub use graph_impl::graph_map::{
     DiGraphMap, Edge, GraphMap, MutEdge, TypedDiGraphMap, TypedGraphMap, TypedUnGraphMap,
     UnGraphMap,
@@ -36,6 +41,7 @@ pub enum GraphImpl {
     GraphMap,
     StaticGraph,
     StaicGraphMmap,
+    DeltaGraph,
 }
 
 impl ::std::str::FromStr for GraphImpl {
@@ -47,6 +53,7 @@ impl ::std::str::FromStr for GraphImpl {
             "graphmap" => Ok(GraphImpl::GraphMap),
             "staticgraph" => Ok(GraphImpl::StaticGraph),
             "staticgraphmmap" => Ok(GraphImpl::StaicGraphMmap),
+            "deltagraph" => Ok(GraphImpl::DeltaGraph),
             _other => Err(format!("Unsupported implementation {:?}", _other)),
         }
     }
@@ -0,0 +1,430 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Build a `TypedStaticGraph` directly from a stream of nodes and edges, e.g. the ones of
+//! `CSVReader::node_iter` and `CSVReader::edge_iter`, without going through `GraphMap`.
+//!
+//! The edges are sorted in runs of a bounded size, which are spilled to temporary files
+//! once full, and merged into `EdgeVec` in the end. Thus, only the final graph has to fit
+//! in memory, but not the edges on their way.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fs::{remove_file, File};
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::marker::PhantomData;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fixedbitset::FixedBitSet;
+
+use error::Result;
+use generic::{GraphType, IdType, MutMapTrait};
+use graph_impl::static_graph::{EdgeVec, TypedStaticGraph};
+use map::SetMap;
+
+/// The number of edges sorted in memory before being spilled, by default.
+pub const DEFAULT_RUN_SIZE: usize = 1 << 24;
+
+/// Used to name the runs of all builders in the process uniquely.
+static NUM_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// A builder of `TypedStaticGraph`, whose node ids are kept as they are.
+///
+/// Nodes that only appear in edges are added without label. Duplicate edges are kept once,
+/// with the smallest of their label ids if any is labeled.
+///
+/// # Example
+/// ```
+/// use rust_graph::prelude::*;
+/// use rust_graph::graph_impl::static_graph::StaticGraphBuilder;
+/// use rust_graph::generic::{DefaultId, Undirected};
+///
+/// let mut builder = StaticGraphBuilder::<DefaultId, &str, &str, Undirected>::new();
+/// builder.add_node(0, Some("a"));
+/// builder.add_edge(0, 1, None).unwrap();
+/// builder.add_edge(1, 2, Some("b")).unwrap();
+///
+/// let g = builder.build().unwrap();
+/// assert_eq!(g.edge_count(), 2);
+/// ```
+pub struct StaticGraphBuilder<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType = Id,
+> {
+    nodes: FixedBitSet,
+    // The largest node id plus one.
+    num_ids: usize,
+    labels: Vec<L>,
+    out_edges: ExternalSorter<Id, L>,
+    // Sorted by target for directed graph, `None` for undirected.
+    in_edges: Option<ExternalSorter<Id, L>>,
+    node_label_map: SetMap<NL>,
+    edge_label_map: SetMap<EL>,
+    graph_type: PhantomData<Ty>,
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
+    StaticGraphBuilder<Id, NL, EL, Ty, L>
+{
+    pub fn new() -> Self {
+        StaticGraphBuilder {
+            nodes: FixedBitSet::with_capacity(0),
+            num_ids: 0,
+            labels: Vec::new(),
+            out_edges: ExternalSorter::new(),
+            in_edges: if Ty::is_directed() {
+                Some(ExternalSorter::new())
+            } else {
+                None
+            },
+            node_label_map: SetMap::new(),
+            edge_label_map: SetMap::new(),
+            graph_type: PhantomData,
+        }
+    }
+
+    /// Set the number of edges sorted in memory before being spilled to a temporary file.
+    pub fn run_size(mut self, run_size: usize) -> Self {
+        if run_size == 0 {
+            panic!("The run size should be positive.");
+        }
+
+        self.out_edges.run_size = run_size;
+        if let Some(ref mut in_edges) = self.in_edges {
+            in_edges.run_size = run_size;
+        }
+        self
+    }
+
+    /// Set the directory of the temporary files, which is `std::env::temp_dir()` by default.
+    pub fn spill_dir<P: AsRef<Path>>(mut self, spill_dir: P) -> Self {
+        self.out_edges.spill_dir = spill_dir.as_ref().to_path_buf();
+        if let Some(ref mut in_edges) = self.in_edges {
+            in_edges.spill_dir = spill_dir.as_ref().to_path_buf();
+        }
+        self
+    }
+
+    /// Add a node with the given id and label. If the node is already added,
+    /// its label is replaced.
+    pub fn add_node(&mut self, id: Id, label: Option<NL>) {
+        self.insert_node(id);
+
+        match label {
+            Some(label) => {
+                let label_id = L::new(self.node_label_map.add_item(label));
+                if self.labels.len() <= id.id() {
+                    self.labels.resize(id.id() + 1, L::max_value());
+                }
+                self.labels[id.id()] = label_id;
+            }
+            None => {
+                if let Some(label_id) = self.labels.get_mut(id.id()) {
+                    *label_id = L::max_value();
+                }
+            }
+        }
+    }
+
+    pub fn add_nodes<I: IntoIterator<Item = (Id, Option<NL>)>>(&mut self, nodes: I) {
+        for (id, label) in nodes {
+            self.add_node(id, label);
+        }
+    }
+
+    /// Add the edge (`start`, `target`) with the given label, which may spill the edges
+    /// sorted so far to a temporary file.
+    pub fn add_edge(&mut self, start: Id, target: Id, label: Option<EL>) -> Result<()> {
+        self.insert_node(start);
+        self.insert_node(target);
+
+        let label_id = match label {
+            Some(label) => L::new(self.edge_label_map.add_item(label)),
+            None => L::max_value(),
+        };
+
+        self.out_edges.push((start, target, label_id))?;
+
+        match self.in_edges {
+            Some(ref mut in_edges) => in_edges.push((target, start, label_id))?,
+            None if start != target => self.out_edges.push((target, start, label_id))?,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn add_edges<I: IntoIterator<Item = (Id, Id, Option<EL>)>>(
+        &mut self,
+        edges: I,
+    ) -> Result<()> {
+        for (start, target, label) in edges {
+            self.add_edge(start, target, label)?;
+        }
+
+        Ok(())
+    }
+
+    /// The number of distinct nodes added so far, including the ends of edges.
+    pub fn node_count(&self) -> usize {
+        self.nodes.count_ones(..)
+    }
+
+    /// Merge the sorted runs into the `EdgeVec`s of the graph, whose nodes are indexed
+    /// from 0 to the largest node id.
+    pub fn build(mut self) -> Result<TypedStaticGraph<Id, NL, EL, Ty, L>> {
+        let num_ids = self.num_ids;
+        let is_directed = Ty::is_directed();
+
+        let mut num_edges = 0;
+        let (offsets, edges, edge_labels) = self.out_edges.merge_into_csr(num_ids, |s, t| {
+            if is_directed || s <= t {
+                num_edges += 1;
+            }
+        })?;
+
+        let edge_labels = if edge_labels.iter().any(|l| *l != L::max_value()) {
+            Some(edge_labels)
+        } else {
+            None
+        };
+
+        let in_edge_vec = match self.in_edges {
+            Some(ref mut in_edges) => {
+                let (offsets, edges, _) = in_edges.merge_into_csr(num_ids, |_, _| {})?;
+                Some(EdgeVec::new(offsets, edges))
+            }
+            None => None,
+        };
+
+        let labels = if self.labels.is_empty() {
+            None
+        } else {
+            let mut labels = mem::take(&mut self.labels);
+            labels.resize(num_ids, L::max_value());
+            Some(labels)
+        };
+
+        Ok(TypedStaticGraph::from_raw(
+            self.nodes.count_ones(..),
+            num_edges,
+            EdgeVec::from_raw(offsets, edges, edge_labels),
+            in_edge_vec,
+            labels,
+            mem::take(&mut self.node_label_map),
+            mem::take(&mut self.edge_label_map),
+        ))
+    }
+
+    #[inline]
+    fn insert_node(&mut self, id: Id) {
+        if self.num_ids <= id.id() {
+            self.num_ids = id.id() + 1;
+            if self.nodes.len() < self.num_ids {
+                let len = self.num_ids.max(self.nodes.len() * 2);
+                self.nodes.grow(len);
+            }
+        }
+        self.nodes.insert(id.id());
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Default
+    for StaticGraphBuilder<Id, NL, EL, Ty, L>
+{
+    fn default() -> Self {
+        StaticGraphBuilder::new()
+    }
+}
+
+type Run<Id, L> = Box<Iterator<Item = io::Result<(Id, Id, L)>>>;
+
+/// Sort `(start, target, label)` in runs of `run_size`, spilling each full run to a file.
+struct ExternalSorter<Id: IdType, L: IdType> {
+    buffer: Vec<(Id, Id, L)>,
+    runs: Vec<PathBuf>,
+    run_size: usize,
+    spill_dir: PathBuf,
+}
+
+impl<Id: IdType, L: IdType> ExternalSorter<Id, L> {
+    fn new() -> Self {
+        ExternalSorter {
+            buffer: Vec::new(),
+            runs: Vec::new(),
+            run_size: DEFAULT_RUN_SIZE,
+            spill_dir: env::temp_dir(),
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, edge: (Id, Id, L)) -> io::Result<()> {
+        self.buffer.push(edge);
+        if self.buffer.len() >= self.run_size {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    /// Sort the buffer, keeping the first of the edges with the same ends.
+    fn sort_buffer(&mut self) {
+        self.buffer.sort_unstable();
+        self.buffer.dedup_by_key(|&mut (s, t, _)| (s, t));
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.sort_buffer();
+
+        let path = self.spill_dir.join(format!(
+            "rust_graph_{}_{}.run",
+            process::id(),
+            NUM_RUNS.fetch_add(1, Ordering::SeqCst)
+        ));
+        // Recorded first, so that it is removed even if the writing fails.
+        self.runs.push(path.clone());
+
+        let mut writer = BufWriter::new(File::create(&path)?);
+        for &(s, t, l) in self.buffer.iter() {
+            for x in &[s.id(), t.id(), l.id()] {
+                writer.write_all(&(*x as u64).to_le_bytes())?;
+            }
+        }
+        writer.flush()?;
+
+        self.buffer.clear();
+
+        Ok(())
+    }
+
+    /// Merge all runs in order into offsets, targets and labels, calling **f**
+    /// on each distinct edge.
+    fn merge_into_csr<F: FnMut(Id, Id)>(
+        &mut self,
+        num_ids: usize,
+        mut f: F,
+    ) -> io::Result<(Vec<usize>, Vec<Id>, Vec<L>)> {
+        self.sort_buffer();
+
+        let mut sources: Vec<Run<Id, L>> = Vec::new();
+        for path in self.runs.iter() {
+            sources.push(Box::new(RunReader::new(File::open(path)?)));
+        }
+        sources.push(Box::new(mem::take(&mut self.buffer).into_iter().map(Ok)));
+
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (i, source) in sources.iter_mut().enumerate() {
+            if let Some(edge) = source.next() {
+                heap.push(Reverse((edge?, i)));
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(num_ids + 1);
+        let mut edges = Vec::new();
+        let mut labels = Vec::new();
+        let mut last = None;
+
+        offsets.push(0);
+
+        while let Some(Reverse(((s, t, l), i))) = heap.pop() {
+            if let Some(edge) = sources[i].next() {
+                heap.push(Reverse((edge?, i)));
+            }
+
+            // The duplicates are adjacent, the first of which has the smallest label.
+            if last == Some((s, t)) {
+                continue;
+            }
+            last = Some((s, t));
+
+            while offsets.len() <= s.id() {
+                offsets.push(edges.len());
+            }
+            edges.push(t);
+            labels.push(l);
+            f(s, t);
+        }
+
+        while offsets.len() <= num_ids {
+            offsets.push(edges.len());
+        }
+
+        Ok((offsets, edges, labels))
+    }
+}
+
+impl<Id: IdType, L: IdType> Drop for ExternalSorter<Id, L> {
+    fn drop(&mut self) {
+        for path in self.runs.iter() {
+            if let Err(e) = remove_file(path) {
+                warn!("Error when removing {:?}: {:?}", path, e);
+            }
+        }
+    }
+}
+
+/// Read a run spilled by `ExternalSorter`.
+struct RunReader<Id: IdType, L: IdType> {
+    reader: BufReader<File>,
+    _ph: PhantomData<(Id, L)>,
+}
+
+impl<Id: IdType, L: IdType> RunReader<Id, L> {
+    fn new(file: File) -> Self {
+        RunReader {
+            reader: BufReader::new(file),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<Id: IdType, L: IdType> Iterator for RunReader<Id, L> {
+    type Item = io::Result<(Id, Id, L)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = [0u8; 24];
+        if let Err(e) = self.reader.read_exact(&mut buf) {
+            return match e.kind() {
+                ErrorKind::UnexpectedEof => None,
+                _ => Some(Err(e)),
+            };
+        }
+
+        let mut values = [0usize; 3];
+        for (i, value) in values.iter_mut().enumerate() {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&buf[i * 8..(i + 1) * 8]);
+            *value = u64::from_le_bytes(bytes) as usize;
+        }
+
+        Some(Ok((
+            Id::new(values[0]),
+            Id::new(values[1]),
+            L::new(values[2]),
+        )))
+    }
+}
@@ -0,0 +1,366 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! A compressed version of `EdgeVec`, which keeps the neighbors of each node gap-encoded
+//! as varints, for graphs that are too large to fit in memory as plain `Id`s.
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::marker::PhantomData;
+
+use generic::{IdType, Iter};
+use graph_impl::static_graph::{AdjacencyTrait, EdgeVec, MutAdjacencyTrait};
+use io::mmap::{dump, typed_as_byte_slice};
+
+/// The number of nodes between two sampled edge offsets.
+const EDGE_OFFSET_INTERVAL: usize = 64;
+
+/// With the node indexed from 0 .. num_nodes - 1, the neighbors of `node` are encoded in
+/// `bytes[byte_offsets[node]]` (included) to `bytes[byte_offsets[node + 1]]` (excluded).
+///
+/// The bytes of each node start with its degree, followed by the gaps between its sorted
+/// neighbors, each as a varint (LEB128) of 7 bits per byte. The first neighbor is stored as
+/// the zigzag-encoded difference to the node itself, which is small for graphs with locality.
+///
+/// Edges have the same index as in `EdgeVec`, which edge labels and weights are aligned with.
+/// Instead of keeping the edge offset of every node, it is sampled every
+/// `EDGE_OFFSET_INTERVAL` nodes, and the rest is recovered from the degrees in between.
+///
+/// *Note*: As in `EdgeVec`, the neighbors of each node must be sorted.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CompressedEdgeVec<Id: IdType, L: IdType = Id> {
+    byte_offsets: Vec<usize>,
+    edge_offsets: Vec<usize>,
+    num_edges: usize,
+    bytes: Vec<u8>,
+    labels: Option<Vec<L>>,
+    _id: PhantomData<Id>,
+}
+
+impl<Id: IdType, L: IdType> CompressedEdgeVec<Id, L> {
+    /// Compress the given edges, panic if the neighbors of any node are not sorted.
+    pub fn from_edge_vec<E: AdjacencyTrait<Id, L>>(edges: &E) -> Self {
+        let num_nodes = edges.num_nodes();
+        let mut byte_offsets = Vec::with_capacity(num_nodes + 1);
+        let mut edge_offsets = Vec::with_capacity(num_nodes / EDGE_OFFSET_INTERVAL + 1);
+        let mut num_edges = 0;
+        let mut bytes = Vec::new();
+
+        byte_offsets.push(0);
+        for n in 0..num_nodes {
+            let node = Id::new(n);
+            if n % EDGE_OFFSET_INTERVAL == 0 {
+                edge_offsets.push(num_edges);
+            }
+
+            let degree = edges.degree(node);
+            write_varint(&mut bytes, degree as u64);
+
+            let mut prev: Option<Id> = None;
+            for nbr in edges.neighbors_iter(node) {
+                match prev {
+                    None => write_varint(&mut bytes, zigzag(nbr.id() as i64 - n as i64)),
+                    Some(prev) if nbr < prev => {
+                        panic!("The neighbors of node {:?} are not sorted.", node)
+                    }
+                    Some(prev) => write_varint(&mut bytes, (nbr.id() - prev.id()) as u64),
+                }
+                prev = Some(nbr);
+            }
+
+            num_edges += degree;
+            byte_offsets.push(bytes.len());
+        }
+
+        let labels = edges.get_labels();
+
+        CompressedEdgeVec {
+            byte_offsets,
+            edge_offsets,
+            num_edges,
+            bytes,
+            labels: if labels.is_empty() {
+                None
+            } else {
+                Some(labels.to_vec())
+            },
+            _id: PhantomData,
+        }
+    }
+
+    /// Decompress into a plain `EdgeVec`.
+    pub fn to_edge_vec(&self) -> EdgeVec<Id, L> {
+        let mut offsets = Vec::with_capacity(self.num_nodes() + 1);
+        let mut edges = Vec::with_capacity(self.num_edges());
+
+        offsets.push(0);
+        for n in 0..self.num_nodes() {
+            edges.extend(self.decode(Id::new(n)));
+            offsets.push(edges.len());
+        }
+
+        EdgeVec::from_raw(offsets, edges, self.labels.clone())
+    }
+
+    /// The number of bytes of the encoded neighbors.
+    #[inline]
+    pub fn num_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Decode the neighbors of `node` in order. The decoding stops early if the bytes
+    /// of `node` turn out to be malformed.
+    #[inline]
+    pub fn decode(&self, node: Id) -> NeighborDecoder<Id> {
+        assert!(self.has_node(node));
+        let mut bytes = self.node_bytes(node.id());
+        let remaining = read_varint(&mut bytes).unwrap_or(0) as usize;
+
+        NeighborDecoder {
+            bytes,
+            node: node.id(),
+            prev: None,
+            remaining,
+            _id: PhantomData,
+        }
+    }
+
+    /// The encoded bytes of node `n`, or nothing if `byte_offsets` is out of range.
+    #[inline]
+    fn node_bytes(&self, n: usize) -> &[u8] {
+        match (self.byte_offsets.get(n), self.byte_offsets.get(n + 1)) {
+            (Some(&start), Some(&end)) => self.bytes.get(start..end).unwrap_or(&[]),
+            _ => &[],
+        }
+    }
+
+    /// The index of the first edge of `node`, summing up the degrees from the nearest
+    /// sampled edge offset.
+    #[inline]
+    fn edge_offset(&self, node: Id) -> usize {
+        let n = node.id();
+        let sampled = n / EDGE_OFFSET_INTERVAL;
+
+        (sampled * EDGE_OFFSET_INTERVAL..n).fold(self.edge_offsets[sampled], |offset, m| {
+            offset + self.degree(Id::new(m))
+        })
+    }
+}
+
+impl<Id: IdType, L: IdType> AdjacencyTrait<Id, L> for CompressedEdgeVec<Id, L> {
+    #[inline]
+    fn num_nodes(&self) -> usize {
+        self.byte_offsets.len() - 1
+    }
+
+    #[inline]
+    fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    #[inline]
+    fn degree(&self, node: Id) -> usize {
+        assert!(self.has_node(node));
+        read_varint(&mut self.node_bytes(node.id())).unwrap_or(0) as usize
+    }
+
+    /// Decode the neighbors of `node` into a new vector, prefer `neighbors_iter`
+    /// if they are only iterated over.
+    #[inline]
+    fn neighbors(&self, node: Id) -> Cow<[Id]> {
+        self.decode(node).collect::<Vec<_>>().into()
+    }
+
+    #[inline]
+    fn neighbors_iter(&self, node: Id) -> Iter<Id> {
+        Iter::new(Box::new(self.decode(node)))
+    }
+
+    /// Decode the neighbors of `start` until `target` is passed, as they are sorted.
+    #[inline]
+    fn find_edge_index(&self, start: Id, target: Id) -> Option<usize> {
+        if !(self.has_node(start) && self.has_node(target)) {
+            return None;
+        }
+
+        for (idx, nbr) in self.decode(start).enumerate() {
+            if nbr == target {
+                return Some(self.edge_offset(start) + idx);
+            }
+            if nbr > target {
+                break;
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn get_labels(&self) -> &[L] {
+        match self.labels {
+            Some(ref labels) => &labels[..],
+            None => &[],
+        }
+    }
+}
+
+impl<Id: IdType, L: IdType> MutAdjacencyTrait<Id, L> for CompressedEdgeVec<Id, L> {
+    type Offset = usize;
+
+    fn remove_labels(&mut self) {
+        self.labels = None;
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.byte_offsets.shrink_to_fit();
+        self.edge_offsets.shrink_to_fit();
+        self.bytes.shrink_to_fit();
+        if let Some(ref mut labels) = self.labels {
+            labels.shrink_to_fit();
+        }
+    }
+
+    /// Decode the neighbors node by node into the `.edges` file, so that the whole
+    /// `EdgeVec` is never materialised.
+    fn dump_mmap(&self, prefix: &str) -> Result<()> {
+        let offsets_file = format!("{}.offsets", prefix);
+        let edges_file = format!("{}.edges", prefix);
+        let labels_file = format!("{}.labels", prefix);
+
+        let mut offsets = Vec::with_capacity(self.num_nodes() + 1);
+        let mut writer = BufWriter::new(File::create(edges_file)?);
+        let mut neighbors = Vec::new();
+        let mut offset = 0;
+
+        offsets.push(offset);
+        for n in 0..self.num_nodes() {
+            neighbors.clear();
+            neighbors.extend(self.decode(Id::new(n)));
+            writer.write_all(typed_as_byte_slice(&neighbors))?;
+            offset += neighbors.len();
+            offsets.push(offset);
+        }
+        writer.flush()?;
+
+        unsafe {
+            dump(&offsets, File::create(offsets_file)?)?;
+        }
+
+        if let Some(ref labels) = self.labels {
+            unsafe { dump(labels, File::create(labels_file)?) }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<Id: IdType, L: IdType> Default for CompressedEdgeVec<Id, L> {
+    fn default() -> Self {
+        CompressedEdgeVec::from_edge_vec(&EdgeVec::<Id, L>::new(vec![0], Vec::new()))
+    }
+}
+
+/// An iterator decoding the neighbors of a node in a `CompressedEdgeVec`.
+pub struct NeighborDecoder<'a, Id: IdType> {
+    bytes: &'a [u8],
+    node: usize,
+    prev: Option<usize>,
+    remaining: usize,
+    _id: PhantomData<Id>,
+}
+
+impl<'a, Id: IdType> Iterator for NeighborDecoder<'a, Id> {
+    type Item = Id;
+
+    #[inline]
+    fn next(&mut self) -> Option<Id> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let nbr = read_varint(&mut self.bytes).and_then(|value| match self.prev {
+            Some(prev) => prev.checked_add(value as usize),
+            None => {
+                let nbr = self.node as i64 + unzigzag(value);
+                if nbr < 0 {
+                    None
+                } else {
+                    Some(nbr as usize)
+                }
+            }
+        });
+
+        match nbr {
+            Some(nbr) => {
+                self.remaining -= 1;
+                self.prev = Some(nbr);
+                Some(Id::new(nbr))
+            }
+            None => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+#[inline]
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[inline]
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[inline]
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Read a varint from the front of `bytes`, or `None` if it is truncated or overflows `u64`.
+#[inline]
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+
+        if shift >= 64 || (shift == 63 && byte > 1) {
+            return None;
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
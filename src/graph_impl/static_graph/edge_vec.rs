@@ -18,8 +18,10 @@
  * specific language governing permissions and limitations
  * under the License.
  */
-use generic::IdType;
+use generic::{IdType, Iter};
+use graph_impl::static_graph::static_edge_iter::StaticEdgeIndexIter;
 use io::mmap::dump;
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::Result;
 
@@ -31,45 +33,40 @@ use std::io::Result;
 /// *Note*: The edges must be sorted according to the starting node, that is,
 /// The sub-vector `edges[offsets[node]]` (included) - `edges[offsets[node + 1]]` (excluded)
 /// for any `node` should be sorted.
+///
+/// The offsets are of type `O`, which defaults to `usize`. As they are as many as the nodes,
+/// a narrower type such as `u32` saves a good share of memory for a graph whose number of
+/// edges fits in it.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub struct EdgeVec<Id: IdType, L: IdType = Id> {
-    offsets: Vec<usize>,
+pub struct EdgeVec<Id: IdType, L: IdType = Id, O: IdType = usize> {
+    offsets: Vec<O>,
     edges: Vec<Id>,
     labels: Option<Vec<L>>,
 }
 
-pub trait EdgeVecTrait<Id: IdType, L: IdType> {
-    fn get_offsets(&self) -> &[usize];
-    fn get_edges(&self) -> &[Id];
-    fn get_labels(&self) -> &[L];
+/// The adjacency lists of a static graph, with the node indexed from 0 .. num_nodes - 1.
+/// This is the API shared by `EdgeVec` and `EdgeVecMmap`, whose neighbors are stored as plain
+/// slices, and by `CompressedEdgeVec`, whose neighbors are decoded on the fly.
+///
+/// The edges are identified by their index, namely the index of the i-th neighbor of `start`
+/// is the number of edges of the nodes before `start` plus i, which the edge labels (and the
+/// edge weights of a graph) are aligned with.
+pub trait AdjacencyTrait<Id: IdType, L: IdType> {
+    fn num_nodes(&self) -> usize;
 
-    #[inline]
-    fn num_nodes(&self) -> usize {
-        self.get_offsets().len() - 1
-    }
+    fn num_edges(&self) -> usize;
 
-    #[inline]
-    fn num_edges(&self) -> usize {
-        self.get_edges().len()
-    }
+    fn degree(&self, node: Id) -> usize;
 
-    #[inline]
-    fn neighbors(&self, node: Id) -> &[Id] {
-        assert!(self.has_node(node));
-        let start = self.get_offsets()[node.id()].id();
-        let end = self.get_offsets()[node.id() + 1].id();
+    /// The sorted neighbors of `node`, borrowed if they are stored as a plain slice.
+    fn neighbors(&self, node: Id) -> Cow<[Id]>;
 
-        &self.get_edges()[start..end]
-    }
+    /// Iterate over the sorted neighbors of `node`, without collecting them.
+    fn neighbors_iter(&self, node: Id) -> Iter<Id>;
 
-    #[inline]
-    fn degree(&self, node: Id) -> usize {
-        assert!(self.has_node(node));
-        let start = self.get_offsets()[node.id()].id();
-        let end = self.get_offsets()[node.id() + 1].id();
+    fn find_edge_index(&self, start: Id, target: Id) -> Option<usize>;
 
-        end - start
-    }
+    fn get_labels(&self) -> &[L];
 
     #[inline]
     fn has_node(&self, node: Id) -> bool {
@@ -77,17 +74,8 @@ pub trait EdgeVecTrait<Id: IdType, L: IdType> {
     }
 
     #[inline]
-    fn find_edge_index(&self, start: Id, target: Id) -> Option<usize> {
-        if !(self.has_node(start) && self.has_node(target)) {
-            None
-        } else {
-            let neighbors = self.neighbors(start);
-            let found = neighbors.binary_search(&target);
-            match found {
-                Err(_) => None,
-                Ok(idx) => Some(self.get_offsets()[start.id()].id() + idx),
-            }
-        }
+    fn has_edge(&self, start: Id, target: Id) -> bool {
+        self.find_edge_index(start, target).is_some()
     }
 
     #[inline]
@@ -98,21 +86,123 @@ pub trait EdgeVecTrait<Id: IdType, L: IdType> {
             return None;
         }
 
-        let idx_opt = self.find_edge_index(start, target);
-        match idx_opt {
+        match self.find_edge_index(start, target) {
             None => None,
             Some(idx) => labels.get(idx),
         }
     }
 
+    /// Iterate over the edges as `(start, target)`. If the graph is undirected,
+    /// each edge is only returned once, with `start <= target`.
+    fn edge_indices(&self, is_directed: bool) -> Iter<(Id, Id)> {
+        Iter::new(Box::new((0..self.num_nodes()).flat_map(move |n| {
+            let node = Id::new(n);
+            self.neighbors_iter(node)
+                .filter(move |&nbr| is_directed || nbr >= node)
+                .map(move |nbr| (node, nbr))
+        })))
+    }
+}
+
+/// The adjacency lists owned by a `TypedStaticGraph`, which can be modified and dumped.
+pub trait MutAdjacencyTrait<Id: IdType, L: IdType>: AdjacencyTrait<Id, L> {
+    /// The type of the offsets written by `dump_mmap`.
+    type Offset: IdType;
+
+    fn remove_labels(&mut self);
+
+    fn shrink_to_fit(&mut self);
+
+    /// Dump self in the layout of `EdgeVec::dump_mmap`, in order to be loaded as `EdgeVecMmap`.
+    fn dump_mmap(&self, prefix: &str) -> Result<()>;
+}
+
+/// The adjacency lists kept as plain slices, using `offset` and `edges`, in which
+/// `offset[node]` maintain the start index of the given node's neighbors in `edges`.
+pub trait EdgeVecTrait<Id: IdType, L: IdType, O: IdType = usize>: AdjacencyTrait<Id, L> {
+    fn get_offsets(&self) -> &[O];
+    fn get_edges(&self) -> &[Id];
+
+    /// The neighbors of `node` as a slice of `get_edges()`.
     #[inline]
-    fn has_edge(&self, start: Id, target: Id) -> bool {
-        self.find_edge_index(start, target).is_some()
+    fn neighbors_slice(&self, node: Id) -> &[Id] {
+        assert!(self.has_node(node));
+        let start = self.get_offsets()[node.id()].id();
+        let end = self.get_offsets()[node.id() + 1].id();
+
+        &self.get_edges()[start..end]
     }
 }
 
-impl<Id: IdType, L: IdType> EdgeVec<Id, L> {
-    pub fn new(offsets: Vec<usize>, edges: Vec<Id>) -> Self {
+/// Implement `AdjacencyTrait` on top of the slices of an `EdgeVecTrait`.
+macro_rules! impl_adjacency_trait {
+    ($type:ident) => {
+        impl<Id: IdType, L: IdType, O: IdType> AdjacencyTrait<Id, L> for $type<Id, L, O> {
+            #[inline]
+            fn num_nodes(&self) -> usize {
+                self.get_offsets().len() - 1
+            }
+
+            #[inline]
+            fn num_edges(&self) -> usize {
+                self.get_edges().len()
+            }
+
+            #[inline]
+            fn degree(&self, node: Id) -> usize {
+                assert!(self.has_node(node));
+                let start = self.get_offsets()[node.id()].id();
+                let end = self.get_offsets()[node.id() + 1].id();
+
+                end - start
+            }
+
+            #[inline]
+            fn neighbors(&self, node: Id) -> Cow<[Id]> {
+                self.neighbors_slice(node).into()
+            }
+
+            #[inline]
+            fn neighbors_iter(&self, node: Id) -> Iter<Id> {
+                Iter::new(Box::new(self.neighbors_slice(node).iter().cloned()))
+            }
+
+            #[inline]
+            fn find_edge_index(&self, start: Id, target: Id) -> Option<usize> {
+                if !(self.has_node(start) && self.has_node(target)) {
+                    None
+                } else {
+                    let neighbors = self.neighbors_slice(start);
+                    let found = neighbors.binary_search(&target);
+                    match found {
+                        Err(_) => None,
+                        Ok(idx) => Some(self.get_offsets()[start.id()].id() + idx),
+                    }
+                }
+            }
+
+            #[inline]
+            fn get_labels(&self) -> &[L] {
+                match self.labels {
+                    Some(ref labels) => &labels[..],
+                    None => &[],
+                }
+            }
+
+            #[inline]
+            fn edge_indices(&self, is_directed: bool) -> Iter<(Id, Id)> {
+                Iter::new(Box::new(StaticEdgeIndexIter::new(
+                    Box::new(self),
+                    is_directed,
+                )))
+            }
+        }
+    };
+}
+
+impl<Id: IdType, L: IdType, O: IdType> EdgeVec<Id, L, O> {
+    pub fn new(offsets: Vec<O>, edges: Vec<Id>) -> Self {
+        check_offset_type::<O>(edges.len());
         EdgeVec {
             offsets,
             edges,
@@ -120,7 +210,7 @@ impl<Id: IdType, L: IdType> EdgeVec<Id, L> {
         }
     }
 
-    pub fn with_labels(offsets: Vec<usize>, edges: Vec<Id>, labels: Vec<L>) -> Self {
+    pub fn with_labels(offsets: Vec<O>, edges: Vec<Id>, labels: Vec<L>) -> Self {
         if edges.len() != labels.len() {
             panic!(
                 "Unequal length: there are {} edges, but {} labels",
@@ -128,6 +218,7 @@ impl<Id: IdType, L: IdType> EdgeVec<Id, L> {
                 labels.len()
             );
         }
+        check_offset_type::<O>(edges.len());
         EdgeVec {
             offsets,
             edges,
@@ -135,13 +226,27 @@ impl<Id: IdType, L: IdType> EdgeVec<Id, L> {
         }
     }
 
-    pub fn from_raw(offsets: Vec<usize>, edges: Vec<Id>, labels: Option<Vec<L>>) -> Self {
+    pub fn from_raw(offsets: Vec<O>, edges: Vec<Id>, labels: Option<Vec<L>>) -> Self {
         match labels {
             Some(labels) => EdgeVec::with_labels(offsets, edges, labels),
             None => EdgeVec::new(offsets, edges),
         }
     }
 
+    /// Convert the offsets into type `O2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of edges does not fit in `O2`.
+    pub fn into_offset_type<O2: IdType>(self) -> EdgeVec<Id, L, O2> {
+        check_offset_type::<O2>(self.edges.len());
+        EdgeVec {
+            offsets: self.offsets.iter().map(|o| O2::new(o.id())).collect(),
+            edges: self.edges,
+            labels: self.labels,
+        }
+    }
+
     pub fn remove_labels(&mut self) {
         self.labels = None;
     }
@@ -172,18 +277,17 @@ impl<Id: IdType, L: IdType> EdgeVec<Id, L> {
             dump(self.get_offsets(), File::create(offsets_file)?)?;
             dump(self.get_edges(), File::create(edges_file)?)?;
 
-            if !self.get_labels().is_empty() {
-                dump(self.get_labels(), File::create(labels_file)?)
-            } else {
-                Ok(())
+            match self.labels {
+                Some(ref labels) if !labels.is_empty() => dump(labels, File::create(labels_file)?),
+                _ => Ok(()),
             }
         }
     }
 }
 
-impl<Id: IdType, L: IdType> EdgeVecTrait<Id, L> for EdgeVec<Id, L> {
+impl<Id: IdType, L: IdType, O: IdType> EdgeVecTrait<Id, L, O> for EdgeVec<Id, L, O> {
     #[inline]
-    fn get_offsets(&self) -> &[usize] {
+    fn get_offsets(&self) -> &[O] {
         &self.offsets
     }
 
@@ -191,18 +295,43 @@ impl<Id: IdType, L: IdType> EdgeVecTrait<Id, L> for EdgeVec<Id, L> {
     fn get_edges(&self) -> &[Id] {
         &self.edges
     }
+}
+
+impl_adjacency_trait!(EdgeVec);
+
+impl<Id: IdType, L: IdType, O: IdType> MutAdjacencyTrait<Id, L> for EdgeVec<Id, L, O> {
+    type Offset = O;
 
     #[inline]
-    fn get_labels(&self) -> &[L] {
-        match self.labels {
-            Some(ref labels) => &labels[..],
-            None => &[],
-        }
+    fn remove_labels(&mut self) {
+        EdgeVec::remove_labels(self)
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        EdgeVec::shrink_to_fit(self)
+    }
+
+    #[inline]
+    fn dump_mmap(&self, prefix: &str) -> Result<()> {
+        EdgeVec::dump_mmap(self, prefix)
     }
 }
 
-impl<Id: IdType, L: IdType> Default for EdgeVec<Id, L> {
+impl<Id: IdType, L: IdType, O: IdType> Default for EdgeVec<Id, L, O> {
     fn default() -> Self {
         EdgeVec::new(Vec::new(), Vec::new())
     }
 }
+
+/// Panic if `num_edges`, the largest offset, does not fit in `O`.
+#[inline]
+pub(crate) fn check_offset_type<O: IdType>(num_edges: usize) {
+    if num_edges > O::max_usize() {
+        panic!(
+            "{} edges do not fit in {}-byte offsets",
+            num_edges,
+            ::std::mem::size_of::<O>()
+        );
+    }
+}
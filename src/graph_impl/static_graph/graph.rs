@@ -22,25 +22,29 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
-use bincode::Result;
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use serde;
 
+use error::Result;
 use generic::{
-    DefaultId, DefaultTy, DiGraphTrait, Directed, EdgeType, GeneralGraph, GraphLabelTrait,
-    GraphTrait, GraphType, IdType, Iter, NodeType, UnGraphTrait, Undirected,
+    DefaultId, DefaultTy, DefaultWeight, DiGraphTrait, Directed, EdgeType, GeneralGraph,
+    GraphLabelTrait, GraphTrait, GraphType, GraphWeightTrait, IdType, Iter, NodeType, UnGraphTrait,
+    Undirected, WeightType,
 };
 use graph_impl::static_graph::mmap::graph_mmap::StaticGraphMmapAux;
+use graph_impl::static_graph::mmap::manifest::MmapManifest;
 use graph_impl::static_graph::node::StaticNode;
-use graph_impl::static_graph::static_edge_iter::StaticEdgeIndexIter;
-use graph_impl::static_graph::{EdgeVec, EdgeVecTrait};
+use graph_impl::static_graph::{CompressedEdgeVec, EdgeVec, MutAdjacencyTrait};
 use graph_impl::{Edge, GraphImpl};
 use io::mmap::dump;
 use io::serde::{Deserialize, Serialize, Serializer};
 use map::SetMap;
 
-pub type TypedUnStaticGraph<Id, NL, EL = NL, L = Id> = TypedStaticGraph<Id, NL, EL, Undirected, L>;
-pub type TypedDiStaticGraph<Id, NL, EL = NL, L = Id> = TypedStaticGraph<Id, NL, EL, Directed, L>;
+pub type TypedUnStaticGraph<Id, NL, EL = NL, L = Id, W = DefaultWeight, E = EdgeVec<Id, L>> =
+    TypedStaticGraph<Id, NL, EL, Undirected, L, W, E>;
+pub type TypedDiStaticGraph<Id, NL, EL = NL, L = Id, W = DefaultWeight, E = EdgeVec<Id, L>> =
+    TypedStaticGraph<Id, NL, EL, Directed, L, W, E>;
 pub type StaticGraph<NL, EL, Ty = DefaultTy, L = DefaultId> =
     TypedStaticGraph<DefaultId, NL, EL, Ty, L>;
 pub type UnStaticGraph<NL, EL = NL, L = DefaultId> = StaticGraph<NL, EL, Undirected, L>;
@@ -48,27 +52,48 @@ pub type DiStaticGraph<NL, EL = NL, L = DefaultId> = StaticGraph<NL, EL, Directe
 
 /// `StaticGraph` is a memory-compact graph data structure.
 /// The labels of both nodes and edges, if exist, are encoded as `Integer`.
+///
+/// The adjacency lists are kept in `E`, which is a plain `EdgeVec` by default,
+/// or a `CompressedEdgeVec` to trade the speed of accessing neighbors for memory.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct TypedStaticGraph<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType = Id>
-{
+pub struct TypedStaticGraph<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType = Id,
+    W: WeightType = DefaultWeight,
+    E: MutAdjacencyTrait<Id, L> = EdgeVec<Id, L>,
+> {
     num_nodes: usize,
     num_edges: usize,
-    edge_vec: EdgeVec<Id, L>,
-    in_edge_vec: Option<EdgeVec<Id, L>>,
+    edge_vec: E,
+    in_edge_vec: Option<E>,
     // Maintain the node's labels, whose index is aligned with `offsets`.
     labels: Option<Vec<L>>,
+    // Maintain the edge's weights, whose index is aligned with the edges of `edge_vec`.
+    edge_weights: Option<Vec<Option<W>>>,
     // A marker of thr graph type, namely, directed or undirected.
     graph_type: PhantomData<Ty>,
+    // A marker of the id type, which is only maintained by `edge_vec`.
+    id_type: PhantomData<Id>,
     // A map of node labels.
     node_label_map: SetMap<NL>,
     // A map of edge labels.
     edge_label_map: SetMap<EL>,
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> PartialEq
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> PartialEq for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {
-    fn eq(&self, other: &TypedStaticGraph<Id, NL, EL, Ty, L>) -> bool {
+    fn eq(&self, other: &TypedStaticGraph<Id, NL, EL, Ty, L, W, E>) -> bool {
         if !self.node_count() == other.node_count() || !self.edge_count() == other.edge_count() {
             return false;
         }
@@ -80,7 +105,10 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Partial
         }
 
         for (s, d) in self.edge_indices() {
-            if !other.has_edge(s, d) || self.get_edge_label(s, d) != other.get_edge_label(s, d) {
+            if !other.has_edge(s, d)
+                || self.get_edge_label(s, d) != other.get_edge_label(s, d)
+                || self.get_edge_weight(s, d) != other.get_edge_weight(s, d)
+            {
                 return false;
             }
         }
@@ -89,12 +117,26 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Partial
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Eq
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> Eq for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Hash
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> Hash for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
         {
@@ -118,30 +160,55 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Hash
         }
     }
 }
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Serialize
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> Serialize for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 where
     Id: serde::Serialize,
     NL: serde::Serialize,
     EL: serde::Serialize,
     L: serde::Serialize,
+    W: serde::Serialize,
+    E: serde::Serialize,
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> Deserialize
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> Deserialize for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 where
     Id: for<'de> serde::Deserialize<'de>,
     NL: for<'de> serde::Deserialize<'de>,
     EL: for<'de> serde::Deserialize<'de>,
     L: for<'de> serde::Deserialize<'de>,
+    W: for<'de> serde::Deserialize<'de>,
+    E: for<'de> serde::Deserialize<'de>,
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {
     pub fn new(
-        edges: EdgeVec<Id, L>,
-        in_edges: Option<EdgeVec<Id, L>>,
+        edges: E,
+        in_edges: Option<E>,
         num_nodes: Option<usize>,
         num_edges: Option<usize>,
     ) -> Self {
@@ -189,15 +256,17 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             edge_vec: edges,
             in_edge_vec: in_edges,
             labels: None,
+            edge_weights: None,
             node_label_map: SetMap::<NL>::new(),
             edge_label_map: SetMap::<EL>::new(),
             graph_type: PhantomData,
+            id_type: PhantomData,
         }
     }
 
     pub fn with_labels(
-        edges: EdgeVec<Id, L>,
-        in_edges: Option<EdgeVec<Id, L>>,
+        edges: E,
+        in_edges: Option<E>,
         labels: Vec<L>,
         node_label_map: SetMap<NL>,
         edge_label_map: SetMap<EL>,
@@ -252,17 +321,19 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             edge_vec: edges,
             in_edge_vec: in_edges,
             labels: Some(labels),
+            edge_weights: None,
             node_label_map,
             edge_label_map,
             graph_type: PhantomData,
+            id_type: PhantomData,
         }
     }
 
     pub fn from_raw(
         num_nodes: usize,
         num_edges: usize,
-        edge_vec: EdgeVec<Id, L>,
-        in_edge_vec: Option<EdgeVec<Id, L>>,
+        edge_vec: E,
+        in_edge_vec: Option<E>,
         labels: Option<Vec<L>>,
         node_label_map: SetMap<NL>,
         edge_label_map: SetMap<EL>,
@@ -322,19 +393,36 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
             edge_vec,
             in_edge_vec,
             labels,
+            edge_weights: None,
             node_label_map,
             edge_label_map,
             graph_type: PhantomData,
+            id_type: PhantomData,
         }
     }
 
+    /// Attach the weights of edges, which must be aligned with the edges of `edge_vec`.
+    /// Edges without weight are `None`.
+    pub fn with_edge_weights(mut self, edge_weights: Vec<Option<W>>) -> Self {
+        if self.edge_vec.num_edges() != edge_weights.len() {
+            panic!(
+                "Unequal length: there are {} edges, but {} weights",
+                self.edge_vec.num_edges(),
+                edge_weights.len()
+            );
+        }
+        self.edge_weights = Some(edge_weights);
+
+        self
+    }
+
     #[inline]
-    pub fn get_edge_vec(&self) -> &EdgeVec<Id, L> {
+    pub fn get_edge_vec(&self) -> &E {
         &self.edge_vec
     }
 
     #[inline]
-    pub fn get_in_edge_vec(&self) -> &Option<EdgeVec<Id, L>> {
+    pub fn get_in_edge_vec(&self) -> &Option<E> {
         &self.in_edge_vec
     }
 
@@ -343,6 +431,11 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         &self.labels
     }
 
+    #[inline]
+    pub fn get_edge_weights(&self) -> &Option<Vec<Option<W>>> {
+        &self.edge_weights
+    }
+
     #[inline]
     pub fn get_node_label_map(&self) -> &SetMap<NL> {
         &self.node_label_map
@@ -354,12 +447,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 
     #[inline]
-    pub fn get_edge_vec_mut(&mut self) -> &mut EdgeVec<Id, L> {
+    pub fn get_edge_vec_mut(&mut self) -> &mut E {
         &mut self.edge_vec
     }
 
     #[inline]
-    pub fn get_in_edge_vec_mut(&mut self) -> &mut Option<EdgeVec<Id, L>> {
+    pub fn get_in_edge_vec_mut(&mut self) -> &mut Option<E> {
         &mut self.in_edge_vec
     }
 
@@ -368,6 +461,11 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         &mut self.labels
     }
 
+    #[inline]
+    pub fn get_edge_weights_mut(&mut self) -> &mut Option<Vec<Option<W>>> {
+        &mut self.edge_weights
+    }
+
     #[inline]
     pub fn get_node_label_map_mut(&mut self) -> &mut SetMap<NL> {
         &mut self.node_label_map
@@ -399,6 +497,11 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         self.remove_edge_labels();
     }
 
+    #[inline]
+    pub fn remove_edge_weights(&mut self) {
+        self.edge_weights = None;
+    }
+
     #[inline]
     pub fn shrink_to_fit(&mut self) {
         self.edge_vec.shrink_to_fit();
@@ -408,6 +511,9 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
         if let Some(ref mut labels) = self.labels {
             labels.shrink_to_fit();
         }
+        if let Some(ref mut edge_weights) = self.edge_weights {
+            edge_weights.shrink_to_fit();
+        }
     }
     #[inline]
     pub fn find_edge_index(&self, start: Id, target: Id) -> Option<usize> {
@@ -415,18 +521,61 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 }
 
-impl<Id: IdType + Copy, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    O: IdType,
+> TypedStaticGraph<Id, NL, EL, Ty, L, W, EdgeVec<Id, L, O>>
+{
+    /// Compress the adjacency lists into `CompressedEdgeVec`, keeping the labels and weights,
+    /// which are aligned with the edges in the same way.
+    pub fn compress(self) -> TypedStaticGraph<Id, NL, EL, Ty, L, W, CompressedEdgeVec<Id, L>> {
+        TypedStaticGraph {
+            num_nodes: self.num_nodes,
+            num_edges: self.num_edges,
+            edge_vec: CompressedEdgeVec::from_edge_vec(&self.edge_vec),
+            in_edge_vec: self
+                .in_edge_vec
+                .as_ref()
+                .map(CompressedEdgeVec::from_edge_vec),
+            labels: self.labels,
+            edge_weights: self.edge_weights,
+            node_label_map: self.node_label_map,
+            edge_label_map: self.edge_label_map,
+            graph_type: PhantomData,
+            id_type: PhantomData,
+        }
+    }
+}
+
+impl<
+    Id: IdType + Copy,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType + Copy + Default,
+    E: MutAdjacencyTrait<Id, L>,
+> TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 where
     NL: serde::Serialize + Clone,
     EL: serde::Serialize + Clone,
 {
+    /// Dump self to bytearrays in order to be loaded as `StaticGraphMmap`, together with
+    /// a manifest that describes the format of the dump.
     pub fn dump_mmap(&self, prefix: &str) -> Result<()> {
         let edges_prefix = format!("{}_OUT", prefix);
         let in_edges_prefix = format!("{}_IN", prefix);
         let label_file = format!("{}.labels", prefix);
+        let weight_file = format!("{}.weights", prefix);
+        let weight_mask_file = format!("{}.weight_mask", prefix);
 
         let aux_map_file = format!("{}_aux.bin", prefix);
+        let manifest_file = format!("{}_manifest.bin", prefix);
 
         self.edge_vec.dump_mmap(&edges_prefix)?;
         if let Some(ref in_edges) = self.in_edge_vec {
@@ -439,6 +588,23 @@ where
             }
         }
 
+        if let Some(ref edge_weights) = self.edge_weights {
+            // The edges without weight are marked in a bitmap, as any value is a valid weight.
+            let mut mask = FixedBitSet::with_capacity(edge_weights.len());
+            let weights = edge_weights
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    mask.set(i, w.is_some());
+                    w.unwrap_or_default()
+                }).collect::<Vec<_>>();
+
+            unsafe {
+                dump(&weights, ::std::fs::File::create(weight_file)?)?;
+                dump(mask.as_slice(), ::std::fs::File::create(weight_mask_file)?)?;
+            }
+        }
+
         let aux_file = StaticGraphMmapAux::new(
             self.num_nodes,
             self.num_edges,
@@ -448,12 +614,53 @@ where
 
         Serializer::export(&aux_file, &aux_map_file)?;
 
+        let mut manifest = MmapManifest::new::<Id, L, E::Offset, W>(
+            Ty::is_directed(),
+            self.num_nodes,
+            self.num_edges,
+            self.edge_weights.is_some(),
+        );
+
+        // The checksums are computed from the files just written, since the adjacency
+        // lists may be compressed in memory.
+        let mut suffixes = vec!["_OUT.offsets", "_OUT.edges"];
+        if !self.edge_vec.get_labels().is_empty() {
+            suffixes.push("_OUT.labels");
+        }
+        if let Some(ref in_edges) = self.in_edge_vec {
+            suffixes.push("_IN.offsets");
+            suffixes.push("_IN.edges");
+            if !in_edges.get_labels().is_empty() {
+                suffixes.push("_IN.labels");
+            }
+        }
+        if self.labels.is_some() {
+            suffixes.push(".labels");
+        }
+        if self.edge_weights.is_some() {
+            suffixes.push(".weights");
+            suffixes.push(".weight_mask");
+        }
+
+        for suffix in suffixes {
+            manifest.add_file_from_path(suffix, format!("{}{}", prefix, suffix))?;
+        }
+
+        Serializer::export(&manifest, &manifest_file)?;
+
         Ok(())
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTrait<Id, L>
-    for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> GraphTrait<Id, L> for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {
     #[inline]
     fn get_node(&self, id: Id) -> NodeType<Id, L> {
@@ -512,10 +719,7 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTr
 
     #[inline]
     fn edge_indices(&self) -> Iter<(Id, Id)> {
-        Iter::new(Box::new(StaticEdgeIndexIter::new(
-            Box::new(&self.edge_vec),
-            self.is_directed(),
-        )))
+        self.edge_vec.edge_indices(self.is_directed())
     }
 
     #[inline]
@@ -561,14 +765,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTr
 
     #[inline]
     fn neighbors_iter(&self, id: Id) -> Iter<Id> {
-        let neighbors = self.edge_vec.neighbors(id);
-
-        Iter::new(Box::new(neighbors.iter().map(|x| *x)))
+        self.edge_vec.neighbors_iter(id)
     }
 
     #[inline]
     fn neighbors(&self, id: Id) -> Cow<[Id]> {
-        self.edge_vec.neighbors(id).into()
+        self.edge_vec.neighbors(id)
     }
 
     #[inline]
@@ -582,8 +784,15 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType> GraphTr
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
-    GraphLabelTrait<Id, NL, EL, L> for TypedStaticGraph<Id, NL, EL, Ty, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> GraphLabelTrait<Id, NL, EL, L> for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
 {
     #[inline(always)]
     fn get_node_label_map(&self) -> &SetMap<NL> {
@@ -596,33 +805,76 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, Ty: GraphType, L: IdType>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> UnGraphTrait<Id, L>
-    for TypedUnStaticGraph<Id, NL, EL, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    Ty: GraphType,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> GraphWeightTrait<Id, W, L> for TypedStaticGraph<Id, NL, EL, Ty, L, W, E>
+{
+    #[inline]
+    fn get_edge_weight(&self, start: Id, target: Id) -> Option<W> {
+        match self.edge_weights {
+            Some(ref edge_weights) => match self.edge_vec.find_edge_index(start, target) {
+                Some(idx) => edge_weights[idx].clone(),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    fn has_edge_weights(&self) -> bool {
+        self.edge_weights.is_some()
+    }
+}
+
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> UnGraphTrait<Id, L> for TypedUnStaticGraph<Id, NL, EL, L, W, E>
 {}
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> DiGraphTrait<Id, L>
-    for TypedDiStaticGraph<Id, NL, EL, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> DiGraphTrait<Id, L> for TypedDiStaticGraph<Id, NL, EL, L, W, E>
 {
     #[inline]
     fn in_degree(&self, id: Id) -> usize {
-        self.in_neighbors(id).len()
+        self.in_edge_vec.as_ref().unwrap().degree(id)
     }
 
     #[inline]
     fn in_neighbors_iter(&self, id: Id) -> Iter<Id> {
-        let in_neighbors = self.in_edge_vec.as_ref().unwrap().neighbors(id);
-
-        Iter::new(Box::new(in_neighbors.iter().map(|x| *x)))
+        self.in_edge_vec.as_ref().unwrap().neighbors_iter(id)
     }
 
     #[inline]
     fn in_neighbors(&self, id: Id) -> Cow<[Id]> {
-        self.in_edge_vec.as_ref().unwrap().neighbors(id).into()
+        self.in_edge_vec.as_ref().unwrap().neighbors(id)
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, EL, L>
-    for TypedUnStaticGraph<Id, NL, EL, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> GeneralGraph<Id, NL, EL, L> for TypedUnStaticGraph<Id, NL, EL, L, W, E>
 {
     #[inline(always)]
     fn as_graph(&self) -> &GraphTrait<Id, L> {
@@ -640,8 +892,14 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, E
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, EL, L>
-    for TypedDiStaticGraph<Id, NL, EL, L>
+impl<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    W: WeightType,
+    E: MutAdjacencyTrait<Id, L>,
+> GeneralGraph<Id, NL, EL, L> for TypedDiStaticGraph<Id, NL, EL, L, W, E>
 {
     #[inline(always)]
     fn as_graph(&self) -> &GraphTrait<Id, L> {
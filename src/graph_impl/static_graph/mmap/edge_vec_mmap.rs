@@ -18,44 +18,84 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+use std::borrow::Cow;
 use std::fs::metadata;
 
-use generic::IdType;
-use graph_impl::static_graph::EdgeVecTrait;
+use error::{GraphError, Result};
+use generic::{IdType, Iter};
+use graph_impl::static_graph::static_edge_iter::StaticEdgeIndexIter;
+use graph_impl::static_graph::{AdjacencyTrait, EdgeVecTrait};
 use io::mmap::TypedMemoryMap;
 
 /// A mmap version of `EdgeVec`.
-pub struct EdgeVecMmap<Id: IdType, L: IdType> {
-    offsets: TypedMemoryMap<usize>,
+pub struct EdgeVecMmap<Id: IdType, L: IdType, O: IdType = usize> {
+    offsets: TypedMemoryMap<O>,
     edges: TypedMemoryMap<Id>,
     labels: Option<TypedMemoryMap<L>>,
 }
 
-impl<Id: IdType, L: IdType> EdgeVecMmap<Id, L> {
-    pub fn new(prefix: &str) -> Self {
+impl<Id: IdType, L: IdType, O: IdType> EdgeVecMmap<Id, L, O> {
+    /// Load the mmap files dumped by `EdgeVec::dump_mmap` with the given prefix,
+    /// and check that the offsets are non-decreasing and consistent with the edges and labels.
+    pub fn new(prefix: &str) -> Result<Self> {
         let offsets_file = format!("{}.offsets", prefix);
         let edges_file = format!("{}.edges", prefix);
         let labels_file = format!("{}.labels", prefix);
 
-        if metadata(&labels_file).is_ok() {
-            EdgeVecMmap {
-                offsets: TypedMemoryMap::new(&offsets_file),
-                edges: TypedMemoryMap::new(&edges_file),
-                labels: Some(TypedMemoryMap::new(&labels_file)),
-            }
+        let offsets: TypedMemoryMap<O> = TypedMemoryMap::new(&offsets_file)?;
+        let edges: TypedMemoryMap<Id> = TypedMemoryMap::new(&edges_file)?;
+        let labels: Option<TypedMemoryMap<L>> = if metadata(&labels_file).is_ok() {
+            Some(TypedMemoryMap::new(&labels_file)?)
         } else {
-            EdgeVecMmap {
-                offsets: TypedMemoryMap::new(&offsets_file),
-                edges: TypedMemoryMap::new(&edges_file),
-                labels: None,
+            None
+        };
+
+        let (first, last) = match (offsets[..].first(), offsets[..].last()) {
+            (Some(first), Some(last)) => (first.id(), last.id()),
+            _ => {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' is empty",
+                    offsets_file
+                )));
+            }
+        };
+
+        if first != 0 || last != edges.len {
+            return Err(GraphError::InvalidData(format!(
+                "offsets in '{}' range over [{}, {}], but '{}' has {} edges",
+                offsets_file, first, last, edges_file, edges.len
+            )));
+        }
+
+        // Together with the first and last offsets, this keeps the neighbors of every node
+        // within the edges.
+        if let Some(node) = offsets[..].windows(2).position(|pair| pair[1] < pair[0]) {
+            return Err(GraphError::InvalidData(format!(
+                "offsets in '{}' decrease at node {}",
+                offsets_file, node
+            )));
+        }
+
+        if let Some(ref labels) = labels {
+            if labels.len != edges.len {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} labels, but '{}' has {} edges",
+                    labels_file, labels.len, edges_file, edges.len
+                )));
             }
         }
+
+        Ok(EdgeVecMmap {
+            offsets,
+            edges,
+            labels,
+        })
     }
 }
 
-impl<Id: IdType, L: IdType> EdgeVecTrait<Id, L> for EdgeVecMmap<Id, L> {
+impl<Id: IdType, L: IdType, O: IdType> EdgeVecTrait<Id, L, O> for EdgeVecMmap<Id, L, O> {
     #[inline(always)]
-    fn get_offsets(&self) -> &[usize] {
+    fn get_offsets(&self) -> &[O] {
         &self.offsets[..]
     }
 
@@ -63,12 +103,6 @@ impl<Id: IdType, L: IdType> EdgeVecTrait<Id, L> for EdgeVecMmap<Id, L> {
     fn get_edges(&self) -> &[Id] {
         &self.edges[..]
     }
-
-    #[inline(always)]
-    fn get_labels(&self) -> &[L] {
-        match self.labels {
-            Some(ref labels) => &labels[..],
-            None => &[],
-        }
-    }
 }
+
+impl_adjacency_trait!(EdgeVecMmap);
@@ -24,25 +24,41 @@ use std::hash::Hash;
 
 use serde;
 
+use error::{GraphError, Result};
 use generic::{
-    DiGraphTrait, EdgeType, GeneralGraph, GraphLabelTrait, GraphTrait, IdType, Iter, NodeType,
+    DefaultWeight, DiGraphTrait, EdgeType, GeneralGraph, GraphLabelTrait, GraphTrait,
+    GraphWeightTrait, IdType, Iter, NodeType, WeightType,
 };
+use graph_impl::static_graph::mmap::manifest::{edge_vec_files, MmapManifest};
 use graph_impl::static_graph::mmap::EdgeVecMmap;
 use graph_impl::static_graph::node::StaticNode;
 use graph_impl::static_graph::static_edge_iter::StaticEdgeIndexIter;
-use graph_impl::static_graph::EdgeVecTrait;
+use graph_impl::static_graph::{AdjacencyTrait, EdgeVecTrait};
 use graph_impl::{Edge, GraphImpl};
-use io::mmap::TypedMemoryMap;
+use io::mmap::{typed_as_byte_slice, TypedMemoryMap};
 use io::serde::Deserializer;
 use map::SetMap;
 
-pub struct StaticGraphMmap<Id: IdType, NL: Hash + Eq, EL: Hash + Eq = NL, L: IdType = Id> {
+pub struct StaticGraphMmap<
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq = NL,
+    L: IdType = Id,
+    W: WeightType + Copy = DefaultWeight,
+    O: IdType = usize,
+> {
     /// Outgoing edges, or edges for undirected
-    edges: EdgeVecMmap<Id, L>,
+    edges: EdgeVecMmap<Id, L, O>,
     /// Incoming edges for directed, `None` for undirected
-    in_edges: Option<EdgeVecMmap<Id, L>>,
+    in_edges: Option<EdgeVecMmap<Id, L, O>>,
     /// Maintain the node's labels, whose index is aligned with `offsets`.
     labels: Option<TypedMemoryMap<L>>,
+    /// Maintain the edge's weights, whose index is aligned with the outgoing edges.
+    edge_weights: Option<TypedMemoryMap<W>>,
+    /// A bitmap of the edges that have a weight, present together with `edge_weights`.
+    edge_weight_mask: Option<TypedMemoryMap<u32>>,
+    /// `None` only if opened with `new_lenient` from a dump without manifest.
+    manifest: Option<MmapManifest>,
 
     num_nodes: usize,
     num_edges: usize,
@@ -83,34 +99,87 @@ impl<NL: Hash + Eq, EL: Hash + Eq> StaticGraphMmapAux<NL, EL> {
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> StaticGraphMmap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    StaticGraphMmap<Id, NL, EL, L, W, O>
 where
     for<'de> NL: serde::Deserialize<'de>,
     for<'de> EL: serde::Deserialize<'de>,
 {
-    pub fn new(prefix: &str) -> Self {
+    /// Load the mmap files dumped by `TypedStaticGraph::dump_mmap` with the given prefix,
+    /// and check that the node labels, edge weights and in-edges are consistent with the edges.
+    ///
+    /// The manifest of the dump is validated against the types of the graph and the current
+    /// platform before any file is mapped, and against the sizes of the files. Their contents
+    /// are only read on demand, so the checksums are left to `verify`.
+    pub fn new(prefix: &str) -> Result<Self> {
+        Self::open(prefix, true)
+    }
+
+    /// Like `new`, but also accept a dump without manifest, e.g. one made before manifests
+    /// were introduced, whose format can not be validated.
+    pub fn new_lenient(prefix: &str) -> Result<Self> {
+        Self::open(prefix, false)
+    }
+
+    fn open(prefix: &str, requires_manifest: bool) -> Result<Self> {
         let edge_prefix = format!("{}_OUT", prefix);
         let in_edge_prefix = format!("{}_IN", prefix);
         let labels_file = format!("{}.labels", prefix);
+        let weights_file = format!("{}.weights", prefix);
+        let weight_mask_file = format!("{}.weight_mask", prefix);
 
         let aux_map_file = format!("{}_aux.bin", prefix);
+        let manifest_file = format!("{}_manifest.bin", prefix);
+
+        let manifest: Option<MmapManifest> = if metadata(&manifest_file).is_ok() {
+            let manifest: MmapManifest = Deserializer::import(&manifest_file)?;
+            manifest.validate::<Id, L, O, W>(metadata(&weights_file).is_ok())?;
+            Some(manifest)
+        } else if requires_manifest {
+            return Err(GraphError::FormatMismatch(format!(
+                "'{}' has no manifest, see `StaticGraphMmap::new_lenient`",
+                prefix
+            )));
+        } else {
+            warn!(
+                "'{}' has no manifest, so its format is not validated",
+                prefix
+            );
+            None
+        };
 
-        let edges = EdgeVecMmap::new(&edge_prefix);
+        let edges = EdgeVecMmap::new(&edge_prefix)?;
 
         let in_edges = if metadata(&format!("{}.offsets", in_edge_prefix)).is_ok() {
-            Some(EdgeVecMmap::new(&in_edge_prefix))
+            Some(EdgeVecMmap::new(&in_edge_prefix)?)
         } else {
             None
         };
 
         let labels = if metadata(&labels_file).is_ok() {
-            Some(TypedMemoryMap::new(&labels_file))
+            Some(TypedMemoryMap::new(&labels_file)?)
         } else {
             None
         };
 
+        let (edge_weights, edge_weight_mask) = if metadata(&weights_file).is_ok() {
+            if metadata(&weight_mask_file).is_err() {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' is present, but '{}' is missing",
+                    weights_file, weight_mask_file
+                )));
+            }
+
+            (
+                Some(TypedMemoryMap::new(&weights_file)?),
+                Some(TypedMemoryMap::new(&weight_mask_file)?),
+            )
+        } else {
+            (None, None)
+        };
+
         let aux_file = if metadata(&aux_map_file).is_ok() {
-            Deserializer::import(&aux_map_file).unwrap()
+            Deserializer::import(&aux_map_file)?
         } else {
             let num_node = edges.num_nodes();
             let num_edge = if in_edges.is_some() {
@@ -122,36 +191,191 @@ where
             StaticGraphMmapAux::empty(num_node, num_edge)
         };
 
-        StaticGraphMmap {
+        if let Some(ref in_edges) = in_edges {
+            if in_edges.num_nodes() != edges.num_nodes()
+                || in_edges.num_edges() != edges.num_edges()
+            {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} nodes and {} edges, but '{}' has {} nodes and {} edges",
+                    in_edge_prefix,
+                    in_edges.num_nodes(),
+                    in_edges.num_edges(),
+                    edge_prefix,
+                    edges.num_nodes(),
+                    edges.num_edges()
+                )));
+            }
+        }
+
+        if let Some(ref labels) = labels {
+            if labels.len != edges.num_nodes() {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} labels, but the graph has {} nodes",
+                    labels_file,
+                    labels.len,
+                    edges.num_nodes()
+                )));
+            }
+        }
+
+        if let Some(ref edge_weights) = edge_weights {
+            if edge_weights.len != edges.num_edges() {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} weights, but '{}' has {} edges",
+                    weights_file,
+                    edge_weights.len,
+                    edge_prefix,
+                    edges.num_edges()
+                )));
+            }
+        }
+
+        if let Some(ref edge_weight_mask) = edge_weight_mask {
+            let blocks = (edges.num_edges() + 31) / 32;
+            if edge_weight_mask.len != blocks {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} blocks, but {} blocks are needed for {} edges",
+                    weight_mask_file,
+                    edge_weight_mask.len,
+                    blocks,
+                    edges.num_edges()
+                )));
+            }
+        }
+
+        if let Some(ref manifest) = manifest {
+            if manifest.directed != in_edges.is_some() {
+                return Err(GraphError::FormatMismatch(format!(
+                    "the manifest says the graph is {}, but '{}' is {}",
+                    if manifest.directed {
+                        "directed"
+                    } else {
+                        "undirected"
+                    },
+                    in_edge_prefix,
+                    if in_edges.is_some() {
+                        "present"
+                    } else {
+                        "missing"
+                    }
+                )));
+            }
+
+            if manifest.num_nodes != aux_file.num_nodes as u64
+                || manifest.num_edges != aux_file.num_edges as u64
+            {
+                return Err(GraphError::InvalidData(format!(
+                    "the manifest has {} nodes and {} edges, but '{}' has {} nodes and {} edges",
+                    manifest.num_nodes,
+                    manifest.num_edges,
+                    aux_map_file,
+                    aux_file.num_nodes,
+                    aux_file.num_edges
+                )));
+            }
+
+            manifest.verify_lens(&files_of(
+                &edges,
+                &in_edges,
+                &labels,
+                &edge_weights,
+                &edge_weight_mask,
+            ))?;
+        }
+
+        Ok(StaticGraphMmap {
             num_nodes: aux_file.num_nodes,
             num_edges: aux_file.num_edges,
             edges,
             in_edges,
             labels,
+            edge_weights,
+            edge_weight_mask,
+            manifest,
             node_label_map: aux_file.node_label_map,
             edge_label_map: aux_file.edge_label_map,
-        }
+        })
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> StaticGraphMmap<Id, NL, EL, L> {
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    StaticGraphMmap<Id, NL, EL, L, W, O>
+{
+    /// Check the contents of all the files against the checksums of the manifest, which reads
+    /// the whole graph from disk.
+    pub fn verify(&self) -> Result<()> {
+        match self.manifest {
+            Some(ref manifest) => manifest.verify_files(&files_of(
+                &self.edges,
+                &self.in_edges,
+                &self.labels,
+                &self.edge_weights,
+                &self.edge_weight_mask,
+            )),
+            None => Err(GraphError::FormatMismatch(
+                "the graph has no manifest to verify".to_owned(),
+            )),
+        }
+    }
+
+    #[inline]
+    pub fn get_edge_vec(&self) -> &EdgeVecMmap<Id, L, O> {
+        &self.edges
+    }
+
+    #[inline]
+    pub fn get_in_edge_vec(&self) -> &Option<EdgeVecMmap<Id, L, O>> {
+        &self.in_edges
+    }
+
     #[inline]
     pub fn inner_neighbors(&self, id: Id) -> &[Id] {
-        self.edges.neighbors(id)
+        self.edges.neighbors_slice(id)
     }
 
     #[inline]
     pub fn inner_in_neighbors(&self, id: Id) -> &[Id] {
         if let Some(ref in_edges) = self.in_edges {
-            in_edges.neighbors(id)
+            in_edges.neighbors_slice(id)
         } else {
             &[]
         }
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GraphTrait<Id, L>
-    for StaticGraphMmap<Id, NL, EL, L>
+/// The suffixes and contents of the files of a dump, as recorded in its manifest.
+fn files_of<'a, Id: IdType, L: IdType, W: WeightType + Copy, O: IdType>(
+    edges: &'a EdgeVecMmap<Id, L, O>,
+    in_edges: &'a Option<EdgeVecMmap<Id, L, O>>,
+    labels: &'a Option<TypedMemoryMap<L>>,
+    edge_weights: &'a Option<TypedMemoryMap<W>>,
+    edge_weight_mask: &'a Option<TypedMemoryMap<u32>>,
+) -> Vec<(String, &'a [u8])> {
+    let mut files = edge_vec_files("_OUT", edges);
+    if let Some(ref in_edges) = *in_edges {
+        files.extend(edge_vec_files("_IN", in_edges));
+    }
+    if let Some(ref labels) = *labels {
+        files.push((".labels".to_owned(), typed_as_byte_slice(&labels[..])));
+    }
+    if let Some(ref edge_weights) = *edge_weights {
+        files.push((
+            ".weights".to_owned(),
+            typed_as_byte_slice(&edge_weights[..]),
+        ));
+    }
+    if let Some(ref edge_weight_mask) = *edge_weight_mask {
+        files.push((
+            ".weight_mask".to_owned(),
+            typed_as_byte_slice(&edge_weight_mask[..]),
+        ));
+    }
+
+    files
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    GraphTrait<Id, L> for StaticGraphMmap<Id, NL, EL, L, W, O>
 {
     #[inline]
     fn get_node(&self, id: Id) -> NodeType<Id, L> {
@@ -260,12 +484,12 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GraphTrait<Id, L>
 
     #[inline]
     fn neighbors_iter(&self, id: Id) -> Iter<Id> {
-        Iter::new(Box::new(self.edges.neighbors(id).iter().map(|x| *x)))
+        Iter::new(Box::new(self.edges.neighbors_slice(id).iter().map(|x| *x)))
     }
 
     #[inline]
     fn neighbors(&self, id: Id) -> Cow<[Id]> {
-        self.edges.neighbors(id).into()
+        self.edges.neighbors(id)
     }
 
     #[inline]
@@ -279,8 +503,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GraphTrait<Id, L>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GraphLabelTrait<Id, NL, EL, L>
-    for StaticGraphMmap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    GraphLabelTrait<Id, NL, EL, L> for StaticGraphMmap<Id, NL, EL, L, W, O>
 {
     #[inline(always)]
     fn get_node_label_map(&self) -> &SetMap<NL> {
@@ -293,8 +517,32 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GraphLabelTrait<Id, NL
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> DiGraphTrait<Id, L>
-    for StaticGraphMmap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    GraphWeightTrait<Id, W, L> for StaticGraphMmap<Id, NL, EL, L, W, O>
+{
+    #[inline]
+    fn get_edge_weight(&self, start: Id, target: Id) -> Option<W> {
+        match (&self.edge_weights, &self.edge_weight_mask) {
+            (Some(ref edge_weights), Some(ref edge_weight_mask)) => {
+                match self.edges.find_edge_index(start, target) {
+                    Some(idx) if edge_weight_mask[..][idx / 32] & (1 << (idx % 32)) != 0 => {
+                        Some(edge_weights[..][idx])
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn has_edge_weights(&self) -> bool {
+        self.edge_weights.is_some()
+    }
+}
+
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    DiGraphTrait<Id, L> for StaticGraphMmap<Id, NL, EL, L, W, O>
 {
     #[inline]
     fn in_degree(&self, id: Id) -> usize {
@@ -312,8 +560,8 @@ impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> DiGraphTrait<Id, L>
     }
 }
 
-impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType> GeneralGraph<Id, NL, EL, L>
-    for StaticGraphMmap<Id, NL, EL, L>
+impl<Id: IdType, NL: Hash + Eq, EL: Hash + Eq, L: IdType, W: WeightType + Copy, O: IdType>
+    GeneralGraph<Id, NL, EL, L> for StaticGraphMmap<Id, NL, EL, L, W, O>
 {
     #[inline(always)]
     fn as_graph(&self) -> &GraphTrait<Id, L> {
@@ -0,0 +1,264 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! The manifest of a mmap dump, which makes the headerless `.offsets`, `.edges`, `.labels`,
+//! `.weights` and `.weight_mask` files self-describing.
+//!
+//! `TypedStaticGraph::dump_mmap` writes the manifest to `{prefix}_manifest.bin` after all the
+//! other files, and `StaticGraphMmap::new` validates it before using any of them, so that a dump
+//! made with e.g. `usize_id` is rejected instead of being reinterpreted with the wrong type.
+//! The checksums are only checked by `StaticGraphMmap::verify`, which reads every file.
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{BufReader, Read};
+use std::mem;
+use std::path::Path;
+
+use fnv::FnvHasher;
+
+use error::{GraphError, Result};
+use generic::IdType;
+use graph_impl::static_graph::EdgeVecTrait;
+use io::mmap::typed_as_byte_slice;
+
+/// The magic bytes at the start of every manifest.
+pub const MMAP_MAGIC: [u8; 8] = *b"RGRAPHMM";
+/// The version of the on-disk format, which is bumped on every incompatible change.
+pub const MMAP_FORMAT_VERSION: u32 = 1;
+
+/// The length and the checksum of one file of a mmap dump.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChecksum {
+    /// The suffix appended to the prefix of the dump, e.g. `_OUT.edges`
+    pub suffix: String,
+    /// The length in bytes
+    pub len: u64,
+    /// The 64-bit FNV-1a hash of the content
+    pub checksum: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MmapManifest {
+    pub magic: [u8; 8],
+    pub version: u32,
+    /// The size in bytes of `Id`
+    pub id_width: u8,
+    /// The size in bytes of `L`
+    pub label_width: u8,
+    /// The size in bytes of the offsets `O`
+    pub offset_width: u8,
+    /// The size in bytes of `W`, or 0 if the graph has no edge weights
+    pub weight_width: u8,
+    pub little_endian: bool,
+    pub directed: bool,
+    pub num_nodes: u64,
+    pub num_edges: u64,
+    pub files: Vec<FileChecksum>,
+}
+
+impl MmapManifest {
+    /// Create a manifest for the current platform and the given types, without any file.
+    pub fn new<Id, L, O, W>(
+        directed: bool,
+        num_nodes: usize,
+        num_edges: usize,
+        weighted: bool,
+    ) -> Self {
+        MmapManifest {
+            magic: MMAP_MAGIC,
+            version: MMAP_FORMAT_VERSION,
+            id_width: mem::size_of::<Id>() as u8,
+            label_width: mem::size_of::<L>() as u8,
+            offset_width: mem::size_of::<O>() as u8,
+            weight_width: if weighted {
+                mem::size_of::<W>() as u8
+            } else {
+                0
+            },
+            little_endian: cfg!(target_endian = "little"),
+            directed,
+            num_nodes: num_nodes as u64,
+            num_edges: num_edges as u64,
+            files: Vec::new(),
+        }
+    }
+
+    /// Record the length and checksum of the file with the given suffix.
+    pub fn add_file(&mut self, suffix: &str, bytes: &[u8]) {
+        self.files.push(FileChecksum {
+            suffix: suffix.to_owned(),
+            len: bytes.len() as u64,
+            checksum: checksum(bytes),
+        });
+    }
+
+    /// Record the length and checksum of the file with the given suffix, read from **path**.
+    pub fn add_file_from_path<P: AsRef<Path>>(&mut self, suffix: &str, path: P) -> Result<()> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut hasher = FnvHasher::default();
+        let mut buf = [0; 8192];
+        let mut len = 0;
+
+        loop {
+            let read = reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            hasher.write(&buf[..read]);
+            len += read as u64;
+        }
+
+        self.files.push(FileChecksum {
+            suffix: suffix.to_owned(),
+            len,
+            checksum: hasher.finish(),
+        });
+
+        Ok(())
+    }
+
+    /// Check that the dump can be read on the current platform as a graph of the given types.
+    pub fn validate<Id, L, O, W>(&self, weighted: bool) -> Result<()> {
+        if self.magic != MMAP_MAGIC {
+            return Err(GraphError::FormatMismatch(
+                "the manifest does not start with the expected magic bytes".to_owned(),
+            ));
+        }
+
+        if self.version != MMAP_FORMAT_VERSION {
+            return Err(GraphError::FormatMismatch(format!(
+                "the dump has format version {}, but version {} is expected",
+                self.version, MMAP_FORMAT_VERSION
+            )));
+        }
+
+        if self.little_endian != cfg!(target_endian = "little") {
+            return Err(GraphError::FormatMismatch(format!(
+                "the dump is {}-endian, but the current platform is not",
+                if self.little_endian { "little" } else { "big" }
+            )));
+        }
+
+        check_width("Id", self.id_width, mem::size_of::<Id>())?;
+        check_width("L", self.label_width, mem::size_of::<L>())?;
+        check_width("O", self.offset_width, mem::size_of::<O>())?;
+        if weighted {
+            check_width("W", self.weight_width, mem::size_of::<W>())?;
+        }
+
+        Ok(())
+    }
+
+    /// Check that exactly the recorded files are given, with the recorded lengths, which does
+    /// not read their contents.
+    pub fn verify_lens(&self, files: &[(String, &[u8])]) -> Result<()> {
+        for (suffix, bytes) in files {
+            let expected = self.get_file(suffix)?;
+            if expected.len != bytes.len() as u64 {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' has {} bytes, but {} bytes are recorded in the manifest",
+                    suffix,
+                    bytes.len(),
+                    expected.len
+                )));
+            }
+        }
+
+        for expected in self.files.iter() {
+            if !files.iter().any(|(suffix, _)| *suffix == expected.suffix) {
+                return Err(GraphError::InvalidData(format!(
+                    "'{}' is recorded in the manifest but missing",
+                    expected.suffix
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Like `verify_lens`, and also check the contents of the files against the checksums.
+    pub fn verify_files(&self, files: &[(String, &[u8])]) -> Result<()> {
+        self.verify_lens(files)?;
+
+        for (suffix, bytes) in files {
+            if self.get_file(suffix)?.checksum != checksum(bytes) {
+                return Err(GraphError::InvalidData(format!(
+                    "the checksum of '{}' does not match the manifest",
+                    suffix
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_file(&self, suffix: &str) -> Result<&FileChecksum> {
+        self.files
+            .iter()
+            .find(|f| f.suffix == suffix)
+            .ok_or_else(|| {
+                GraphError::InvalidData(format!("'{}' is not recorded in the manifest", suffix))
+            })
+    }
+}
+
+/// The suffixes and contents of the files that `EdgeVec::dump_mmap` writes for **edges**.
+pub fn edge_vec_files<'a, Id: IdType, L: IdType, O: IdType, E: EdgeVecTrait<Id, L, O>>(
+    suffix: &str,
+    edges: &'a E,
+) -> Vec<(String, &'a [u8])> {
+    let mut files = vec![
+        (
+            format!("{}.offsets", suffix),
+            typed_as_byte_slice(edges.get_offsets()),
+        ),
+        (
+            format!("{}.edges", suffix),
+            typed_as_byte_slice(edges.get_edges()),
+        ),
+    ];
+
+    if !edges.get_labels().is_empty() {
+        files.push((
+            format!("{}.labels", suffix),
+            typed_as_byte_slice(edges.get_labels()),
+        ));
+    }
+
+    files
+}
+
+/// Compute the 64-bit FNV-1a hash of the given bytes.
+pub fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn check_width(name: &str, width: u8, expected: usize) -> Result<()> {
+    if width as usize != expected {
+        Err(GraphError::FormatMismatch(format!(
+            "the dump has {}-byte {}, but it is opened with {}-byte {}",
+            width, name, expected, name
+        )))
+    } else {
+        Ok(())
+    }
+}
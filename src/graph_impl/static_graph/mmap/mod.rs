@@ -23,6 +23,8 @@
 
 pub mod edge_vec_mmap;
 pub mod graph_mmap;
+pub mod manifest;
 
 pub use graph_impl::static_graph::mmap::edge_vec_mmap::EdgeVecMmap;
 pub use graph_impl::static_graph::mmap::graph_mmap::StaticGraphMmap;
+pub use graph_impl::static_graph::mmap::manifest::MmapManifest;
@@ -18,13 +18,20 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+pub mod builder;
+#[macro_use]
 pub mod edge_vec;
+pub mod compressed_edge_vec;
 pub mod graph;
 pub mod mmap;
 pub mod node;
 pub mod static_edge_iter;
 
-pub use graph_impl::static_graph::edge_vec::{EdgeVec, EdgeVecTrait};
+pub use graph_impl::static_graph::builder::StaticGraphBuilder;
+pub use graph_impl::static_graph::compressed_edge_vec::CompressedEdgeVec;
+pub use graph_impl::static_graph::edge_vec::{
+    AdjacencyTrait, EdgeVec, EdgeVecTrait, MutAdjacencyTrait,
+};
 pub use graph_impl::static_graph::graph::{
     DiStaticGraph, StaticGraph, TypedDiStaticGraph, TypedStaticGraph, TypedUnStaticGraph,
     UnStaticGraph,
@@ -21,16 +21,16 @@
 use generic::IdType;
 use graph_impl::static_graph::EdgeVecTrait;
 
-pub struct StaticEdgeIndexIter<'a, Id: IdType, L: IdType> {
-    edge_vec: Box<&'a EdgeVecTrait<Id, L>>,
+pub struct StaticEdgeIndexIter<'a, Id: IdType, L: IdType, O: IdType = usize> {
+    edge_vec: Box<&'a EdgeVecTrait<Id, L, O>>,
     curr_node: usize,
     curr_neighbor_index: usize,
     is_directed: bool,
 }
 
-impl<'a, Id: IdType, L: IdType> StaticEdgeIndexIter<'a, Id, L> {
+impl<'a, Id: IdType, L: IdType, O: IdType> StaticEdgeIndexIter<'a, Id, L, O> {
     #[inline]
-    pub fn new(edge_vec: Box<&'a EdgeVecTrait<Id, L>>, is_directed: bool) -> Self {
+    pub fn new(edge_vec: Box<&'a EdgeVecTrait<Id, L, O>>, is_directed: bool) -> Self {
         StaticEdgeIndexIter {
             edge_vec,
             curr_node: 0,
@@ -40,7 +40,7 @@ impl<'a, Id: IdType, L: IdType> StaticEdgeIndexIter<'a, Id, L> {
     }
 }
 
-impl<'a, Id: IdType, L: IdType> Iterator for StaticEdgeIndexIter<'a, Id, L> {
+impl<'a, Id: IdType, L: IdType, O: IdType> Iterator for StaticEdgeIndexIter<'a, Id, L, O> {
     type Item = (Id, Id);
 
     #[inline]
@@ -61,7 +61,7 @@ impl<'a, Id: IdType, L: IdType> Iterator for StaticEdgeIndexIter<'a, Id, L> {
                 return None;
             }
 
-            neighbors = self.edge_vec.neighbors(Id::new(node));
+            neighbors = self.edge_vec.neighbors_slice(Id::new(node));
 
             if !self.is_directed && neighbors[self.curr_neighbor_index] < Id::new(node) {
                 match neighbors.binary_search(&Id::new(node)) {
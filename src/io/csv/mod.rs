@@ -18,8 +18,11 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod reader;
 pub mod record;
+pub mod report;
 pub mod writer;
 
 use std::hash::Hash;
@@ -28,8 +31,15 @@ use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
-use generic::{GeneralGraph, IdType, MutGraphTrait};
+use error;
+use generic::{
+    GeneralGraph, GraphTrait, GraphWeightTrait, IdType, MutGraphTrait, MutGraphWeightTrait,
+    WeightType,
+};
+#[cfg(feature = "parallel")]
+pub use io::csv::parallel::ParCSVReader;
 pub use io::csv::reader::CSVReader;
+pub use io::csv::report::{ErrorPolicy, LoadReport, SkippedRow};
 pub use io::csv::writer::CSVWriter;
 
 pub fn write_to_csv<Id, NL, EL, P, L>(
@@ -54,12 +64,12 @@ pub fn read_from_csv<Id, NL, EL, G, P>(
     separator: Option<&str>,
     has_headers: bool,
     is_flexible: bool,
-) -> Result<()>
+) -> error::Result<LoadReport>
 where
     for<'de> Id: IdType + Serialize + Deserialize<'de>,
     for<'de> NL: Hash + Eq + Serialize + Deserialize<'de>,
     for<'de> EL: Hash + Eq + Serialize + Deserialize<'de>,
-    G: MutGraphTrait<Id, NL, EL>,
+    G: MutGraphTrait<Id, NL, EL> + GraphTrait<Id, Id>,
     P: AsRef<Path>,
 {
     match separator {
@@ -73,3 +83,48 @@ where
             .read(g),
     }
 }
+
+pub fn write_weighted_to_csv<Id, NL, EL, G, P, L, W>(
+    g: &G,
+    path_to_nodes: P,
+    path_to_edges: P,
+) -> Result<()>
+where
+    Id: IdType + Serialize,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    L: IdType + Serialize,
+    W: WeightType + Serialize,
+    G: GeneralGraph<Id, NL, EL, L> + GraphWeightTrait<Id, W, L>,
+    P: AsRef<Path>,
+{
+    CSVWriter::new(g, path_to_nodes, path_to_edges).write_weighted(g)
+}
+
+pub fn read_weighted_from_csv<Id, NL, EL, G, P, W>(
+    g: &mut G,
+    path_to_nodes: Option<P>,
+    path_to_edges: P,
+    separator: Option<&str>,
+    has_headers: bool,
+    is_flexible: bool,
+) -> error::Result<LoadReport>
+where
+    for<'de> Id: IdType + Serialize + Deserialize<'de>,
+    for<'de> NL: Hash + Eq + Serialize + Deserialize<'de>,
+    for<'de> EL: Hash + Eq + Serialize + Deserialize<'de>,
+    for<'de> W: WeightType + Deserialize<'de>,
+    G: MutGraphTrait<Id, NL, EL> + MutGraphWeightTrait<Id, W, Id>,
+    P: AsRef<Path>,
+{
+    match separator {
+        Some(sep) => CSVReader::with_separator(path_to_nodes, path_to_edges, sep)
+            .headers(has_headers)
+            .flexible(is_flexible)
+            .read_weighted(g),
+        None => CSVReader::new(path_to_nodes, path_to_edges)
+            .headers(has_headers)
+            .flexible(is_flexible)
+            .read_weighted(g),
+    }
+}
@@ -0,0 +1,322 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Read the edges of a large csv file on all available threads, enabled by the `parallel` feature.
+//!
+//! The edge file is split into chunks of roughly `chunk_size` bytes, aligned to line boundaries,
+//! which are parsed concurrently by [rayon](https://github.com/rayon-rs/rayon). The parsed
+//! records are then added in the order of the file, so the result is the same as the one of the
+//! sequential `CSVReader`. At most one chunk per thread is held in memory at a time.
+//!
+//! **Note**: As the chunks are split at line breaks, quoted fields must not contain line breaks.
+//! The node file is read sequentially.
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use csv::{ByteRecord, ReaderBuilder};
+use rayon;
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use error::Result;
+use generic::{GraphTrait, GraphType, IdType, MutGraphTrait};
+use graph_impl::static_graph::StaticGraphBuilder;
+use io::csv::reader::{parse_record, CSVReader};
+use io::csv::record::{EdgeRecord, NodeRecord};
+use io::csv::report::LoadReport;
+
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 26;
+
+/// A chunk of parsed rows, with the 1-based line number within the chunk for the bad ones.
+struct Chunk<T> {
+    rows: Vec<::std::result::Result<T, (u64, ByteRecord, String)>>,
+    num_of_lines: u64,
+}
+
+/// A `CSVReader` that parses the edge file in parallel, created by `CSVReader::parallel`.
+///
+/// # Example
+/// ```
+/// use rust_graph::io::csv::CSVReader;
+/// use rust_graph::prelude::*;
+/// use rust_graph::DiGraphMap;
+///
+/// # use std::fs;
+/// # let dir = std::env::temp_dir().join("rust_graph_par_csv_doctest");
+/// # fs::create_dir_all(&dir).unwrap();
+/// # let path_to_edges = dir.join("edges.csv");
+/// # fs::write(&path_to_edges, "0,1\n1,2\n2,0\n").unwrap();
+/// let mut g = DiGraphMap::<Void>::new();
+/// let report = CSVReader::new(None, &path_to_edges)
+///     .headers(false)
+///     .parallel()
+///     .chunk_size(4)
+///     .read(&mut g)
+///     .unwrap();
+///
+/// assert_eq!(report.edges_added, 3);
+/// assert!(g.has_edge(2, 0));
+/// # fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParCSVReader<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> {
+    reader: CSVReader<'a, Id, NL, EL>,
+    chunk_size: usize,
+}
+
+impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, NL, EL> {
+    pub fn parallel(self) -> ParCSVReader<'a, Id, NL, EL> {
+        ParCSVReader {
+            reader: self,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+}
+
+impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> ParCSVReader<'a, Id, NL, EL> {
+    /// The number of bytes of each chunk, `DEFAULT_CHUNK_SIZE` by default.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "The chunk size must be positive.");
+        self.chunk_size = chunk_size;
+        self
+    }
+}
+
+impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> ParCSVReader<'a, Id, NL, EL>
+where
+    for<'de> Id: Deserialize<'de>,
+    for<'de> NL: Deserialize<'de>,
+    for<'de> EL: Deserialize<'de> + Send,
+{
+    /// Read the graph as `CSVReader::read` does.
+    pub fn read<G, L: IdType>(&self, g: &mut G) -> Result<LoadReport>
+    where
+        G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    {
+        let num_of_nodes = g.node_count();
+        let mut report = LoadReport::default();
+
+        if let Some(ref path_to_nodes) = self.reader.path_to_nodes {
+            info!(
+                "Adding nodes from {}",
+                path_to_nodes.as_path().to_str().unwrap()
+            );
+
+            self.reader.for_each_record(
+                path_to_nodes.as_path(),
+                &mut report,
+                |record: NodeRecord<Id, NL>, report| {
+                    report.add_node(record.add_to_graph(g));
+
+                    Ok(())
+                },
+            )?;
+        }
+
+        info!(
+            "Adding edges from {} in parallel",
+            self.reader.path_to_edges.as_path().to_str().unwrap()
+        );
+
+        self.par_for_each_record(&mut report, |record: EdgeRecord<Id, EL>, report| {
+            let is_self_loop = record.start == record.target;
+            report.add_edge(is_self_loop, record.add_to_graph(g));
+
+            Ok(())
+        })?;
+
+        report.nodes_added = g.node_count() - num_of_nodes;
+
+        Ok(report)
+    }
+
+    /// Read the graph into `builder`, which takes care of duplicate edges when it builds the
+    /// graph. Duplicates are not detected while reading, so every edge row shows up in
+    /// `edges_added`, and `duplicate_nodes` and `duplicate_edges` stay 0.
+    pub fn read_into_builder<Ty: GraphType, L: IdType>(
+        &self,
+        builder: &mut StaticGraphBuilder<Id, NL, EL, Ty, L>,
+    ) -> Result<LoadReport> {
+        let num_of_nodes = builder.node_count();
+        let mut report = LoadReport::default();
+
+        if let Some(ref path_to_nodes) = self.reader.path_to_nodes {
+            info!(
+                "Adding nodes from {}",
+                path_to_nodes.as_path().to_str().unwrap()
+            );
+
+            self.reader.for_each_record(
+                path_to_nodes.as_path(),
+                &mut report,
+                |record: NodeRecord<Id, NL>, _| {
+                    builder.add_node(record.id, record.label);
+
+                    Ok(())
+                },
+            )?;
+        }
+
+        info!(
+            "Adding edges from {} in parallel",
+            self.reader.path_to_edges.as_path().to_str().unwrap()
+        );
+
+        self.par_for_each_record(&mut report, |record: EdgeRecord<Id, EL>, report| {
+            report.add_edge(record.start == record.target, true);
+
+            builder.add_edge(record.start, record.target, record.label)
+        })?;
+
+        report.nodes_added = builder.node_count() - num_of_nodes;
+
+        Ok(report)
+    }
+
+    /// Parse the edge file in chunks on all threads, and pass the records to `f`
+    /// in the order of the file.
+    fn par_for_each_record<T, F>(&self, report: &mut LoadReport, mut f: F) -> Result<()>
+    where
+        for<'de> T: Deserialize<'de> + Send,
+        F: FnMut(T, &mut LoadReport) -> Result<()>,
+    {
+        let path = self.reader.path_to_edges.as_path();
+        let separator = self.reader.separator;
+
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(separator)
+            .from_path(path)?;
+
+        // The first record is the header, or decides the number of fields otherwise.
+        let mut first = ByteRecord::new();
+        rdr.read_byte_record(&mut first)?;
+        let (headers, start, mut num_of_lines) = if self.reader.has_headers {
+            let position = rdr.position();
+
+            (Some(first.clone()), position.byte(), position.line() - 1)
+        } else {
+            (None, 0, 0)
+        };
+        let expected_len = if self.reader.is_flexible {
+            None
+        } else {
+            Some(first.len())
+        };
+
+        let ranges = split_lines(path, start, self.chunk_size as u64)?;
+        for batch in ranges.chunks(rayon::current_num_threads()) {
+            let chunks = batch
+                .par_iter()
+                .map(|&(start, end)| {
+                    parse_chunk(path, start, end, separator, headers.as_ref(), expected_len)
+                }).collect::<Vec<_>>();
+
+            for chunk in chunks {
+                let chunk = chunk?;
+                for row in chunk.rows {
+                    report.rows_read += 1;
+
+                    match row {
+                        Ok(record) => f(record, report)?,
+                        Err((line, record, error)) => {
+                            let line = num_of_lines + line;
+                            self.reader.skip_row(path, line, &record, error, report)?
+                        }
+                    }
+                }
+                num_of_lines += chunk.num_of_lines;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Split the bytes of `path` from `start` to the end into ranges of about `chunk_size` bytes,
+/// each of which ends right after a line break or at the end of the file.
+fn split_lines(path: &Path, start: u64, chunk_size: u64) -> Result<Vec<(u64, u64)>> {
+    let mut file = BufReader::new(File::open(path)?);
+    let len = file.get_ref().metadata()?.len();
+
+    let mut ranges = Vec::new();
+    let mut line = Vec::new();
+    let mut begin = start;
+    while begin < len {
+        let mut end = begin + chunk_size;
+        if end < len {
+            // Move `end` to right after the next line break, which may be at `end - 1`.
+            file.seek(SeekFrom::Start(end - 1))?;
+            line.clear();
+            end += file.read_until(b'\n', &mut line)? as u64 - 1;
+        }
+        let end = end.min(len);
+
+        ranges.push((begin, end));
+        begin = end;
+    }
+
+    Ok(ranges)
+}
+
+fn parse_chunk<T>(
+    path: &Path,
+    start: u64,
+    end: u64,
+    separator: u8,
+    headers: Option<&ByteRecord>,
+    expected_len: Option<usize>,
+) -> Result<Chunk<T>>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(start))?;
+
+    let mut bytes = Vec::with_capacity((end - start) as usize);
+    file.take(end - start).read_to_end(&mut bytes)?;
+
+    let mut rdr = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(separator)
+        .from_reader(bytes.as_slice());
+
+    let mut rows = Vec::new();
+    for result in rdr.byte_records() {
+        let record = result?;
+
+        rows.push(
+            parse_record(&record, headers, expected_len).map_err(|error| {
+                let line = record.position().map_or(0, |p| p.line());
+
+                (line, record, error)
+            }),
+        );
+    }
+
+    Ok(Chunk {
+        rows,
+        num_of_lines: bytes.iter().filter(|&&b| b == b'\n').count() as u64,
+    })
+}
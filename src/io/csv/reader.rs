@@ -24,26 +24,39 @@
 /// Edges:
 /// src <sep> dst <sep> edge_label(optional)
 ///
-/// **Note**: Rows that are unable to parse will be skipped.
+/// Weighted edges:
+/// src <sep> dst <sep> edge_label(optional) <sep> edge_weight(optional)
+///
+/// **Note**: By default, rows that are unable to parse will be skipped with a warning.
+/// See `ErrorPolicy` for the alternatives.
+///
+/// Ids that can not be parsed as `Id` (e.g. UUIDs, URLs or sparse 64-bit keys) can be
+/// read with `read_with_id_map`, which interns them into dense `Id`s.
 use std::hash::Hash;
-use std::io::Result;
+use std::io;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
+use std::result;
 
-use csv::ReaderBuilder;
+use csv::{self, ByteRecord, ReaderBuilder};
 use serde::Deserialize;
 
-use generic::{IdType, Iter, MutGraphTrait};
-use io::csv::record::{EdgeRecord, NodeRecord};
+use error::{GraphError, Result};
+use generic::{GraphTrait, IdType, Iter, MutGraphTrait, MutGraphWeightTrait, WeightType};
+use io::csv::record::{EdgeRecord, NodeRecord, WeightedEdgeRecord};
+use io::csv::report::{ErrorPolicy, LoadReport, SkippedRow};
+use io::text::intern_id;
+use map::SetMap;
 
 #[derive(Debug)]
 pub struct CSVReader<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> {
-    path_to_nodes: Option<PathBuf>,
-    path_to_edges: PathBuf,
-    separator: u8,
-    has_headers: bool,
+    pub(crate) path_to_nodes: Option<PathBuf>,
+    pub(crate) path_to_edges: PathBuf,
+    pub(crate) separator: u8,
+    pub(crate) has_headers: bool,
     // Whether the number of fields in records is allowed to change or not.
-    is_flexible: bool,
+    pub(crate) is_flexible: bool,
+    error_policy: ErrorPolicy,
     _ph: PhantomData<(&'a Id, &'a NL, &'a EL)>,
 }
 
@@ -55,6 +68,7 @@ impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> Clone for CSVReader
             separator: self.separator.clone(),
             has_headers: self.has_headers.clone(),
             is_flexible: self.is_flexible.clone(),
+            error_policy: self.error_policy,
             _ph: PhantomData,
         }
     }
@@ -68,6 +82,7 @@ impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, N
             separator: b',',
             has_headers: true,
             is_flexible: false,
+            error_policy: ErrorPolicy::Skip,
             _ph: PhantomData,
         }
     }
@@ -94,6 +109,7 @@ impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, N
             separator: sep_string.chars().next().unwrap() as u8,
             has_headers: true,
             is_flexible: false,
+            error_policy: ErrorPolicy::Skip,
             _ph: PhantomData,
         }
     }
@@ -107,6 +123,13 @@ impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, N
         self.is_flexible = is_flexible;
         self
     }
+
+    /// How `read` and its variants handle rows that can not be parsed,
+    /// `ErrorPolicy::Skip` by default. The iterators always skip such rows.
+    pub fn error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
 }
 
 impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, NL, EL>
@@ -115,54 +138,93 @@ where
     for<'de> NL: Deserialize<'de>,
     for<'de> EL: Deserialize<'de>,
 {
-    pub fn read<G: MutGraphTrait<Id, NL, EL, L>, L: IdType>(&self, g: &mut G) -> Result<()> {
-        if let Some(ref path_to_nodes) = self.path_to_nodes {
-            info!(
-                "Adding nodes from {}",
-                path_to_nodes.as_path().to_str().unwrap()
-            );
-            let rdr = ReaderBuilder::new()
-                .has_headers(self.has_headers)
-                .flexible(self.is_flexible)
-                .delimiter(self.separator)
-                .from_path(path_to_nodes.as_path())?;
-
-            for (i, result) in rdr.into_deserialize().enumerate() {
-                match result {
-                    Ok(_result) => {
-                        let record: NodeRecord<Id, NL> = _result;
-                        record.add_to_graph(g);
-                    }
-                    Err(e) => warn!("Line {:?}: Error when reading csv: {:?}", i + 1, e),
-                }
-            }
-        }
+    pub fn read<G, L: IdType>(&self, g: &mut G) -> Result<LoadReport>
+    where
+        G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    {
+        let num_of_nodes = g.node_count();
+        let mut report = LoadReport::default();
+        self.read_nodes(g, &mut report)?;
 
         info!(
             "Adding edges from {}",
             self.path_to_edges.as_path().to_str().unwrap()
         );
 
-        let rdr = ReaderBuilder::new()
-            .has_headers(self.has_headers)
-            .flexible(self.is_flexible)
-            .delimiter(self.separator)
-            .from_path(self.path_to_edges.as_path())?;
+        self.for_each_record(
+            self.path_to_edges.as_path(),
+            &mut report,
+            |record: EdgeRecord<Id, EL>, report| {
+                let is_self_loop = record.start == record.target;
+                report.add_edge(is_self_loop, record.add_to_graph(g));
 
-        for (i, result) in rdr.into_deserialize().enumerate() {
-            match result {
-                Ok(_result) => {
-                    let record: EdgeRecord<Id, EL> = _result;
-                    record.add_to_graph(g);
-                }
-                Err(e) => warn!("Line {:?}: Error when reading csv: {:?}", i + 1, e),
-            }
+                Ok(())
+            },
+        )?;
+
+        report.nodes_added = g.node_count() - num_of_nodes;
+
+        Ok(report)
+    }
+
+    /// Read the graph as `read` does, taking the edge weight from the fourth column
+    /// (`:WEIGHT` if headers are present). An empty weight field leaves the edge unweighted.
+    pub fn read_weighted<G, L: IdType, W>(&self, g: &mut G) -> Result<LoadReport>
+    where
+        G: MutGraphTrait<Id, NL, EL, L> + MutGraphWeightTrait<Id, W, L>,
+        for<'de> W: WeightType + Deserialize<'de>,
+    {
+        let num_of_nodes = g.node_count();
+        let mut report = LoadReport::default();
+        self.read_nodes(g, &mut report)?;
+
+        info!(
+            "Adding weighted edges from {}",
+            self.path_to_edges.as_path().to_str().unwrap()
+        );
+
+        self.for_each_record(
+            self.path_to_edges.as_path(),
+            &mut report,
+            |record: WeightedEdgeRecord<Id, EL, W>, report| {
+                let is_self_loop = record.start == record.target;
+                report.add_edge(is_self_loop, record.add_to_graph(g));
+
+                Ok(())
+            },
+        )?;
+
+        report.nodes_added = g.node_count() - num_of_nodes;
+
+        Ok(report)
+    }
+
+    fn read_nodes<G: MutGraphTrait<Id, NL, EL, L>, L: IdType>(
+        &self,
+        g: &mut G,
+        report: &mut LoadReport,
+    ) -> Result<()> {
+        if let Some(ref path_to_nodes) = self.path_to_nodes {
+            info!(
+                "Adding nodes from {}",
+                path_to_nodes.as_path().to_str().unwrap()
+            );
+
+            self.for_each_record(
+                path_to_nodes.as_path(),
+                report,
+                |record: NodeRecord<Id, NL>, report| {
+                    report.add_node(record.add_to_graph(g));
+
+                    Ok(())
+                },
+            )?;
         }
 
         Ok(())
     }
 
-    pub fn node_iter(&self) -> Result<Iter<'a, (Id, Option<NL>)>> {
+    pub fn node_iter(&self) -> io::Result<Iter<'a, (Id, Option<NL>)>> {
         if let Some(ref path_to_nodes) = self.path_to_nodes {
             info!(
                 "Reading nodes from {}",
@@ -194,7 +256,7 @@ where
         }
     }
 
-    pub fn edge_iter(&self) -> Result<Iter<'a, (Id, Id, Option<EL>)>> {
+    pub fn edge_iter(&self) -> io::Result<Iter<'a, (Id, Id, Option<EL>)>> {
         info!(
             "Reading edges from {}",
             self.path_to_edges.as_path().to_str().unwrap()
@@ -221,4 +283,270 @@ where
 
         Ok(Iter::new(Box::new(rdr)))
     }
+
+    pub fn weighted_edge_iter<W>(&self) -> io::Result<Iter<'a, (Id, Id, Option<EL>, Option<W>)>>
+    where
+        for<'de> W: WeightType + Deserialize<'de>,
+    {
+        info!(
+            "Reading weighted edges from {}",
+            self.path_to_edges.as_path().to_str().unwrap()
+        );
+        let rdr = ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .flexible(self.is_flexible)
+            .delimiter(self.separator)
+            .from_path(self.path_to_edges.as_path())?;
+
+        let rdr = rdr
+            .into_deserialize()
+            .enumerate()
+            .filter_map(|(i, result)| match result {
+                Ok(_result) => {
+                    let record: WeightedEdgeRecord<Id, EL, W> = _result;
+                    Some((record.start, record.target, record.label, record.weight))
+                }
+                Err(e) => {
+                    warn!("Line {:?}: Error when reading csv: {:?}", i + 1, e);
+                    None
+                }
+            });
+
+        Ok(Iter::new(Box::new(rdr)))
+    }
+}
+
+impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, NL, EL>
+where
+    for<'de> NL: Deserialize<'de>,
+    for<'de> EL: Deserialize<'de>,
+{
+    /// Read the graph as `read` does, but treat node ids as arbitrary strings.
+    /// Each distinct external id is assigned the next dense `Id` in order of first appearance,
+    /// and the returned map gives the external id of node `i` at index `i`.
+    ///
+    /// Fails with `GraphError::InvalidInput` if `g` is not empty, as its nodes would be mixed up
+    /// with the interned ids, and with `GraphError::InvalidData` if there are more distinct ids
+    /// than `Id` can hold.
+    pub fn read_with_id_map<G, L: IdType>(&self, g: &mut G) -> Result<(SetMap<String>, LoadReport)>
+    where
+        G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    {
+        if g.node_count() > 0 {
+            return Err(GraphError::InvalidInput(
+                "Node ids can only be interned into an empty graph".to_owned(),
+            ));
+        }
+
+        let mut report = LoadReport::default();
+        let mut id_map = SetMap::new();
+        self.read_nodes_with_id_map(g, &mut id_map, &mut report)?;
+
+        info!(
+            "Adding edges from {}",
+            self.path_to_edges.as_path().to_str().unwrap()
+        );
+
+        self.for_each_record(
+            self.path_to_edges.as_path(),
+            &mut report,
+            |record: EdgeRecord<String, EL>, report| {
+                let is_self_loop = record.start == record.target;
+                let start = intern_id(&mut id_map, record.start)?;
+                let target = intern_id(&mut id_map, record.target)?;
+                let is_new = EdgeRecord::new(start, target, record.label).add_to_graph(g);
+                report.add_edge(is_self_loop, is_new);
+
+                Ok(())
+            },
+        )?;
+
+        report.nodes_added = g.node_count();
+
+        Ok((id_map, report))
+    }
+
+    /// Read the graph as `read_weighted` does, interning node ids as `read_with_id_map` does.
+    /// `g` must be empty as well.
+    pub fn read_weighted_with_id_map<G, L: IdType, W>(
+        &self,
+        g: &mut G,
+    ) -> Result<(SetMap<String>, LoadReport)>
+    where
+        G: MutGraphTrait<Id, NL, EL, L> + MutGraphWeightTrait<Id, W, L>,
+        for<'de> W: WeightType + Deserialize<'de>,
+    {
+        if g.node_count() > 0 {
+            return Err(GraphError::InvalidInput(
+                "Node ids can only be interned into an empty graph".to_owned(),
+            ));
+        }
+
+        let mut report = LoadReport::default();
+        let mut id_map = SetMap::new();
+        self.read_nodes_with_id_map(g, &mut id_map, &mut report)?;
+
+        info!(
+            "Adding weighted edges from {}",
+            self.path_to_edges.as_path().to_str().unwrap()
+        );
+
+        self.for_each_record(
+            self.path_to_edges.as_path(),
+            &mut report,
+            |record: WeightedEdgeRecord<String, EL, W>, report| {
+                let is_self_loop = record.start == record.target;
+                let start = intern_id(&mut id_map, record.start)?;
+                let target = intern_id(&mut id_map, record.target)?;
+                let is_new = WeightedEdgeRecord::new(start, target, record.label, record.weight)
+                    .add_to_graph(g);
+                report.add_edge(is_self_loop, is_new);
+
+                Ok(())
+            },
+        )?;
+
+        report.nodes_added = g.node_count();
+
+        Ok((id_map, report))
+    }
+
+    fn read_nodes_with_id_map<G: MutGraphTrait<Id, NL, EL, L>, L: IdType>(
+        &self,
+        g: &mut G,
+        id_map: &mut SetMap<String>,
+        report: &mut LoadReport,
+    ) -> Result<()> {
+        if let Some(ref path_to_nodes) = self.path_to_nodes {
+            info!(
+                "Adding nodes from {}",
+                path_to_nodes.as_path().to_str().unwrap()
+            );
+
+            self.for_each_record(
+                path_to_nodes.as_path(),
+                report,
+                |record: NodeRecord<String, NL>, report| {
+                    let id = intern_id(id_map, record.id)?;
+                    report.add_node(NodeRecord::new(id, record.label).add_to_graph(g));
+
+                    Ok(())
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Id: IdType, NL: Hash + Eq + 'a, EL: Hash + Eq + 'a> CSVReader<'a, Id, NL, EL> {
+    /// Parse each row of `path` as a `T` and pass it to `f`, handling the rows that
+    /// can not be parsed according to the `ErrorPolicy`.
+    pub(crate) fn for_each_record<T, F>(
+        &self,
+        path: &Path,
+        report: &mut LoadReport,
+        mut f: F,
+    ) -> Result<()>
+    where
+        for<'de> T: Deserialize<'de>,
+        F: FnMut(T, &mut LoadReport) -> Result<()>,
+    {
+        // The field count is checked here rather than by `csv`,
+        // which would not give back the offending record.
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .flexible(true)
+            .delimiter(self.separator)
+            .from_path(path)?;
+
+        let headers = if self.has_headers {
+            Some(rdr.byte_headers()?.clone())
+        } else {
+            None
+        };
+        let mut num_of_fields = headers.as_ref().map(|h| h.len());
+
+        for result in rdr.byte_records() {
+            let record = result?;
+            report.rows_read += 1;
+
+            let expected_len = if self.is_flexible {
+                None
+            } else {
+                num_of_fields
+            };
+            let parsed = parse_record(&record, headers.as_ref(), expected_len);
+
+            if num_of_fields.is_none() {
+                num_of_fields = Some(record.len());
+            }
+
+            match parsed {
+                Ok(parsed) => f(parsed, report)?,
+                Err(error) => {
+                    let line = record.position().map_or(0, |p| p.line());
+                    self.skip_row(path, line, &record, error, report)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn skip_row(
+        &self,
+        path: &Path,
+        line: u64,
+        record: &ByteRecord,
+        error: String,
+        report: &mut LoadReport,
+    ) -> Result<()> {
+        let separator = (self.separator as char).to_string();
+        let row = SkippedRow {
+            path: path.to_path_buf(),
+            line,
+            record: record
+                .iter()
+                .map(|field| String::from_utf8_lossy(field))
+                .collect::<Vec<_>>()
+                .join(&separator),
+            error,
+        };
+
+        match self.error_policy {
+            ErrorPolicy::Skip => warn!("Error when reading csv: {}", row),
+            ErrorPolicy::Collect => {
+                warn!("Error when reading csv: {}", row);
+                report.skipped.push(row);
+            }
+            ErrorPolicy::FailFast => return Err(GraphError::InvalidData(row.to_string())),
+        }
+        report.skipped_rows += 1;
+
+        Ok(())
+    }
+}
+
+/// Parse `record` as a `T`, checking its number of fields against `expected_len` if given.
+pub(crate) fn parse_record<T>(
+    record: &ByteRecord,
+    headers: Option<&ByteRecord>,
+    expected_len: Option<usize>,
+) -> result::Result<T, String>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    match expected_len {
+        Some(n) if record.len() != n => Err(format!(
+            "found record with {} fields, but the previous record has {} fields",
+            record.len(),
+            n
+        )),
+        // The position is left out, as it is part of `SkippedRow`.
+        _ => record.deserialize(headers).map_err(|e| match *e.kind() {
+            csv::ErrorKind::Deserialize { ref err, .. } => err.to_string(),
+            _ => e.to_string(),
+        }),
+    }
 }
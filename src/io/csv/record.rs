@@ -23,12 +23,12 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 
 use serde;
-use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
 
-use generic::{IdType, MutGraphTrait};
+use generic::{IdType, MutGraphTrait, MutGraphWeightTrait, WeightType};
 
 #[derive(Debug, Serialize)]
-pub struct NodeRecord<Id: IdType, N: Hash + Eq> {
+pub struct NodeRecord<Id, N: Hash + Eq> {
     #[serde(rename = "nodeId:ID")]
     pub(crate) id: Id,
     #[serde(rename = ":LABEL")]
@@ -36,7 +36,7 @@ pub struct NodeRecord<Id: IdType, N: Hash + Eq> {
 }
 
 #[derive(Debug, Serialize)]
-pub struct EdgeRecord<Id: IdType, E: Hash + Eq> {
+pub struct EdgeRecord<Id, E: Hash + Eq> {
     #[serde(rename = ":START_ID")]
     pub(crate) start: Id,
     #[serde(rename = ":END_ID")]
@@ -45,19 +45,37 @@ pub struct EdgeRecord<Id: IdType, E: Hash + Eq> {
     pub(crate) label: Option<E>,
 }
 
-impl<Id: IdType, N: Hash + Eq> NodeRecord<Id, N> {
+#[derive(Debug, Serialize)]
+pub struct WeightedEdgeRecord<Id, E: Hash + Eq, W: WeightType> {
+    #[serde(rename = ":START_ID")]
+    pub(crate) start: Id,
+    #[serde(rename = ":END_ID")]
+    pub(crate) target: Id,
+    #[serde(rename = ":TYPE")]
+    pub(crate) label: Option<E>,
+    #[serde(rename = ":WEIGHT")]
+    pub(crate) weight: Option<W>,
+}
+
+impl<Id, N: Hash + Eq> NodeRecord<Id, N> {
     #[inline]
     pub fn new(id: Id, label: Option<N>) -> Self {
         NodeRecord { id, label }
     }
 
     #[inline]
-    pub fn add_to_graph<E: Hash + Eq, G: MutGraphTrait<Id, N, E, L>, L: IdType>(self, g: &mut G) {
-        g.add_node(self.id, self.label);
+    pub fn add_to_graph<E: Hash + Eq, G: MutGraphTrait<Id, N, E, L>, L: IdType>(
+        self,
+        g: &mut G,
+    ) -> bool
+    where
+        Id: IdType,
+    {
+        g.add_node(self.id, self.label)
     }
 }
 
-impl<Id: IdType, E: Hash + Eq> EdgeRecord<Id, E> {
+impl<Id, E: Hash + Eq> EdgeRecord<Id, E> {
     #[inline]
     pub fn new(start: Id, target: Id, label: Option<E>) -> Self {
         EdgeRecord {
@@ -68,12 +86,44 @@ impl<Id: IdType, E: Hash + Eq> EdgeRecord<Id, E> {
     }
 
     #[inline]
-    pub fn add_to_graph<N: Hash + Eq, G: MutGraphTrait<Id, N, E, L>, L: IdType>(self, g: &mut G) {
-        g.add_edge(self.start, self.target, self.label);
+    pub fn add_to_graph<N: Hash + Eq, G: MutGraphTrait<Id, N, E, L>, L: IdType>(
+        self,
+        g: &mut G,
+    ) -> bool
+    where
+        Id: IdType,
+    {
+        g.add_edge(self.start, self.target, self.label)
+    }
+}
+
+impl<Id, E: Hash + Eq, W: WeightType> WeightedEdgeRecord<Id, E, W> {
+    #[inline]
+    pub fn new(start: Id, target: Id, label: Option<E>, weight: Option<W>) -> Self {
+        WeightedEdgeRecord {
+            start,
+            target,
+            label,
+            weight,
+        }
+    }
+
+    #[inline]
+    pub fn add_to_graph<N: Hash + Eq, G, L: IdType>(self, g: &mut G) -> bool
+    where
+        Id: IdType,
+        G: MutGraphTrait<Id, N, E, L> + MutGraphWeightTrait<Id, W, L>,
+    {
+        let is_new = g.add_edge(self.start, self.target, self.label);
+        if self.weight.is_some() {
+            g.update_edge_weight(self.start, self.target, self.weight);
+        }
+
+        is_new
     }
 }
 
-impl<'de, Id: IdType, N: Hash + Eq> Deserialize<'de> for NodeRecord<Id, N>
+impl<'de, Id, N: Hash + Eq> Deserialize<'de> for NodeRecord<Id, N>
 where
     Id: serde::Deserialize<'de>,
     N: serde::Deserialize<'de>,
@@ -96,7 +146,7 @@ where
             _n: PhantomData<N>,
         };
 
-        impl<'de, Id: IdType, N: Hash + Eq> Visitor<'de> for NodeRecordVisitor<Id, N>
+        impl<'de, Id, N: Hash + Eq> Visitor<'de> for NodeRecordVisitor<Id, N>
         where
             Id: serde::Deserialize<'de>,
             N: serde::Deserialize<'de>,
@@ -159,7 +209,7 @@ where
     }
 }
 
-impl<'de, Id: IdType, E: Hash + Eq> Deserialize<'de> for EdgeRecord<Id, E>
+impl<'de, Id, E: Hash + Eq> Deserialize<'de> for EdgeRecord<Id, E>
 where
     Id: serde::Deserialize<'de>,
     E: serde::Deserialize<'de>,
@@ -177,6 +227,8 @@ where
             Target,
             #[serde(rename = ":TYPE")]
             Label,
+            #[serde(rename = ":WEIGHT")]
+            Weight,
         }
 
         struct EdgeRecordVisitor<Id, E> {
@@ -184,7 +236,7 @@ where
             _e: PhantomData<E>,
         };
 
-        impl<'de, Id: IdType, E: Hash + Eq> Visitor<'de> for EdgeRecordVisitor<Id, E>
+        impl<'de, Id, E: Hash + Eq> Visitor<'de> for EdgeRecordVisitor<Id, E>
         where
             Id: serde::Deserialize<'de>,
             E: serde::Deserialize<'de>,
@@ -237,6 +289,9 @@ where
                             }
                             label = Some(map.next_value().unwrap_or(None));
                         }
+                        Field::Weight => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
                     }
                 }
                 let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
@@ -257,3 +312,117 @@ where
         )
     }
 }
+
+impl<'de, Id, E: Hash + Eq, W: WeightType> Deserialize<'de> for WeightedEdgeRecord<Id, E, W>
+where
+    Id: serde::Deserialize<'de>,
+    E: serde::Deserialize<'de>,
+    W: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(field_identifier)]
+        enum Field {
+            #[serde(rename = ":START_ID")]
+            Start,
+            #[serde(rename = ":END_ID")]
+            Target,
+            #[serde(rename = ":TYPE")]
+            Label,
+            #[serde(rename = ":WEIGHT")]
+            Weight,
+        }
+
+        struct WeightedEdgeRecordVisitor<Id, E, W> {
+            _id: PhantomData<Id>,
+            _e: PhantomData<E>,
+            _w: PhantomData<W>,
+        }
+
+        impl<'de, Id, E: Hash + Eq, W: WeightType> Visitor<'de> for WeightedEdgeRecordVisitor<Id, E, W>
+        where
+            Id: serde::Deserialize<'de>,
+            E: serde::Deserialize<'de>,
+            W: serde::Deserialize<'de>,
+        {
+            type Value = WeightedEdgeRecord<Id, E, W>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WeightedEdgeRecord")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<WeightedEdgeRecord<Id, E, W>, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let start = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let target = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let label = seq.next_element().unwrap_or(None).unwrap_or(None);
+                let weight = seq.next_element().unwrap_or(None).unwrap_or(None);
+
+                Ok(WeightedEdgeRecord::new(start, target, label, weight))
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<WeightedEdgeRecord<Id, E, W>, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut start = None;
+                let mut target = None;
+                let mut label = None;
+                let mut weight = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Start => {
+                            if start.is_some() {
+                                return Err(de::Error::duplicate_field("start"));
+                            }
+                            start = Some(map.next_value()?);
+                        }
+                        Field::Target => {
+                            if target.is_some() {
+                                return Err(de::Error::duplicate_field("target"));
+                            }
+                            target = Some(map.next_value()?);
+                        }
+                        Field::Label => {
+                            if label.is_some() {
+                                return Err(de::Error::duplicate_field("label"));
+                            }
+                            label = Some(map.next_value().unwrap_or(None));
+                        }
+                        Field::Weight => {
+                            if weight.is_some() {
+                                return Err(de::Error::duplicate_field("weight"));
+                            }
+                            weight = Some(map.next_value().unwrap_or(None));
+                        }
+                    }
+                }
+                let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
+                let target = target.ok_or_else(|| de::Error::missing_field("target"))?;
+                let label = label.unwrap_or(None);
+                let weight = weight.unwrap_or(None);
+                Ok(WeightedEdgeRecord::new(start, target, label, weight))
+            }
+        }
+
+        const FIELDS: &[&str] = &["start", "target", "label", "weight"];
+        deserializer.deserialize_struct(
+            "WeightedEdgeRecord",
+            FIELDS,
+            WeightedEdgeRecordVisitor {
+                _id: PhantomData,
+                _e: PhantomData,
+                _w: PhantomData,
+            },
+        )
+    }
+}
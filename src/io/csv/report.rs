@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! The outcome of loading a graph with `CSVReader`.
+use std::fmt;
+use std::path::PathBuf;
+
+/// What `CSVReader` does with a row that can not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Log a warning and skip the row, the default.
+    Skip,
+    /// Log a warning, skip the row and keep it in `LoadReport::skipped`.
+    Collect,
+    /// Stop at the first bad row with `GraphError::InvalidData`.
+    FailFast,
+}
+
+/// A row that was skipped, collected under `ErrorPolicy::Collect`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRow {
+    pub path: PathBuf,
+    /// 1-based line number in `path`
+    pub line: u64,
+    /// The fields of the row, joined by the separator
+    pub record: String,
+    pub error: String,
+}
+
+impl fmt::Display for SkippedRow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} line {}: {} in {:?}",
+            self.path.display(),
+            self.line,
+            self.error,
+            self.record
+        )
+    }
+}
+
+/// Statistics of a `CSVReader::read`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    /// Data rows of both files, excluding headers
+    pub rows_read: usize,
+    /// New nodes, including those only added as the end of an edge
+    pub nodes_added: usize,
+    pub edges_added: usize,
+    /// Node rows whose id was already in the graph
+    pub duplicate_nodes: usize,
+    /// Edge rows whose edge was already in the graph
+    pub duplicate_edges: usize,
+    /// Edge rows with the same start and target, which are still added
+    pub self_loops: usize,
+    pub skipped_rows: usize,
+    /// The skipped rows, only filled under `ErrorPolicy::Collect`
+    pub skipped: Vec<SkippedRow>,
+}
+
+impl LoadReport {
+    #[inline]
+    pub(crate) fn add_node(&mut self, is_new: bool) {
+        if !is_new {
+            self.duplicate_nodes += 1;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_edge(&mut self, is_self_loop: bool, is_new: bool) {
+        if is_self_loop {
+            self.self_loops += 1;
+        }
+        if is_new {
+            self.edges_added += 1;
+        } else {
+            self.duplicate_edges += 1;
+        }
+    }
+}
@@ -26,8 +26,9 @@ use csv::WriterBuilder;
 use serde::Serialize;
 
 use generic::GeneralGraph;
-use generic::IdType;
-use io::csv::record::{EdgeRecord, NodeRecord};
+use generic::{GraphWeightTrait, IdType, MapTrait, WeightType};
+use io::csv::record::{EdgeRecord, NodeRecord, WeightedEdgeRecord};
+use map::SetMap;
 
 pub struct CSVWriter<'a, Id, NL, EL, L>
 where
@@ -40,6 +41,7 @@ where
     path_to_nodes: PathBuf,
     path_to_edges: PathBuf,
     separator: u8,
+    id_map: Option<&'a SetMap<String>>,
 }
 
 impl<'a, Id, NL, EL, L> CSVWriter<'a, Id, NL, EL, L>
@@ -60,6 +62,7 @@ where
             path_to_nodes: path_to_nodes.as_ref().to_path_buf(),
             path_to_edges: path_to_edges.as_ref().to_path_buf(),
             separator: b',',
+            id_map: None,
         }
     }
 
@@ -85,8 +88,19 @@ where
             path_to_nodes: path_to_nodes.as_ref().to_path_buf(),
             path_to_edges: path_to_edges.as_ref().to_path_buf(),
             separator: sep_string.chars().next().unwrap() as u8,
+            id_map: None,
         }
     }
+
+    /// Write node `i` as the `i`-th item of `id_map` (e.g. the map returned by
+    /// `CSVReader::read_with_id_map`) instead of its internal id.
+    ///
+    /// # Panics
+    /// If a node of the graph has no entry in `id_map`.
+    pub fn id_map(mut self, id_map: &'a SetMap<String>) -> Self {
+        self.id_map = Some(id_map);
+        self
+    }
 }
 
 impl<'a, Id, NL, EL, L> CSVWriter<'a, Id, NL, EL, L>
@@ -97,23 +111,46 @@ where
     L: 'a + IdType + Serialize,
 {
     pub fn write(&self) -> Result<()> {
+        self.write_nodes()?;
+
         let g = self.g.as_labeled_graph();
 
         info!(
-            "csv::Writer::write - Writing nodes to {}",
-            self.path_to_nodes.as_path().to_str().unwrap()
+            "csv::Writer::write - Writing edges to {}",
+            self.path_to_edges.as_path().to_str().unwrap()
         );
 
         let mut wtr = WriterBuilder::new()
             .delimiter(self.separator)
-            .from_path(self.path_to_nodes.as_path())?;
+            .from_path(self.path_to_edges.as_path())?;
 
-        for id in self.g.node_indices() {
-            wtr.serialize(NodeRecord::new(id, g.get_node_label(id)))?;
+        for (start, target) in self.g.edge_indices() {
+            let label = g.get_edge_label(start, target);
+            match self.id_map {
+                Some(id_map) => wtr.serialize(EdgeRecord::new(
+                    external_id(id_map, start),
+                    external_id(id_map, target),
+                    label,
+                ))?,
+                None => wtr.serialize(EdgeRecord::new(start, target, label))?,
+            }
         }
 
+        Ok(())
+    }
+
+    /// Write the graph as `write` does, with an extra `:WEIGHT` column taken from `weights`,
+    /// which is usually the same graph as the one being written.
+    pub fn write_weighted<W>(&self, weights: &GraphWeightTrait<Id, W, L>) -> Result<()>
+    where
+        W: WeightType + Serialize,
+    {
+        self.write_nodes()?;
+
+        let g = self.g.as_labeled_graph();
+
         info!(
-            "csv::Writer::write - Writing edges to {}",
+            "csv::Writer::write_weighted - Writing weighted edges to {}",
             self.path_to_edges.as_path().to_str().unwrap()
         );
 
@@ -122,13 +159,49 @@ where
             .from_path(self.path_to_edges.as_path())?;
 
         for (start, target) in self.g.edge_indices() {
-            wtr.serialize(EdgeRecord::new(
-                start,
-                target,
-                g.get_edge_label(start, target),
-            ))?;
+            let label = g.get_edge_label(start, target);
+            let weight = weights.get_edge_weight(start, target);
+            match self.id_map {
+                Some(id_map) => wtr.serialize(WeightedEdgeRecord::new(
+                    external_id(id_map, start),
+                    external_id(id_map, target),
+                    label,
+                    weight,
+                ))?,
+                None => wtr.serialize(WeightedEdgeRecord::new(start, target, label, weight))?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_nodes(&self) -> Result<()> {
+        let g = self.g.as_labeled_graph();
+
+        info!(
+            "csv::Writer::write - Writing nodes to {}",
+            self.path_to_nodes.as_path().to_str().unwrap()
+        );
+
+        let mut wtr = WriterBuilder::new()
+            .delimiter(self.separator)
+            .from_path(self.path_to_nodes.as_path())?;
+
+        for id in self.g.node_indices() {
+            let label = g.get_node_label(id);
+            match self.id_map {
+                Some(id_map) => wtr.serialize(NodeRecord::new(external_id(id_map, id), label))?,
+                None => wtr.serialize(NodeRecord::new(id, label))?,
+            }
         }
 
         Ok(())
     }
 }
+
+fn external_id<Id: IdType>(id_map: &SetMap<String>, id: Id) -> &String {
+    match id_map.get_item(id.id()) {
+        Some(key) => key,
+        None => panic!("Node {:?} has no external id.", id),
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! [DOT](https://graphviz.org/doc/info/lang.html), the language of Graphviz, mostly for
+//! looking at small graphs, e.g. query graphs and their matches, while debugging.
+//!
+//! # Example
+//! ```
+//! use rust_graph::io::dot::to_dot;
+//! use rust_graph::prelude::*;
+//! use rust_graph::UnGraphMap;
+//!
+//! let mut g = UnGraphMap::<&str>::new();
+//! g.add_node(0, Some("a"));
+//! g.add_edge(0, 1, Some("x"));
+//!
+//! assert_eq!(
+//!     to_dot(&g, &[1]).unwrap(),
+//!     "graph {\n    \
+//!      0 [label=\"0: a\"];\n    \
+//!      1 [label=\"1\", style=filled, fillcolor=gold];\n    \
+//!      0 -- 1 [label=\"x\"];\n\
+//!      }\n"
+//! );
+//! ```
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::io::Write;
+
+use serde::Serialize;
+
+use error::Result;
+use generic::{GeneralGraph, IdType};
+use io::label::{edge_label_string, node_label_string};
+
+/// Write `g` as a `digraph` or a `graph` according to `is_directed()`, with the nodes and edges
+/// sorted by id. A node is shown as `<id>: <label>`, and an edge by its label.
+///
+/// The nodes in `highlight` (e.g. a match or a BFS frontier) are filled, and the edges between
+/// two of them are drawn in bold red.
+pub fn write_dot<Id, NL, EL, L, W>(
+    g: &GeneralGraph<Id, NL, EL, L>,
+    highlight: &[Id],
+    writer: &mut W,
+) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    L: IdType,
+    W: Write,
+{
+    let labeled = g.as_labeled_graph();
+    let highlight = highlight.iter().cloned().collect::<HashSet<_>>();
+    let (graph, edge_op) = if g.is_directed() {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    writeln!(writer, "{} {{", graph)?;
+
+    let mut nodes = g.node_indices().collect::<Vec<_>>();
+    nodes.sort();
+    for id in nodes {
+        let label = match node_label_string(labeled, id)? {
+            Some(label) => format!("{}: {}", id.id(), label),
+            None => id.id().to_string(),
+        };
+
+        let mut attrs = vec![format!("label={}", quote(&label))];
+        if highlight.contains(&id) {
+            attrs.push("style=filled, fillcolor=gold".to_owned());
+        }
+        writeln!(writer, "    {} [{}];", id.id(), attrs.join(", "))?;
+    }
+
+    let mut edges = g.edge_indices().collect::<Vec<_>>();
+    edges.sort();
+    for (start, target) in edges {
+        let mut attrs = Vec::new();
+        if let Some(label) = edge_label_string(labeled, start, target)? {
+            attrs.push(format!("label={}", quote(&label)));
+        }
+        if highlight.contains(&start) && highlight.contains(&target) {
+            attrs.push("color=red, penwidth=2".to_owned());
+        }
+
+        if attrs.is_empty() {
+            writeln!(writer, "    {} {} {};", start.id(), edge_op, target.id())?;
+        } else {
+            writeln!(
+                writer,
+                "    {} {} {} [{}];",
+                start.id(),
+                edge_op,
+                target.id(),
+                attrs.join(", ")
+            )?;
+        }
+    }
+
+    writeln!(writer, "}}")?;
+
+    Ok(())
+}
+
+/// Return `g` in DOT as `write_dot` writes it.
+pub fn to_dot<Id, NL, EL, L>(g: &GeneralGraph<Id, NL, EL, L>, highlight: &[Id]) -> Result<String>
+where
+    Id: IdType,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    L: IdType,
+{
+    let mut dot = Vec::new();
+    write_dot(g, highlight, &mut dot)?;
+
+    Ok(String::from_utf8(dot).unwrap())
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
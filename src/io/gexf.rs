@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! [GEXF](https://gephi.org/gexf/format/), the native format of Gephi.
+//!
+//! Node and edge labels are written as the `label` attribute of `node` and `edge`.
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde::Serialize;
+
+use error::Result;
+use generic::{GeneralGraph, IdType};
+use io::label::{edge_label_string, node_label_string};
+use io::xml::escape;
+
+/// Write `g` as GEXF 1.3, with the nodes and edges sorted by id.
+/// Labels must be scalars, e.g. strings or numbers.
+pub fn write_gexf<Id, NL, EL, L, P>(g: &GeneralGraph<Id, NL, EL, L>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    L: IdType,
+    P: AsRef<Path>,
+{
+    info!("Writing GEXF to {}", path.as_ref().to_str().unwrap());
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    let labeled = g.as_labeled_graph();
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<gexf xmlns="http://gexf.net/1.3" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://gexf.net/1.3 http://gexf.net/1.3/gexf.xsd" version="1.3">"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <graph defaultedgetype="{}">"#,
+        if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        }
+    )?;
+
+    writeln!(writer, "    <nodes>")?;
+    let mut nodes = g.node_indices().collect::<Vec<_>>();
+    nodes.sort();
+    for id in nodes {
+        let label = node_label_string(labeled, id)?;
+
+        match label {
+            Some(label) => writeln!(
+                writer,
+                r#"      <node id="{}" label="{}"/>"#,
+                id.id(),
+                escape(&label)
+            )?,
+            None => writeln!(writer, r#"      <node id="{}"/>"#, id.id())?,
+        }
+    }
+    writeln!(writer, "    </nodes>")?;
+
+    writeln!(writer, "    <edges>")?;
+    let mut edges = g.edge_indices().collect::<Vec<_>>();
+    edges.sort();
+    for (i, (start, target)) in edges.into_iter().enumerate() {
+        let label = edge_label_string(labeled, start, target)?;
+
+        match label {
+            Some(label) => writeln!(
+                writer,
+                r#"      <edge id="{}" source="{}" target="{}" label="{}"/>"#,
+                i,
+                start.id(),
+                target.id(),
+                escape(&label)
+            )?,
+            None => writeln!(
+                writer,
+                r#"      <edge id="{}" source="{}" target="{}"/>"#,
+                i,
+                start.id(),
+                target.id()
+            )?,
+        }
+    }
+    writeln!(writer, "    </edges>")?;
+
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</gexf>")?;
+    writer.flush()?;
+
+    Ok(())
+}
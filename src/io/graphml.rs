@@ -0,0 +1,386 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! [GraphML](http://graphml.graphdrawing.org/), as read and written by e.g. yEd, Gephi,
+//! Cytoscape and NetworkX.
+//!
+//! Node and edge labels are stored as `data` of the keys whose `attr.name` is `label`.
+//! Other keys, nested graphs, hyperedges and ports are ignored when reading.
+//!
+//! # Example
+//! ```
+//! use rust_graph::io::graphml::{read_graphml, write_graphml};
+//! use rust_graph::prelude::*;
+//! use rust_graph::DiGraphMap;
+//!
+//! let mut g = DiGraphMap::<String>::new();
+//! g.add_node(0, Some("a <b>".to_owned()));
+//! g.add_edge(0, 1, Some("c".to_owned()));
+//!
+//! let path = std::env::temp_dir().join("rust_graph_graphml_doctest.graphml");
+//! write_graphml(&g, &path).unwrap();
+//!
+//! let g_: DiGraphMap<String> = read_graphml(&path).unwrap();
+//! assert_eq!(g_.get_node_label(0), Some(&"a <b>".to_owned()));
+//! assert_eq!(g_.get_edge_label(0, 1), Some(&"c".to_owned()));
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde::Serialize;
+
+use error::{GraphError, Result};
+use generic::{GeneralGraph, GraphTrait, GraphType, IdType, MutGraphTrait};
+use graph_impl::TypedGraphMap;
+use io::label::{edge_label_string, node_label_string};
+use io::text::intern_id;
+use io::xml::{escape, get_attr, XmlEvent, XmlReader};
+use map::SetMap;
+
+/// The graph read from GraphML, whose labels are strings.
+pub type GraphMLGraph<Id, Ty> = TypedGraphMap<Id, String, String, Ty>;
+
+/// Write `g` as GraphML, with the nodes and edges sorted by id. The node with id `i` is
+/// written as `n<i>`. Labels must be scalars, e.g. strings or numbers.
+pub fn write_graphml<Id, NL, EL, L, P>(g: &GeneralGraph<Id, NL, EL, L>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq + Serialize,
+    L: IdType,
+    P: AsRef<Path>,
+{
+    info!("Writing GraphML to {}", path.as_ref().to_str().unwrap());
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    let labeled = g.as_labeled_graph();
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <key id="d0" for="node" attr.name="label" attr.type="string"/>"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <key id="d1" for="edge" attr.name="label" attr.type="string"/>"#
+    )?;
+    writeln!(
+        writer,
+        r#"  <graph id="G" edgedefault="{}">"#,
+        if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        }
+    )?;
+
+    let mut nodes = g.node_indices().collect::<Vec<_>>();
+    nodes.sort();
+    for id in nodes {
+        let label = node_label_string(labeled, id)?;
+
+        match label {
+            Some(label) => {
+                writeln!(writer, r#"    <node id="n{}">"#, id.id())?;
+                writeln!(writer, r#"      <data key="d0">{}</data>"#, escape(&label))?;
+                writeln!(writer, "    </node>")?;
+            }
+            None => writeln!(writer, r#"    <node id="n{}"/>"#, id.id())?,
+        }
+    }
+
+    let mut edges = g.edge_indices().collect::<Vec<_>>();
+    edges.sort();
+    for (start, target) in edges {
+        let label = edge_label_string(labeled, start, target)?;
+
+        match label {
+            Some(label) => {
+                writeln!(
+                    writer,
+                    r#"    <edge source="n{}" target="n{}">"#,
+                    start.id(),
+                    target.id()
+                )?;
+                writeln!(writer, r#"      <data key="d1">{}</data>"#, escape(&label))?;
+                writeln!(writer, "    </edge>")?;
+            }
+            None => writeln!(
+                writer,
+                r#"    <edge source="n{}" target="n{}"/>"#,
+                start.id(),
+                target.id()
+            )?,
+        }
+    }
+
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Read a GraphML file into a `TypedGraphMap` with string labels.
+///
+/// If every node id is a number, optionally prefixed by `n` as written by `write_graphml`,
+/// the number is kept as the id. Otherwise, the ids are assigned as `read_graphml_with_id_map`
+/// does. An edge that appears more than once is only added once.
+///
+/// Fails with `GraphError::FormatMismatch` if the `edgedefault` of the graph does not match `Ty`.
+pub fn read_graphml<Id, Ty, P>(path: P) -> Result<GraphMLGraph<Id, Ty>>
+where
+    Id: IdType,
+    Ty: GraphType,
+    P: AsRef<Path>,
+{
+    let document = GraphMLDocument::read(path, Ty::is_directed())?;
+
+    let is_numeric = document
+        .nodes
+        .iter()
+        .map(|(id, _)| id)
+        .chain(document.edges.iter().flat_map(|(s, t, _)| vec![s, t]))
+        .all(|id| match numeric_id(id) {
+            Some(id) => id < Id::max_usize(),
+            None => false,
+        });
+
+    if is_numeric {
+        document.into_graph(|id| Ok(Id::new(numeric_id(&id).unwrap())))
+    } else {
+        document.into_graph_with_id_map().map(|(g, _)| g)
+    }
+}
+
+/// Read a GraphML file into a `TypedGraphMap` with string labels, assigning dense ids to the
+/// nodes in order of appearance. The returned map gives the GraphML id of node `i` at index `i`.
+pub fn read_graphml_with_id_map<Id, Ty, P>(
+    path: P,
+) -> Result<(GraphMLGraph<Id, Ty>, SetMap<String>)>
+where
+    Id: IdType,
+    Ty: GraphType,
+    P: AsRef<Path>,
+{
+    GraphMLDocument::read(path, Ty::is_directed())?.into_graph_with_id_map()
+}
+
+/// The node id `k` or `n<k>`.
+fn numeric_id(id: &str) -> Option<usize> {
+    let digits = id.strip_prefix('n').unwrap_or(id);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// The nodes and edges of a GraphML file, by their GraphML ids, in order of appearance.
+struct GraphMLDocument {
+    nodes: Vec<(String, Option<String>)>,
+    edges: Vec<(String, String, Option<String>)>,
+}
+
+impl GraphMLDocument {
+    fn read<P: AsRef<Path>>(path: P, is_directed: bool) -> Result<Self> {
+        info!("Reading GraphML from {}", path.as_ref().to_str().unwrap());
+
+        let xml = fs::read_to_string(path)?;
+        let mut reader = XmlReader::new(&xml);
+
+        let mut node_label_keys = HashSet::new();
+        let mut edge_label_keys = HashSet::new();
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        // Whether we are in a node (`Some(true)`) or an edge (`Some(false)`).
+        let mut in_node = None;
+        let mut label: Option<String> = None;
+        let mut is_label = false;
+        let mut num_of_graphs = 0;
+
+        while let Some(event) = reader.read_event()? {
+            match event {
+                XmlEvent::Start {
+                    name,
+                    attrs,
+                    is_empty,
+                } => match name {
+                    "key" if get_attr(&attrs, "attr.name") == Some("label") => {
+                        let id = required_attr(&attrs, "id", name)?;
+                        match get_attr(&attrs, "for").unwrap_or("all") {
+                            "node" => {
+                                node_label_keys.insert(id);
+                            }
+                            "edge" => {
+                                edge_label_keys.insert(id);
+                            }
+                            "all" => {
+                                node_label_keys.insert(id.clone());
+                                edge_label_keys.insert(id);
+                            }
+                            _ => {}
+                        }
+                    }
+                    "graph" => {
+                        num_of_graphs += 1;
+                        if num_of_graphs > 1 {
+                            return Err(GraphError::InvalidData(
+                                "Only one graph per GraphML file is supported".to_owned(),
+                            ));
+                        }
+
+                        let edgedefault = required_attr(&attrs, "edgedefault", name)?;
+                        if (edgedefault == "directed") != is_directed {
+                            return Err(GraphError::FormatMismatch(format!(
+                                "Expected a {} graph, found edgedefault=\"{}\"",
+                                if is_directed {
+                                    "directed"
+                                } else {
+                                    "undirected"
+                                },
+                                edgedefault
+                            )));
+                        }
+                    }
+                    "node" => {
+                        nodes.push((required_attr(&attrs, "id", name)?, None));
+                        if !is_empty {
+                            in_node = Some(true);
+                        }
+                    }
+                    "edge" => {
+                        edges.push((
+                            required_attr(&attrs, "source", name)?,
+                            required_attr(&attrs, "target", name)?,
+                            None,
+                        ));
+                        if !is_empty {
+                            in_node = Some(false);
+                        }
+                    }
+                    "data" => {
+                        let key = required_attr(&attrs, "key", name)?;
+                        is_label = match in_node {
+                            Some(true) => node_label_keys.contains(&key),
+                            Some(false) => edge_label_keys.contains(&key),
+                            None => false,
+                        };
+                        if is_label {
+                            label = Some(String::new());
+                            // A self-closing label, e.g. `<data key="d0"/>`, has no end tag.
+                            if is_empty {
+                                is_label = false;
+                                set_label(in_node, &mut nodes, &mut edges, label.take());
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                XmlEvent::Text(text) => {
+                    if let (true, Some(label)) = (is_label, label.as_mut()) {
+                        label.push_str(&text);
+                    }
+                }
+                XmlEvent::End(name) => match name {
+                    "node" | "edge" => in_node = None,
+                    "data" if is_label => {
+                        is_label = false;
+                        set_label(in_node, &mut nodes, &mut edges, label.take());
+                    }
+                    _ => {}
+                },
+            }
+        }
+
+        if num_of_graphs == 0 {
+            return Err(GraphError::InvalidData(
+                "No graph in the GraphML file".to_owned(),
+            ));
+        }
+
+        Ok(GraphMLDocument { nodes, edges })
+    }
+
+    fn into_graph<Id, Ty, F>(self, mut to_id: F) -> Result<GraphMLGraph<Id, Ty>>
+    where
+        Id: IdType,
+        Ty: GraphType,
+        F: FnMut(String) -> Result<Id>,
+    {
+        let mut g = TypedGraphMap::new();
+
+        for (id, label) in self.nodes {
+            g.add_node(to_id(id)?, label);
+        }
+        for (start, target, label) in self.edges {
+            let start = to_id(start)?;
+            let target = to_id(target)?;
+            if !g.has_edge(start, target) {
+                g.add_edge(start, target, label);
+            }
+        }
+
+        Ok(g)
+    }
+
+    fn into_graph_with_id_map<Id: IdType, Ty: GraphType>(
+        self,
+    ) -> Result<(GraphMLGraph<Id, Ty>, SetMap<String>)> {
+        let mut id_map = SetMap::new();
+        let g = self.into_graph(|id| intern_id(&mut id_map, id))?;
+
+        Ok((g, id_map))
+    }
+}
+
+/// Set the label of the last node if `in_node` is `Some(true)`, or of the last edge
+/// if it is `Some(false)`.
+fn set_label(
+    in_node: Option<bool>,
+    nodes: &mut [(String, Option<String>)],
+    edges: &mut [(String, String, Option<String>)],
+    label: Option<String>,
+) {
+    match in_node {
+        Some(true) => nodes.last_mut().unwrap().1 = label,
+        Some(false) => edges.last_mut().unwrap().2 = label,
+        None => {}
+    }
+}
+
+fn required_attr(attrs: &[(&str, String)], attr: &str, tag: &str) -> Result<String> {
+    match get_attr(attrs, attr) {
+        Some(value) => Ok(value.to_owned()),
+        None => Err(GraphError::InvalidData(format!(
+            "Missing attribute {} of <{}>",
+            attr, tag
+        ))),
+    }
+}
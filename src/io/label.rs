@@ -0,0 +1,266 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Turning labels into strings for the text formats that support them, e.g. GraphML and DOT.
+use std::error;
+use std::fmt;
+use std::hash::Hash;
+
+use serde::ser::{self, Impossible, Serialize};
+
+use error::{GraphError, Result};
+use generic::{GraphLabelTrait, IdType};
+
+/// The label of node `id` as a string, see `label_to_string`.
+pub(crate) fn node_label_string<Id, NL, EL, L>(
+    g: &GraphLabelTrait<Id, NL, EL, L>,
+    id: Id,
+) -> Result<Option<String>>
+where
+    Id: IdType,
+    NL: Hash + Eq + Serialize,
+    EL: Hash + Eq,
+    L: IdType,
+{
+    match g.get_node_label(id) {
+        Some(label) => label_to_string(label),
+        None => Ok(None),
+    }
+}
+
+/// The label of edge (`start`, `target`) as a string, see `label_to_string`.
+pub(crate) fn edge_label_string<Id, NL, EL, L>(
+    g: &GraphLabelTrait<Id, NL, EL, L>,
+    start: Id,
+    target: Id,
+) -> Result<Option<String>>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq + Serialize,
+    L: IdType,
+{
+    match g.get_edge_label(start, target) {
+        Some(label) => label_to_string(label),
+        None => Ok(None),
+    }
+}
+
+/// Turn a label into a string, or `None` for a unit label (e.g. `Void`).
+/// Only scalar labels, i.e. strings, chars, numbers and bools, are supported.
+pub(crate) fn label_to_string<T: Serialize + ?Sized>(label: &T) -> Result<Option<String>> {
+    label
+        .serialize(LabelSerializer)
+        .map_err(|e| GraphError::InvalidInput(e.to_string()))
+}
+
+#[derive(Debug)]
+struct LabelError(String);
+
+impl fmt::Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl error::Error for LabelError {}
+
+impl ser::Error for LabelError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        LabelError(msg.to_string())
+    }
+}
+
+fn unsupported<T>(kind: &str) -> ::std::result::Result<T, LabelError> {
+    Err(LabelError(format!("{} labels are not supported", kind)))
+}
+
+struct LabelSerializer;
+
+impl ser::Serializer for LabelSerializer {
+    type Ok = Option<String>;
+    type Error = LabelError;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, v: bool) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i16(self, v: i16) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i32(self, v: i32) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_u16(self, v: u16) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_u32(self, v: u32) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_f64(self, v: f64) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_char(self, v: char) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> ::std::result::Result<Self::Ok, Self::Error> {
+        unsupported("Byte")
+    }
+
+    fn serialize_none(self) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> ::std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(
+        self,
+        _: &'static str,
+    ) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> ::std::result::Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> ::std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> ::std::result::Result<Self::Ok, Self::Error> {
+        unsupported("Enum")
+    }
+
+    fn serialize_seq(
+        self,
+        _: Option<usize>,
+    ) -> ::std::result::Result<Self::SerializeSeq, Self::Error> {
+        unsupported("Sequence")
+    }
+
+    fn serialize_tuple(self, _: usize) -> ::std::result::Result<Self::SerializeTuple, Self::Error> {
+        unsupported("Tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> ::std::result::Result<Self::SerializeTupleStruct, Self::Error> {
+        unsupported("Tuple")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> ::std::result::Result<Self::SerializeTupleVariant, Self::Error> {
+        unsupported("Enum")
+    }
+
+    fn serialize_map(
+        self,
+        _: Option<usize>,
+    ) -> ::std::result::Result<Self::SerializeMap, Self::Error> {
+        unsupported("Map")
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> ::std::result::Result<Self::SerializeStruct, Self::Error> {
+        unsupported("Struct")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> ::std::result::Result<Self::SerializeStructVariant, Self::Error> {
+        unsupported("Enum")
+    }
+}
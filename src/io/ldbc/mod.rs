@@ -24,12 +24,30 @@ pub mod scheme;
 
 pub use io::ldbc::scheme::Scheme;
 
+use csv::StringRecord;
+use error::{GraphError, Result};
 use generic::{GraphType, IdType};
 use graph_impl::TypedGraphMap;
 use std::path::Path;
 
 pub fn read_ldbc_from_path<Id: IdType, Ty: GraphType, P: AsRef<Path>>(
     path: P,
-) -> TypedGraphMap<Id, String, String, Ty> {
-    self::scheme::Scheme::init().from_path(path).unwrap()
+) -> Result<TypedGraphMap<Id, String, String, Ty>> {
+    self::scheme::Scheme::init().from_path(path)
+}
+
+/// Read the CSV files in `path` with the schema described in the file `config`,
+/// see `Scheme::from_config`.
+pub fn read_ldbc_with_config<Id: IdType, Ty: GraphType, P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    config: Q,
+) -> Result<TypedGraphMap<Id, String, String, Ty>> {
+    self::scheme::Scheme::from_config(config)?.from_path(path)
+}
+
+pub(crate) fn get_field(record: &StringRecord, index: usize) -> Result<&str> {
+    record.get(index).ok_or_else(|| {
+        let line = record.position().map_or(0, |position| position.line());
+        GraphError::InvalidData(format!("Line {}: no column {}", line, index))
+    })
 }
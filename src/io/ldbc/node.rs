@@ -22,26 +22,45 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use csv::StringRecord;
-use regex::Regex;
+use regex::{self, Regex};
 
+use error::Result;
 use generic::{GraphTrait, GraphType, IdType, MutGraphTrait};
 use graph_impl::graph_map::TypedGraphMap;
+use io::ldbc::get_field;
 
 #[derive(Debug)]
 pub struct Node {
     name: String,
     id_index: usize,
-    label_index: usize,
+    // The node is labeled by `name` if `None`.
+    label_index: Option<usize>,
     file_name_start: Regex,
 }
 
 impl Node {
     pub fn new(name: &str, id_index: usize, label_index: usize, file_name_start: &str) -> Self {
+        Node::with_label_index(name, id_index, Some(label_index), file_name_start)
+    }
+
+    /// A node type without label column, whose nodes are labeled by `name`.
+    pub fn with_type_label(name: &str, id_index: usize, file_name_start: &str) -> Self {
+        Node::with_label_index(name, id_index, None, file_name_start)
+    }
+
+    fn with_label_index(
+        name: &str,
+        id_index: usize,
+        label_index: Option<usize>,
+        file_name_start: &str,
+    ) -> Self {
+        let file_name_start = regex::escape(file_name_start);
+
         Node {
             name: name.to_owned(),
             id_index,
             label_index,
-            file_name_start: Regex::new(&format!(r"^{}[_\d]*.csv", file_name_start)[..]).unwrap(),
+            file_name_start: Regex::new(&format!(r"^{}[_\d]*\.csv$", file_name_start)[..]).unwrap(),
         }
     }
 
@@ -56,8 +75,12 @@ impl Node {
         record: StringRecord,
         g: &mut TypedGraphMap<Id, String, String, Ty>,
         node_id_map: &mut HashMap<String, Id>,
-    ) {
-        let str_id = self.name.clone() + &record[self.id_index];
+    ) -> Result<()> {
+        let str_id = self.name.clone() + get_field(&record, self.id_index)?;
+        let label = match self.label_index {
+            Some(label_index) => get_field(&record, label_index)?.to_owned(),
+            None => self.name.clone(),
+        };
 
         let id = *node_id_map.entry(str_id).or_insert_with(|| {
             if let Some(i) = g.max_seen_id() {
@@ -67,6 +90,8 @@ impl Node {
             }
         });
 
-        g.add_node(id, Some(record[self.label_index].to_owned()));
+        g.add_node(id, Some(label));
+
+        Ok(())
     }
 }
@@ -22,10 +22,12 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use csv::StringRecord;
-use regex::Regex;
+use regex::{self, Regex};
 
+use error::Result;
 use generic::{GraphTrait, GraphType, IdType, MutGraphTrait};
 use graph_impl::graph_map::TypedGraphMap;
+use io::ldbc::get_field;
 
 #[derive(Debug)]
 pub struct Relation {
@@ -52,8 +54,9 @@ impl Relation {
             edge_label: edge_label.to_owned(),
             start_index,
             target_index,
-            file_name_start: Regex::new(&format!("{}{}", file_name_start, r"[_\d]*.csv")[..])
-                .unwrap(),
+            file_name_start: Regex::new(
+                &format!(r"^{}[_\d]*\.csv$", regex::escape(file_name_start))[..],
+            ).unwrap(),
         }
     }
 
@@ -68,9 +71,9 @@ impl Relation {
         record: StringRecord,
         g: &mut TypedGraphMap<Id, String, String, Ty>,
         node_id_map: &mut HashMap<String, Id>,
-    ) {
-        let start_str_id = self.start_label.clone() + &record[self.start_index];
-        let target_str_id = self.target_label.clone() + &record[self.target_index];
+    ) -> Result<()> {
+        let start_str_id = self.start_label.clone() + get_field(&record, self.start_index)?;
+        let target_str_id = self.target_label.clone() + get_field(&record, self.target_index)?;
 
         let start_id = *node_id_map.entry(start_str_id).or_insert_with(|| {
             let i = if let Some(i) = g.max_seen_id() {
@@ -95,5 +98,7 @@ impl Relation {
         });
 
         g.add_edge(start_id, target_id, Some(self.edge_label.clone()));
+
+        Ok(())
     }
 }
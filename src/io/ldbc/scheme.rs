@@ -18,176 +18,138 @@
  * specific language governing permissions and limitations
  * under the License.
  */
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::Result;
 use std::path::Path;
 
+use error::{GraphError, Result};
 use generic::{GraphType, IdType};
 use graph_impl::graph_map::TypedGraphMap;
 use io::ldbc::node::Node;
 use io::ldbc::relation::Relation;
+use io::text::{invalid_data, parse_number};
 
 use csv::ReaderBuilder;
 
+/// The schema of the LDBC Social Network Benchmark read by `Scheme::init`.
+pub const LDBC_SNB_CONFIG: &str = include_str!("ldbc_snb.conf");
+
 #[derive(Debug)]
 pub struct Scheme {
     relations: Vec<Relation>,
     nodes: Vec<Node>,
     delimiter: u8,
+    has_headers: bool,
 }
 
 impl Scheme {
+    /// The schema of the LDBC Social Network Benchmark, i.e. `LDBC_SNB_CONFIG`.
     pub fn init() -> Self {
-        let n1 = Node::new("organisation", 0, 1, "organisation_");
-        let n2 = Node::new("place", 0, 3, "place_");
-
-        let r1 = Relation::new(
-            "comment",
-            "person",
-            "hasCreator",
-            0,
-            1,
-            "comment_hasCreator_person_",
-        );
-        let r2 = Relation::new("comment", "tag", "hasTag", 0, 1, "comment_hasTag_tag_");
-        let r3 = Relation::new(
-            "comment",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "comment_isLocatedIn_place_",
-        );
-        let r4 = Relation::new(
-            "comment",
-            "comment",
-            "replyOf",
-            0,
-            1,
-            "comment_replyOf_comment_",
-        );
-        let r5 = Relation::new("comment", "post", "replyOf", 0, 1, "comment_replyOf_post_");
-        let r6 = Relation::new(
-            "forum",
-            "post",
-            "containerOf",
-            0,
-            1,
-            "forum_containerOf_post_",
-        );
-        let r7 = Relation::new(
-            "forum",
-            "post",
-            "containerOf",
-            0,
-            1,
-            "forum_containerOf_post_",
-        );
-        let r8 = Relation::new(
-            "forum",
-            "person",
-            "hasMember",
-            0,
-            1,
-            "forum_hasMember_person_",
-        );
-        let r9 = Relation::new(
-            "forum",
-            "person",
-            "hasModerator",
-            0,
-            1,
-            "forum_hasModerator_person_",
-        );
-        let r10 = Relation::new(
-            "forum",
-            "person",
-            "hasModerator",
-            0,
-            1,
-            "forum_hasModerator_person_",
-        );
-        let r11 = Relation::new("forum", "tag", "hasTag", 0, 1, "forum_hasTag_tag_");
-        let r12 = Relation::new(
-            "organisation",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "organisation_isLocatedIn_place_",
-        );
-        let r13 = Relation::new(
-            "person",
-            "tag",
-            "hasInterest",
-            0,
-            1,
-            "person_hasInterest_tag_",
-        );
-        let r14 = Relation::new(
-            "person",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "person_isLocatedIn_place_",
-        );
-        let r15 = Relation::new("person", "person", "knows", 0, 1, "person_knows_person_");
-        let r16 = Relation::new("person", "comment", "likes", 0, 1, "person_likes_comment_");
-        let r17 = Relation::new("person", "post", "likes", 0, 1, "person_likes_post_");
-        let r18 = Relation::new(
-            "person",
-            "organisation",
-            "studyAt",
-            0,
-            1,
-            "person_studyAt_organisation_",
-        );
-        let r19 = Relation::new(
-            "person",
-            "organisation",
-            "workAt",
-            0,
-            1,
-            "person_workAt_organisation_",
-        );
-        let r20 = Relation::new("place", "place", "isPartOf", 0, 1, "place_isPartOf_place_");
-        let r21 = Relation::new(
-            "post",
-            "person",
-            "hasCreator",
-            0,
-            1,
-            "post_hasCreator_person_",
-        );
-        let r22 = Relation::new("post", "tag", "hasTag", 0, 1, "post_hasTag_tag_");
-        let r23 = Relation::new(
-            "post",
-            "place",
-            "isLocatedIn",
-            0,
-            1,
-            "post_isLocatedIn_place_",
-        );
-        let r24 = Relation::new("tag", "tagclass", "hasType", 0, 1, "tag_hasType_tagclass_");
-        let r25 = Relation::new(
-            "tagclass",
-            "tagclass",
-            "isSubclassOf",
-            0,
-            1,
-            "tagclass_isSubclassOf_tagclass_",
-        );
-
-        Scheme {
-            relations: vec![
-                r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, r13, r14, r15, r16, r17, r18,
-                r19, r20, r21, r22, r23, r24, r25,
-            ],
-            nodes: vec![n1, n2],
+        Scheme::from_config_str(LDBC_SNB_CONFIG).unwrap()
+    }
+
+    /// Read the schema from a config file, see `from_config_str`.
+    pub fn from_config<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Scheme::from_config_str(&fs::read_to_string(path)?)
+    }
+
+    /// Parse a schema with one declaration of whitespace-separated fields per line, where empty
+    /// lines and lines starting with `#` are ignored:
+    ///
+    /// * `delimiter <delimiter>` of the CSV files, `|` by default, with `\t` for a tab.
+    /// * `headers <true|false>`, i.e. whether the CSV files start with a header, `true` by default.
+    /// * `node <type> <id column> <label column> <file prefix>`, where the label column may be
+    ///   `-` to label the nodes by their type, see `Node`.
+    /// * `relation <start type> <target type> <edge label> <start column> <target column>
+    ///   <file prefix>`, see `Relation`.
+    ///
+    /// The columns are indexed from 0. See `LDBC_SNB_CONFIG` for an example.
+    pub fn from_config_str(config: &str) -> Result<Self> {
+        let mut scheme = Scheme {
+            relations: Vec::new(),
+            nodes: Vec::new(),
             delimiter: b'|',
+            has_headers: true,
+        };
+        let mut file_name_starts = HashSet::new();
+
+        for (i, line) in config.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            match (fields[0], fields.len()) {
+                ("delimiter", 2) => {
+                    scheme.delimiter = match fields[1] {
+                        "\\t" => b'\t',
+                        delimiter if delimiter.len() == 1 => delimiter.as_bytes()[0],
+                        delimiter => {
+                            return Err(invalid_data(
+                                line_no,
+                                &format!("Invalid delimiter '{}'", delimiter),
+                            ))
+                        }
+                    }
+                }
+                ("headers", 2) => {
+                    scheme.has_headers = match fields[1] {
+                        "true" => true,
+                        "false" => false,
+                        headers => {
+                            return Err(invalid_data(
+                                line_no,
+                                &format!("Invalid headers '{}'", headers),
+                            ))
+                        }
+                    }
+                }
+                ("node", 5) => {
+                    let id_index = parse_number(fields[2], line_no)?;
+                    let node = match fields[3] {
+                        "-" => Node::with_type_label(fields[1], id_index, fields[4]),
+                        label_index => Node::new(
+                            fields[1],
+                            id_index,
+                            parse_number(label_index, line_no)?,
+                            fields[4],
+                        ),
+                    };
+
+                    scheme.nodes.push(node);
+                }
+                ("relation", 7) => scheme.relations.push(Relation::new(
+                    fields[1],
+                    fields[2],
+                    fields[3],
+                    parse_number(fields[4], line_no)?,
+                    parse_number(fields[5], line_no)?,
+                    fields[6],
+                )),
+                _ => {
+                    return Err(invalid_data(
+                        line_no,
+                        &format!("Invalid declaration '{}'", line),
+                    ))
+                }
+            }
+
+            let file_name_start = match fields[0] {
+                "node" => fields[4],
+                "relation" => fields[6],
+                _ => continue,
+            };
+            if !file_name_starts.insert(file_name_start) {
+                return Err(invalid_data(
+                    line_no,
+                    &format!("Duplicate file prefix '{}'", file_name_start),
+                ));
+            }
         }
+
+        Ok(scheme)
     }
 
     pub fn from_path<Id: IdType, Ty: GraphType, P: AsRef<Path>>(
@@ -195,7 +157,10 @@ impl Scheme {
         path: P,
     ) -> Result<TypedGraphMap<Id, String, String, Ty>> {
         if !path.as_ref().is_dir() {
-            panic!("path must be a dir")
+            return Err(GraphError::InvalidInput(format!(
+                "'{}' is not a directory",
+                path.as_ref().display()
+            )));
         }
 
         let mut files_in_dir = Vec::new();
@@ -219,12 +184,13 @@ impl Scheme {
 
                     let mut rdr = ReaderBuilder::new()
                         .delimiter(self.delimiter)
+                        .has_headers(self.has_headers)
                         .from_path(path.clone())?;
 
                     for result in rdr.records() {
                         let record = result?;
 
-                        node.add_node::<Id, Ty>(record, &mut g, &mut node_id_map);
+                        node.add_node::<Id, Ty>(record, &mut g, &mut node_id_map)?;
                     }
                 }
             }
@@ -238,12 +204,13 @@ impl Scheme {
 
                     let mut rdr = ReaderBuilder::new()
                         .delimiter(self.delimiter)
+                        .has_headers(self.has_headers)
                         .from_path(path.clone())?;
 
                     for result in rdr.records() {
                         let record = result?;
 
-                        relation.add_edge::<Id, Ty>(record, &mut g, &mut node_id_map);
+                        relation.add_edge::<Id, Ty>(record, &mut g, &mut node_id_map)?;
                     }
                 }
             }
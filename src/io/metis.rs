@@ -0,0 +1,189 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! The graph format of [METIS](http://glaros.dtc.umn.edu/gkhome/metis/metis/overview),
+//! also used by the DIMACS challenges and KaHIP.
+//!
+//! The header `n m [fmt [ncon]]` is followed by one line per node, listing the 1-based ids
+//! of its neighbors. Lines starting with `%` are comments. Node sizes, node weights and
+//! edge weights, as announced by `fmt`, are skipped when reading and never written.
+//! Labels are neither read nor written.
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use error::{GraphError, Result};
+use generic::{GeneralGraph, GraphTrait, IdType, MutGraphTrait};
+use io::text::{invalid_data, num_of_ids, numbered_lines, parse_id, parse_number};
+
+/// Read a METIS graph into `g`, adding the nodes `0..n`. As a METIS graph is undirected,
+/// a directed `g` gets an edge in each direction.
+pub fn read_metis<Id, NL, EL, L, G, P>(g: &mut G, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    P: AsRef<Path>,
+{
+    info!(
+        "Reading METIS graph from {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    // Comments may appear anywhere, but an empty line is a node without neighbors.
+    let mut lines = numbered_lines(path)?.filter(|(_, line)| match line {
+        Ok(line) => !line.trim_start().starts_with('%'),
+        Err(_) => true,
+    });
+
+    let (num_of_nodes, num_of_edges, fmt, ncon) = match lines.next() {
+        Some((line_no, line)) => {
+            let line = line?;
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            if tokens.len() < 2 || tokens.len() > 4 {
+                return Err(invalid_data(
+                    line_no,
+                    "expected the header `n m [fmt [ncon]]`",
+                ));
+            }
+
+            let num_of_nodes: usize = parse_number(tokens[0], line_no)?;
+            let num_of_edges: usize = parse_number(tokens[1], line_no)?;
+            let fmt: usize = match tokens.get(2) {
+                Some(fmt) => parse_number(fmt, line_no)?,
+                None => 0,
+            };
+            let ncon: usize = match tokens.get(3) {
+                Some(ncon) => parse_number(ncon, line_no)?,
+                None => 1,
+            };
+
+            if num_of_nodes > Id::max_usize() {
+                return Err(invalid_data(line_no, "too many nodes for `Id`"));
+            }
+
+            (num_of_nodes, num_of_edges, fmt, ncon)
+        }
+        None => return Err(invalid_data(1, "expected the header `n m [fmt [ncon]]`")),
+    };
+
+    let has_node_sizes = fmt / 100 % 10 == 1;
+    let has_node_weights = fmt / 10 % 10 == 1;
+    let has_edge_weights = fmt % 10 == 1;
+    let num_of_skipped =
+        if has_node_sizes { 1 } else { 0 } + if has_node_weights { ncon } else { 0 };
+    let step = if has_edge_weights { 2 } else { 1 };
+
+    for id in 0..num_of_nodes {
+        g.add_node(Id::new(id), None);
+    }
+
+    let mut num_of_entries = 0;
+    for id in 0..num_of_nodes {
+        let (line_no, line) = match lines.next() {
+            Some((line_no, line)) => (line_no, line?),
+            None => {
+                return Err(GraphError::InvalidData(format!(
+                    "Expected {} nodes, found {}",
+                    num_of_nodes, id
+                )))
+            }
+        };
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() < num_of_skipped || (tokens.len() - num_of_skipped) % step != 0 {
+            return Err(invalid_data(line_no, "unexpected number of fields"));
+        }
+
+        let start = Id::new(id);
+        for token in tokens[num_of_skipped..].iter().step_by(step) {
+            let target: Id = parse_id(token, true, line_no)?;
+            if target.id() >= num_of_nodes {
+                return Err(invalid_data(line_no, &format!("unknown node {}", token)));
+            }
+
+            if !g.has_edge(start, target) {
+                g.add_edge(start, target, None);
+            }
+            num_of_entries += 1;
+        }
+    }
+
+    if num_of_entries != 2 * num_of_edges {
+        warn!(
+            "Expected {} edges, found {} neighbors, i.e. twice the number of edges",
+            num_of_edges, num_of_entries
+        );
+    }
+
+    Ok(())
+}
+
+/// Write the undirected graph `g` in the METIS format, with a line for each id up to the
+/// largest one. Self-loops are not allowed in METIS and thus left out.
+///
+/// Fails with `GraphError::InvalidInput` if `g` is directed.
+pub fn write_metis<Id, NL, EL, L, P>(g: &GeneralGraph<Id, NL, EL, L>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    P: AsRef<Path>,
+{
+    if g.is_directed() {
+        return Err(GraphError::InvalidInput(
+            "METIS only supports undirected graphs".to_owned(),
+        ));
+    }
+
+    info!("Writing METIS graph to {}", path.as_ref().to_str().unwrap());
+
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    let num_of_nodes = num_of_ids(g.as_graph());
+    let num_of_edges = g.edge_indices().filter(|&(s, t)| s != t).count();
+    writeln!(writer, "{} {}", num_of_nodes, num_of_edges)?;
+
+    for id in (0..num_of_nodes).map(Id::new) {
+        if g.has_node(id) {
+            let mut neighbors = g.neighbors(id).into_owned();
+            neighbors.sort();
+            neighbors.dedup();
+
+            let line = neighbors
+                .into_iter()
+                .filter(|&n| n != id)
+                .map(|n| (n.id() + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(writer, "{}", line)?;
+        } else {
+            writeln!(writer)?;
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
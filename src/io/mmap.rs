@@ -32,8 +32,11 @@ use std::ops;
 use std::path::Path;
 use std::slice;
 
+use error::{GraphError, Result as GraphResult};
+
 pub struct TypedMemoryMap<T: Copy> {
-    pub map: memmap::Mmap,
+    /// `None` if the file is empty, which can not be mapped.
+    pub map: Option<memmap::Mmap>,
     pub len: usize,
     type_len: usize,
     // in bytes (needed because map extends to full block)
@@ -41,35 +44,62 @@ pub struct TypedMemoryMap<T: Copy> {
 }
 
 impl<T: Copy> TypedMemoryMap<T> {
-    pub fn new<P: AsRef<Path>>(filename: P) -> Self {
-        let file = File::open(filename).expect("error opening file");
-        let size = file.metadata().expect("error reading metadata").len() as usize;
+    /// Map the file as a slice of `T`, which fails if the file can not be mapped
+    /// or its size is not a multiple of `size_of::<T>()`.
+    pub fn new<P: AsRef<Path>>(filename: P) -> GraphResult<Self> {
+        let file = File::open(filename.as_ref())?;
+        let size = file.metadata()?.len() as usize;
         let type_len = mem::size_of::<T>();
-        TypedMemoryMap {
-            map: unsafe { memmap::Mmap::map(&file).unwrap() },
+
+        if type_len == 0 || size % type_len != 0 {
+            return Err(GraphError::InvalidData(format!(
+                "the size of '{}' ({} bytes) is not a multiple of {} bytes",
+                filename.as_ref().display(),
+                size,
+                type_len
+            )));
+        }
+
+        let map = if size == 0 {
+            None
+        } else {
+            Some(unsafe { memmap::Mmap::map(&file)? })
+        };
+
+        Ok(TypedMemoryMap {
+            map,
             len: size / type_len,
             type_len,
             phn: PhantomData,
-        }
+        })
     }
 
     pub fn with_mmap(map: memmap::Mmap) -> Self {
         let size = map.len();
         let type_len = mem::size_of::<T>();
         Self {
-            map,
+            map: Some(map),
             len: size / type_len,
             type_len,
             phn: PhantomData,
         }
     }
+
+    /// The start of the mapped memory, or a dangling but aligned pointer if the file is empty.
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        match self.map {
+            Some(ref map) => map.as_ptr(),
+            None => mem::align_of::<T>() as *const u8,
+        }
+    }
 }
 
 impl<T: Copy> ops::Index<ops::RangeFull> for TypedMemoryMap<T> {
     type Output = [T];
     #[inline]
     fn index(&self, _index: ops::RangeFull) -> &[T] {
-        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const T, self.len) }
     }
 }
 
@@ -80,7 +110,7 @@ impl<T: Copy> ops::Index<ops::RangeFrom<usize>> for TypedMemoryMap<T> {
         let index = _index.start;
         unsafe {
             slice::from_raw_parts(
-                self.map.as_ptr().add(index * self.type_len) as *const T,
+                self.as_ptr().add(index * self.type_len) as *const T,
                 self.len - index,
             )
         }
@@ -91,7 +121,7 @@ impl<T: Copy> ops::Index<ops::RangeTo<usize>> for TypedMemoryMap<T> {
     type Output = [T];
     #[inline]
     fn index(&self, _index: ops::RangeTo<usize>) -> &[T] {
-        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, _index.end) }
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const T, _index.end) }
     }
 }
 
@@ -19,12 +19,27 @@
  * under the License.
  */
 pub mod csv;
+pub mod dot;
+pub mod gexf;
+pub mod graphml;
+mod label;
+pub mod metis;
 pub mod mmap;
+pub mod mtx;
 pub mod serde;
+pub mod snap;
+mod text;
+mod xml;
 
-pub use io::csv::{read_from_csv, write_to_csv};
+pub use io::csv::{read_from_csv, read_weighted_from_csv, write_to_csv, write_weighted_to_csv};
+pub use io::dot::write_dot;
+pub use io::gexf::write_gexf;
+pub use io::graphml::{read_graphml, write_graphml};
+pub use io::metis::{read_metis, write_metis};
+pub use io::mtx::{read_mtx, write_mtx};
+pub use io::snap::{read_snap, write_snap};
 
 #[cfg(feature = "ldbc")]
 pub mod ldbc;
 #[cfg(feature = "ldbc")]
-pub use io::ldbc::read_ldbc_from_path;
+pub use io::ldbc::{read_ldbc_from_path, read_ldbc_with_config};
@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) coordinate files (`.mtx`),
+//! as distributed by the [SuiteSparse Matrix Collection](https://sparse.tamu.edu/).
+//!
+//! An entry `i j` of the matrix is the edge from node `i - 1` to node `j - 1`. The values of
+//! the entries are ignored, and graphs are written as `pattern` matrices without values.
+//! Labels are neither read nor written.
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use error::Result;
+use generic::{GeneralGraph, GraphTrait, IdType, MutGraphTrait};
+use io::text::{invalid_data, num_of_ids, numbered_lines, parse_id, parse_number};
+
+/// Read a Matrix Market coordinate file into `g`, adding a node for each row and column.
+///
+/// The entries of a `symmetric`, `skew-symmetric` or `hermitian` matrix only hold one half
+/// of the matrix, so their reverses are added too. An edge that is already in `g`
+/// is skipped.
+pub fn read_mtx<Id, NL, EL, L, G, P>(g: &mut G, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    P: AsRef<Path>,
+{
+    info!(
+        "Reading Matrix Market file from {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    let mut lines = numbered_lines(path)?;
+
+    let is_symmetric = match lines.next() {
+        Some((line_no, line)) => {
+            let line = line?.to_lowercase();
+            let banner = line.split_whitespace().collect::<Vec<_>>();
+
+            if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+                return Err(invalid_data(line_no, "expected a Matrix Market banner"));
+            }
+            if banner[2] != "coordinate" {
+                return Err(invalid_data(
+                    line_no,
+                    &format!("{} matrices are not supported", banner[2]),
+                ));
+            }
+
+            match banner[4] {
+                "general" => false,
+                "symmetric" | "skew-symmetric" | "hermitian" => true,
+                other => {
+                    return Err(invalid_data(
+                        line_no,
+                        &format!("unknown symmetry {}", other),
+                    ))
+                }
+            }
+        }
+        None => return Err(invalid_data(1, "expected a Matrix Market banner")),
+    };
+
+    let mut num_of_entries: Option<usize> = None;
+    let mut entries_read: usize = 0;
+    let mut last_line_no = 1;
+
+    for (line_no, line) in lines {
+        let line = line?;
+        let line = line.trim();
+        last_line_no = line_no;
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+        match num_of_entries {
+            // The first line after the comments is the size of the matrix.
+            None => {
+                if tokens.len() != 3 {
+                    return Err(invalid_data(line_no, "expected the size of the matrix"));
+                }
+
+                let num_of_rows: usize = parse_number(tokens[0], line_no)?;
+                let num_of_cols: usize = parse_number(tokens[1], line_no)?;
+                num_of_entries = Some(parse_number(tokens[2], line_no)?);
+
+                let num_of_nodes = num_of_rows.max(num_of_cols);
+                if num_of_nodes > Id::max_usize() {
+                    return Err(invalid_data(line_no, "too many nodes for `Id`"));
+                }
+                for id in 0..num_of_nodes {
+                    g.add_node(Id::new(id), None);
+                }
+            }
+            Some(_) => {
+                if tokens.len() < 2 {
+                    return Err(invalid_data(line_no, "expected an entry"));
+                }
+
+                let start = parse_id(tokens[0], true, line_no)?;
+                let target = parse_id(tokens[1], true, line_no)?;
+
+                if !g.has_edge(start, target) {
+                    g.add_edge(start, target, None);
+                }
+                if is_symmetric && !g.has_edge(target, start) {
+                    g.add_edge(target, start, None);
+                }
+
+                entries_read += 1;
+            }
+        }
+    }
+
+    match num_of_entries {
+        Some(n) if n == entries_read => Ok(()),
+        Some(n) => Err(invalid_data(
+            last_line_no,
+            &format!("expected {} entries, found {}", n, entries_read),
+        )),
+        None => Err(invalid_data(
+            last_line_no,
+            "expected the size of the matrix",
+        )),
+    }
+}
+
+/// Write `g` as a `pattern` coordinate matrix with a row and a column for each id up to
+/// the largest one. An undirected graph is written as a `symmetric` matrix, whose entries
+/// are in the lower triangle.
+pub fn write_mtx<Id, NL, EL, L, P>(g: &GeneralGraph<Id, NL, EL, L>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    P: AsRef<Path>,
+{
+    info!(
+        "Writing Matrix Market file to {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    let is_directed = g.is_directed();
+    let edges = g.edge_indices().collect::<Vec<_>>();
+    let num_of_nodes = num_of_ids(g.as_graph());
+
+    if is_directed {
+        writeln!(writer, "%%MatrixMarket matrix coordinate pattern general")?;
+    } else {
+        writeln!(writer, "%%MatrixMarket matrix coordinate pattern symmetric")?;
+    }
+    writeln!(writer, "{} {} {}", num_of_nodes, num_of_nodes, edges.len())?;
+
+    for (start, target) in edges {
+        let (row, col) = if is_directed || start >= target {
+            (start, target)
+        } else {
+            (target, start)
+        };
+        writeln!(writer, "{} {}", row.id() + 1, col.id() + 1)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
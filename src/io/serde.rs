@@ -24,9 +24,10 @@ use std::path::Path;
 
 use serde::{de, ser};
 
-pub use bincode::Result;
 use bincode::{deserialize_from, serialize_into};
 
+pub use error::Result;
+
 pub struct Serializer;
 pub struct Deserializer;
 
@@ -59,7 +60,9 @@ impl Serializer {
     {
         let mut writer = BufWriter::new(File::create(path)?);
 
-        serialize_into(&mut writer, &obj)
+        serialize_into(&mut writer, &obj)?;
+
+        Ok(())
     }
 }
 
@@ -72,6 +75,6 @@ impl Deserializer {
     {
         let mut reader = BufReader::new(File::open(path)?);
 
-        deserialize_from(&mut reader)
+        Ok(deserialize_from(&mut reader)?)
     }
 }
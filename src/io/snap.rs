@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! SNAP-style edge lists, as distributed by the
+//! [Stanford Large Network Dataset Collection](https://snap.stanford.edu/data/).
+//!
+//! Each line holds the 0-based ids of the two ends of an edge, separated by whitespace.
+//! Lines starting with `#` are comments, and any column after the first two is ignored.
+//! Labels are neither read nor written.
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use error::Result;
+use generic::{GeneralGraph, GraphTrait, IdType, MutGraphTrait};
+use io::text::{invalid_data, numbered_lines, parse_id};
+
+/// Read the edges of a SNAP edge list into `g`. An edge that is already in `g`,
+/// e.g. the reverse of an edge of an undirected graph, is skipped.
+pub fn read_snap<Id, NL, EL, L, G, P>(g: &mut G, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    G: MutGraphTrait<Id, NL, EL, L> + GraphTrait<Id, L>,
+    P: AsRef<Path>,
+{
+    info!(
+        "Reading SNAP edge list from {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    for (line_no, line) in numbered_lines(path)? {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let (start, target) = match (tokens.next(), tokens.next()) {
+            (Some(start), Some(target)) => (
+                parse_id(start, false, line_no)?,
+                parse_id(target, false, line_no)?,
+            ),
+            _ => return Err(invalid_data(line_no, "expected two node ids")),
+        };
+
+        if !g.has_edge(start, target) {
+            g.add_edge(start, target, None);
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the edges of `g` as a SNAP edge list, each edge of an undirected graph once.
+pub fn write_snap<Id, NL, EL, L, P>(g: &GeneralGraph<Id, NL, EL, L>, path: P) -> Result<()>
+where
+    Id: IdType,
+    NL: Hash + Eq,
+    EL: Hash + Eq,
+    L: IdType,
+    P: AsRef<Path>,
+{
+    info!(
+        "Writing SNAP edge list to {}",
+        path.as_ref().to_str().unwrap()
+    );
+
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    if g.is_directed() {
+        writeln!(writer, "# Directed graph")?;
+    } else {
+        writeln!(writer, "# Undirected graph")?;
+    }
+    writeln!(
+        writer,
+        "# Nodes: {} Edges: {}",
+        g.node_count(),
+        g.edge_count()
+    )?;
+    writeln!(writer, "# FromNodeId\tToNodeId")?;
+
+    for (start, target) in g.edge_indices() {
+        writeln!(writer, "{}\t{}", start.id(), target.id())?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Helpers shared by the line-based formats, i.e. Matrix Market, METIS and SNAP,
+//! as well as the mapping of string node ids used by CSV and GraphML.
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::str::FromStr;
+
+use error::{GraphError, Result};
+use generic::{GraphTrait, IdType, Iter, MutMapTrait};
+use map::SetMap;
+
+/// The lines of a file, numbered from 1.
+pub(crate) fn numbered_lines<'a, P: AsRef<Path>>(
+    path: P,
+) -> Result<Iter<'a, (usize, io::Result<String>)>> {
+    let lines = BufReader::new(File::open(path)?).lines();
+
+    Ok(Iter::new(Box::new((1..).zip(lines))))
+}
+
+pub(crate) fn invalid_data(line_no: usize, msg: &str) -> GraphError {
+    GraphError::InvalidData(format!("Line {}: {}", line_no, msg))
+}
+
+pub(crate) fn parse_number<T: FromStr>(token: &str, line_no: usize) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| invalid_data(line_no, &format!("{:?} is not a valid number", token)))
+}
+
+/// Parse a node id, which is 1-based in Matrix Market and METIS but 0-based in SNAP.
+pub(crate) fn parse_id<Id: IdType>(token: &str, is_one_based: bool, line_no: usize) -> Result<Id> {
+    let id: usize = parse_number(token, line_no)?;
+    let id = if is_one_based {
+        match id.checked_sub(1) {
+            Some(id) => id,
+            None => return Err(invalid_data(line_no, "node ids start from 1")),
+        }
+    } else {
+        id
+    };
+
+    if !fits_in_id::<Id>(id) {
+        return Err(invalid_data(
+            line_no,
+            &format!("node id {} does not fit in `Id`", token),
+        ));
+    }
+
+    Ok(Id::new(id))
+}
+
+/// Map a string node id to `Id` by its order of first appearance in **id_map**.
+pub(crate) fn intern_id<Id: IdType>(id_map: &mut SetMap<String>, key: String) -> Result<Id> {
+    let index = id_map.add_item(key);
+
+    if !fits_in_id::<Id>(index) {
+        // Only a new key can be out of range.
+        id_map.pop_item();
+
+        return Err(GraphError::InvalidData(format!(
+            "More than {} distinct node ids.",
+            Id::max_usize()
+        )));
+    }
+
+    Ok(Id::new(index))
+}
+
+#[inline]
+fn fits_in_id<Id: IdType>(id: usize) -> bool {
+    // `Id::max_value()` is reserved as the invalid id.
+    id < Id::max_usize()
+}
+
+/// The number of nodes of a format without explicit node ids, i.e. the largest id plus one.
+pub(crate) fn num_of_ids<Id: IdType, L: IdType>(g: &GraphTrait<Id, L>) -> usize {
+    g.max_seen_id().map_or(0, |id| id.id() + 1)
+}
@@ -0,0 +1,228 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Helpers shared by the XML formats, i.e. GraphML and GEXF: escaping and a minimal
+//! pull parser, which is just enough for the graph formats.
+use error::{GraphError, Result};
+
+pub(crate) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+pub(crate) fn unescape(s: &str) -> Result<String> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let end = match rest[start..].find(';') {
+            Some(end) => start + end,
+            None => return Err(invalid_xml(&format!("unterminated entity in {:?}", s))),
+        };
+
+        let entity = &rest[start + 1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(::std::char::from_u32),
+            _ if entity.starts_with('#') => {
+                entity[1..].parse().ok().and_then(::std::char::from_u32)
+            }
+            _ => None,
+        };
+        match c {
+            Some(c) => unescaped.push(c),
+            None => return Err(invalid_xml(&format!("unknown entity &{};", entity))),
+        }
+
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+
+    Ok(unescaped)
+}
+
+fn invalid_xml(msg: &str) -> GraphError {
+    GraphError::InvalidData(format!("Invalid XML: {}", msg))
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum XmlEvent<'a> {
+    /// An opening tag, which is also closed if `is_empty`, e.g. `<node id="n0"/>`
+    Start {
+        name: &'a str,
+        attrs: Vec<(&'a str, String)>,
+        is_empty: bool,
+    },
+    End(&'a str),
+    Text(String),
+}
+
+pub(crate) fn get_attr<'a>(attrs: &'a [(&str, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|&&(attr, _)| attr == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// A pull parser over the tags and texts of a document. Processing instructions, comments
+/// and doctypes are skipped, and the nesting of the tags is not checked.
+pub(crate) struct XmlReader<'a> {
+    xml: &'a str,
+    pos: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    pub(crate) fn new(xml: &'a str) -> Self {
+        XmlReader { xml, pos: 0 }
+    }
+
+    pub(crate) fn read_event(&mut self) -> Result<Option<XmlEvent<'a>>> {
+        loop {
+            let rest = &self.xml[self.pos..];
+            if rest.is_empty() {
+                return Ok(None);
+            }
+
+            if !rest.starts_with('<') {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.pos += end;
+
+                return Ok(Some(XmlEvent::Text(unescape(&rest[..end])?)));
+            }
+
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                let end = self.find("]]>")?;
+                let text = rest[9..end].to_owned();
+                self.pos += end + 3;
+
+                return Ok(Some(XmlEvent::Text(text)));
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else if rest.starts_with("</") {
+                let end = self.find(">")?;
+                self.pos += end + 1;
+
+                return Ok(Some(XmlEvent::End(rest[2..end].trim())));
+            } else {
+                let end = self.find_tag_end()?;
+                self.pos += end + 1;
+
+                return parse_start_tag(&rest[1..end]).map(Some);
+            }
+        }
+    }
+
+    fn find(&self, pattern: &str) -> Result<usize> {
+        self.xml[self.pos..]
+            .find(pattern)
+            .ok_or_else(|| invalid_xml(&format!("missing {:?}", pattern)))
+    }
+
+    fn skip_past(&mut self, pattern: &str) -> Result<()> {
+        self.pos += self.find(pattern)? + pattern.len();
+
+        Ok(())
+    }
+
+    /// The position of the `>` closing the current tag, which may appear in quoted values.
+    fn find_tag_end(&self) -> Result<usize> {
+        let mut quote = None;
+        for (i, c) in self.xml[self.pos..].char_indices() {
+            match (quote, c) {
+                (None, '>') => return Ok(i),
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (Some(q), c) if q == c => quote = None,
+                _ => {}
+            }
+        }
+
+        Err(invalid_xml("unterminated tag"))
+    }
+}
+
+fn parse_start_tag(tag: &str) -> Result<XmlEvent> {
+    let (tag, is_empty) = match tag.strip_suffix('/') {
+        Some(tag) => (tag, true),
+        None => (tag, false),
+    };
+
+    let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+    let name = &tag[..name_end];
+
+    let mut attrs = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let eq = match rest.find('=') {
+            Some(eq) => eq,
+            None => {
+                return Err(invalid_xml(&format!(
+                    "attribute without value in <{}>",
+                    name
+                )))
+            }
+        };
+        let attr = rest[..eq].trim();
+        let value = rest[eq + 1..].trim_start();
+
+        let quote = match value.chars().next() {
+            Some(q) if q == '"' || q == '\'' => q,
+            _ => {
+                return Err(invalid_xml(&format!(
+                    "unquoted attribute {} in <{}>",
+                    attr, name
+                )))
+            }
+        };
+        let end = match value[1..].find(quote) {
+            Some(end) => end + 1,
+            None => return Err(invalid_xml(&format!("unterminated attribute {}", attr))),
+        };
+
+        attrs.push((attr, unescape(&value[1..end])?));
+        rest = value[end + 1..].trim_start();
+    }
+
+    Ok(XmlEvent::Start {
+        name,
+        attrs,
+        is_empty,
+    })
+}
@@ -37,7 +37,11 @@ extern crate serde_derive;
 #[cfg(feature = "ldbc")]
 extern crate regex;
 
+#[cfg(feature = "parallel")]
+extern crate rayon;
+
 pub mod algorithm;
+pub mod error;
 pub mod generic;
 pub mod graph_gen;
 pub mod graph_impl;
@@ -45,6 +49,7 @@ pub mod io;
 pub mod map;
 pub mod prelude;
 
+pub use error::GraphError;
 pub use graph_impl::{
     DiGraphMap, DiStaticGraph, GraphMap, StaticGraph, StaticGraphMmap, UnGraphMap, UnStaticGraph,
 };
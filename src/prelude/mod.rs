@@ -25,7 +25,8 @@
 //! ```
 
 pub use generic::{
-    DefaultId, DiGraphTrait, Directed, EdgeTrait, GeneralGraph, GraphLabelTrait, GraphTrait,
-    GraphType, IdType, Iter, MapTrait, MutEdgeType, MutGraphLabelTrait, MutGraphTrait, MutMapTrait,
-    MutNodeTrait, NodeTrait, UnGraphTrait, Undirected, Void,
+    DefaultId, DefaultWeight, DiGraphTrait, Directed, EdgeTrait, GeneralGraph, GraphLabelTrait,
+    GraphTrait, GraphType, GraphWeightTrait, IdType, Iter, MapTrait, MutEdgeType,
+    MutGraphLabelTrait, MutGraphTrait, MutGraphWeightTrait, MutMapTrait, MutNodeTrait, NodeTrait,
+    UnGraphTrait, Undirected, Void, WeightType,
 };
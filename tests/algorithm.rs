@@ -19,10 +19,21 @@
  * under the License.
  */
 extern crate rust_graph;
+extern crate tempfile;
 
-use rust_graph::algorithm::{graph_minus, graph_union, Bfs, ConnComp, ConnSubgraph, Dfs};
-use rust_graph::graph_impl::{DiGraphMap, UnGraphMap};
+use std::collections::HashMap;
+
+use rust_graph::algorithm::{
+    graph_minus, graph_union, is_acyclic, topological_sort, topological_sort_dfs, Bfs, ConnComp,
+    ConnSubgraph, Dfs, DfsEvent, PageRank, ShortestPath, StronglyConnComp, SubgraphMatcher,
+    TriangleCount,
+};
+use rust_graph::generic::EdgeType;
+use rust_graph::graph_impl::{DiGraphMap, EdgeVec, StaticGraphMmap, UnGraphMap};
 use rust_graph::prelude::*;
+use rust_graph::{DiStaticGraph, UnStaticGraph};
+
+use tempfile::TempDir;
 
 #[test]
 fn test_cc_undirected_one_component() {
@@ -1071,3 +1082,613 @@ fn test_graph_sub_boxed_undirected_typedgraphs() {
     assert_eq!(result_graph.get_edge_label(2, 1), Some(&10));
     assert_eq!(result_graph.get_edge_label(4, 3), None);
 }
+
+#[test]
+fn test_shortest_path_bfs_directed() {
+    let mut graph = DiGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(2, 3, None);
+    graph.add_edge(0, 3, None);
+    graph.add_edge(4, 0, None);
+
+    let sp = ShortestPath::bfs(&graph, 0);
+
+    assert_eq!(sp.source(), 0);
+    assert_eq!(sp.distance(0), Some(0));
+    assert_eq!(sp.distance(2), Some(2));
+    assert_eq!(sp.distance(3), Some(1));
+    assert_eq!(sp.distance(4), None);
+    assert_eq!(sp.predecessor(0), None);
+    assert_eq!(sp.path_to(2), Some(vec![0, 1, 2]));
+    assert_eq!(sp.path_to(4), None);
+}
+
+#[test]
+fn test_shortest_path_bfs_undirected() {
+    let mut graph = UnGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(4, 0, None);
+    graph.add_node(5, None);
+
+    let sp = ShortestPath::bfs(&graph, 2);
+
+    assert_eq!(sp.distance(4), Some(3));
+    assert_eq!(sp.path_to(4), Some(vec![2, 1, 0, 4]));
+    assert!(!sp.is_reachable(5));
+    assert_eq!(sp.distances().len(), 4);
+}
+
+#[test]
+fn test_shortest_path_dijkstra() {
+    let mut graph = DiGraphMap::<Void, u32>::new();
+    graph.add_edge(0, 1, Some(7));
+    graph.add_edge(0, 2, Some(9));
+    graph.add_edge(0, 5, Some(14));
+    graph.add_edge(1, 2, Some(10));
+    graph.add_edge(1, 3, Some(15));
+    graph.add_edge(2, 3, Some(11));
+    graph.add_edge(2, 5, Some(2));
+    graph.add_edge(3, 4, Some(6));
+    graph.add_edge(5, 4, Some(9));
+
+    let edge_labels = graph.get_edge_label_map().clone();
+    let sp = ShortestPath::dijkstra(&graph, 0, |e| {
+        *edge_labels
+            .get_item(e.get_label_id().unwrap().id())
+            .unwrap() as f64
+    });
+
+    assert_eq!(sp.distance(3), Some(20.0));
+    assert_eq!(sp.distance(4), Some(20.0));
+    assert_eq!(sp.distance(5), Some(11.0));
+    assert_eq!(sp.path_to(4), Some(vec![0, 2, 5, 4]));
+
+    let sp = ShortestPath::dijkstra(&graph, 4, |_| 1);
+    assert_eq!(sp.distances().len(), 1);
+}
+
+#[test]
+fn test_shortest_path_bellman_ford() {
+    let mut graph = DiGraphMap::<Void, i32>::new();
+    graph.add_edge(0, 1, Some(4));
+    graph.add_edge(0, 2, Some(5));
+    graph.add_edge(1, 3, Some(3));
+    graph.add_edge(2, 1, Some(-3));
+    graph.add_edge(3, 4, Some(1));
+
+    let edge_labels = graph.get_edge_label_map().clone();
+    let weight = |e: &EdgeType<DefaultId>| {
+        *edge_labels
+            .get_item(e.get_label_id().unwrap().id())
+            .unwrap()
+    };
+
+    let sp = ShortestPath::bellman_ford(&graph, 0, &weight).unwrap();
+
+    assert_eq!(sp.distance(1), Some(2));
+    assert_eq!(sp.distance(4), Some(6));
+    assert_eq!(sp.path_to(4), Some(vec![0, 2, 1, 3, 4]));
+
+    graph.add_edge(3, 2, Some(-1));
+    let edge_labels = graph.get_edge_label_map().clone();
+    let weight = |e: &EdgeType<DefaultId>| {
+        *edge_labels
+            .get_item(e.get_label_id().unwrap().id())
+            .unwrap()
+    };
+
+    assert!(ShortestPath::bellman_ford(&graph, 0, &weight).is_none());
+    assert!(ShortestPath::bellman_ford(&graph, 4, &weight).is_some());
+}
+
+#[test]
+fn test_page_rank_directed() {
+    // 0 -> 1 -> 2 -> 0
+    let edge_vec = EdgeVec::new(vec![0, 1, 2, 3], vec![1, 2, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 3], vec![2, 0, 1]);
+    let graph = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+
+    let ranks = PageRank::new().run(
+        graph.get_edge_vec(),
+        graph.get_in_edge_vec().as_ref().unwrap(),
+    );
+    for rank in ranks {
+        assert!((rank - 1.0 / 3.0).abs() < 1e-6);
+    }
+
+    let ranks = PageRank::new()
+        .tolerance(1e-10)
+        .max_iterations(500)
+        .personalization(vec![2.0, 0.0, 0.0])
+        .run(
+            graph.get_edge_vec(),
+            graph.get_in_edge_vec().as_ref().unwrap(),
+        );
+    let expected = 0.15 / (1.0 - 0.85f64.powi(3));
+    assert!((ranks[0] - expected).abs() < 1e-8);
+    assert!((ranks[1] - 0.85 * expected).abs() < 1e-8);
+    assert!((ranks[2] - 0.85 * 0.85 * expected).abs() < 1e-8);
+}
+
+#[test]
+fn test_page_rank_undirected_dangling() {
+    // Star centered at 0, plus an isolated node 4.
+    let edge_vec = EdgeVec::new(vec![0, 3, 4, 5, 6, 6], vec![1, 2, 3, 0, 0, 0]);
+    let graph = UnStaticGraph::<Void>::new(edge_vec, None, None, None);
+
+    let ranks = PageRank::new()
+        .damping(0.9)
+        .run_undirected(graph.get_edge_vec());
+
+    assert_eq!(ranks.len(), 5);
+    assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+    assert!(ranks[0] > ranks[1]);
+    assert!((ranks[1] - ranks[2]).abs() < 1e-9);
+    assert!(ranks[4] < ranks[1]);
+}
+
+#[test]
+#[should_panic]
+fn test_page_rank_mismatched_in_edges() {
+    let edge_vec = EdgeVec::<DefaultId>::new(vec![0, 1, 2, 3], vec![1, 2, 0]);
+    let in_edge_vec = EdgeVec::<DefaultId>::new(vec![0, 1, 2], vec![1, 0]);
+
+    PageRank::new().run(&edge_vec, &in_edge_vec);
+}
+
+#[test]
+fn test_page_rank_mmap() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 3, 5], vec![1, 2, 2, 0, 2]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 5, 5], vec![3, 0, 0, 1, 3]);
+    let graph = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir
+        .path()
+        .join("page_rank")
+        .to_str()
+        .unwrap()
+        .to_owned();
+    graph.dump_mmap(&prefix).expect("Dump graph error");
+    let graph_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+
+    let page_rank = PageRank::new().max_iterations(50);
+    let ranks = page_rank.run(
+        graph.get_edge_vec(),
+        graph.get_in_edge_vec().as_ref().unwrap(),
+    );
+    let ranks_mmap = page_rank.run(
+        graph_mmap.get_edge_vec(),
+        graph_mmap.get_in_edge_vec().as_ref().unwrap(),
+    );
+
+    assert_eq!(ranks, ranks_mmap);
+    assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_page_rank_compressed() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 3, 3, 5], vec![1, 2, 2, 0, 2]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 5, 5], vec![3, 0, 0, 1, 3]);
+    let graph = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+    let compressed = graph.clone().compress();
+
+    let page_rank = PageRank::new().max_iterations(50);
+    let ranks = page_rank.run(
+        graph.get_edge_vec(),
+        graph.get_in_edge_vec().as_ref().unwrap(),
+    );
+    let ranks_compressed = page_rank.run(
+        compressed.get_edge_vec(),
+        compressed.get_in_edge_vec().as_ref().unwrap(),
+    );
+
+    assert_eq!(ranks, ranks_compressed);
+}
+
+#[test]
+fn test_triangle_count() {
+    // Two triangles sharing the edge (1, 2), a pendant node 4 and a self-loop on 3.
+    let mut graph = UnGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(0, 2, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(1, 3, None);
+    graph.add_edge(2, 3, None);
+    graph.add_edge(3, 4, None);
+    graph.add_edge(3, 3, None);
+
+    let tc = TriangleCount::from_graph_map(&graph);
+
+    assert_eq!(tc.get_count(), 2);
+    assert_eq!(tc.get_node_count(0), Some(1));
+    assert_eq!(tc.get_node_count(1), Some(2));
+    assert_eq!(tc.get_node_count(2), Some(2));
+    assert_eq!(tc.get_node_count(3), Some(1));
+    assert_eq!(tc.get_node_count(4), Some(0));
+    assert_eq!(tc.get_node_count(5), None);
+
+    assert_eq!(tc.local_clustering(0), Some(1.0));
+    assert_eq!(tc.local_clustering(1), Some(2.0 / 3.0));
+    assert_eq!(tc.local_clustering(3), Some(1.0 / 3.0));
+    assert_eq!(tc.local_clustering(4), Some(0.0));
+    // Triples: 1 + 3 + 3 + 3 + 0
+    assert_eq!(tc.global_clustering(), 6.0 / 10.0);
+
+    let expected = tc.clone();
+    let tc = TriangleCount::from_edge_vec(graph.into_static().get_edge_vec());
+
+    assert_eq!(tc.get_count(), expected.get_count());
+    for id in 0..5 {
+        assert_eq!(tc.get_node_count(id), expected.get_node_count(id));
+        assert_eq!(tc.local_clustering(id), expected.local_clustering(id));
+    }
+    assert_eq!(tc.average_clustering(), expected.average_clustering());
+    assert_eq!(tc.global_clustering(), expected.global_clustering());
+}
+
+#[test]
+fn test_triangle_count_absent_nodes() {
+    let mut graph = UnGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(2, 0, None);
+    graph.add_edge(5, 6, None);
+
+    let tc = TriangleCount::from_graph_map(&graph);
+
+    assert_eq!(tc.get_node_count(2), Some(1));
+    assert_eq!(tc.get_node_count(3), None);
+    assert_eq!(tc.get_node_count(4), None);
+    assert_eq!(tc.get_node_count(5), Some(0));
+    assert_eq!(tc.local_clustering(3), None);
+    assert_eq!(tc.average_clustering(), 3.0 / 5.0);
+}
+
+#[test]
+fn test_triangle_count_complete() {
+    let mut graph = UnGraphMap::<Void>::new();
+    for i in 0..6 {
+        for j in i + 1..6 {
+            graph.add_edge(i, j, None);
+        }
+    }
+
+    let tc = TriangleCount::from_graph_map(&graph);
+    assert_eq!(tc.get_count(), 20);
+    assert_eq!(tc.average_clustering(), 1.0);
+    assert_eq!(tc.global_clustering(), 1.0);
+
+    let tc = TriangleCount::from_edge_vec(graph.into_static().get_edge_vec());
+    assert_eq!(tc.get_count(), 20);
+    assert_eq!(tc.get_node_count(3), Some(10));
+}
+
+#[test]
+fn test_subgraph_matching_unlabeled() {
+    let mut data = UnGraphMap::<Void>::new();
+    for i in 0..4 {
+        for j in i + 1..4 {
+            data.add_edge(i, j, None);
+        }
+    }
+    data.add_edge(3, 4, None);
+    let data = data.into_static();
+
+    let mut triangle = UnGraphMap::<Void>::new();
+    triangle.add_edge(0, 1, None);
+    triangle.add_edge(1, 2, None);
+    triangle.add_edge(2, 0, None);
+
+    let matches: Vec<_> = SubgraphMatcher::new(&triangle, &data).collect();
+    assert_eq!(matches.len(), 24);
+    for m in &matches {
+        for (s, d) in triangle.edge_indices() {
+            assert!(data.has_edge(m[&s], m[&d]));
+        }
+        assert!(m.values().all(|&v| v != 4));
+    }
+
+    assert_eq!(SubgraphMatcher::new(&triangle, &data).limit(5).count(), 5);
+
+    let mut star = UnGraphMap::<Void>::new();
+    star.add_edge(0, 1, None);
+    star.add_edge(0, 2, None);
+    star.add_edge(0, 3, None);
+    star.add_edge(0, 4, None);
+
+    assert_eq!(SubgraphMatcher::new(&star, &data).count(), 24);
+
+    star.add_edge(0, 5, None);
+
+    assert_eq!(SubgraphMatcher::new(&star, &data).count(), 0);
+}
+
+#[test]
+fn test_subgraph_matching_labeled() {
+    let mut data = UnGraphMap::<&str, u32>::new();
+    data.add_node(0, Some("a"));
+    data.add_node(1, Some("b"));
+    data.add_node(2, Some("b"));
+    data.add_node(3, Some("c"));
+    data.add_edge(0, 1, Some(1));
+    data.add_edge(0, 2, Some(2));
+    data.add_edge(1, 3, Some(1));
+    data.add_edge(2, 3, Some(1));
+    let data = data.into_static();
+
+    let mut query = UnGraphMap::<&str, u32>::new();
+    query.add_node(0, Some("a"));
+    query.add_node(1, Some("b"));
+    query.add_node(2, Some("c"));
+    query.add_edge(0, 1, None);
+    query.add_edge(1, 2, Some(1));
+
+    let matches: Vec<_> = SubgraphMatcher::new(&query, &data).collect();
+    assert_eq!(matches.len(), 2);
+
+    query.update_edge_label(0, 1, Some(2));
+    let matches: Vec<_> = SubgraphMatcher::new(&query, &data).collect();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0][&1], 2);
+
+    query.update_node_label(2, Some("d"));
+    assert_eq!(SubgraphMatcher::new(&query, &data).count(), 0);
+}
+
+#[test]
+fn test_subgraph_matching_directed() {
+    let mut data = DiGraphMap::<Void>::new();
+    data.add_edge(0, 1, None);
+    data.add_edge(1, 2, None);
+    data.add_edge(2, 0, None);
+    data.add_edge(2, 3, None);
+
+    let mut query = DiGraphMap::<Void>::new();
+    query.add_edge(0, 1, None);
+    query.add_edge(2, 1, None);
+
+    assert_eq!(SubgraphMatcher::new(&query, &data).count(), 0);
+
+    let mut query = DiGraphMap::<Void>::new();
+    query.add_edge(0, 1, None);
+    query.add_edge(1, 2, None);
+
+    let matches: Vec<_> = SubgraphMatcher::new(&query, &data.clone().into_static()).collect();
+    assert_eq!(matches.len(), 4);
+    for m in &matches {
+        assert!(data.has_edge(m[&0], m[&1]));
+        assert!(data.has_edge(m[&1], m[&2]));
+    }
+}
+
+#[test]
+fn test_subgraph_matching_homomorphism() {
+    let mut data = UnGraphMap::<&str>::new();
+    data.add_node(0, Some("a"));
+    data.add_node(1, Some("b"));
+    data.add_edge(0, 1, None);
+
+    let mut query = UnGraphMap::<&str>::new();
+    query.add_node(0, Some("a"));
+    query.add_node(1, Some("b"));
+    query.add_node(2, Some("a"));
+    query.add_edge(0, 1, None);
+    query.add_edge(1, 2, None);
+
+    assert_eq!(SubgraphMatcher::new(&query, &data).count(), 0);
+
+    let matches: Vec<_> = SubgraphMatcher::new(&query, &data)
+        .homomorphism(true)
+        .collect();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0][&0], 0);
+    assert_eq!(matches[0][&1], 1);
+    assert_eq!(matches[0][&2], 0);
+}
+
+#[test]
+fn test_subgraph_matching_mmap() {
+    let mut data = UnGraphMap::<u32>::new();
+    data.add_node(0, Some(0));
+    data.add_node(1, Some(1));
+    data.add_node(2, Some(0));
+    data.add_node(3, Some(1));
+    data.add_edge(0, 1, None);
+    data.add_edge(1, 2, None);
+    data.add_edge(2, 3, None);
+    data.add_edge(3, 0, None);
+    let data = data.into_static();
+
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("matching").to_str().unwrap().to_owned();
+    data.dump_mmap(&prefix).expect("Dump graph error");
+    let data_mmap = StaticGraphMmap::<DefaultId, u32>::new(&prefix).expect("Load graph error");
+
+    let mut query = UnGraphMap::<u32>::new();
+    query.add_node(0, Some(0));
+    query.add_node(1, Some(1));
+    query.add_node(2, Some(0));
+    query.add_edge(0, 1, None);
+    query.add_edge(1, 2, None);
+
+    let mut matches: Vec<_> = SubgraphMatcher::new(&query, &data)
+        .map(|m| (m[&0], m[&1], m[&2]))
+        .collect();
+    let mut matches_mmap: Vec<_> = SubgraphMatcher::new(&query, &data_mmap)
+        .map(|m| (m[&0], m[&1], m[&2]))
+        .collect();
+    matches.sort();
+    matches_mmap.sort();
+
+    assert_eq!(matches.len(), 4);
+    assert_eq!(matches, matches_mmap);
+}
+
+#[test]
+fn test_scc_directed() {
+    let mut graph = DiGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(2, 0, None);
+    graph.add_edge(2, 3, None);
+    graph.add_edge(3, 4, None);
+    graph.add_edge(4, 5, None);
+    graph.add_edge(5, 3, None);
+    graph.add_edge(6, 5, None);
+    graph.add_node(7, None);
+
+    let scc = StronglyConnComp::new(&graph);
+
+    assert_eq!(scc.get_count(), 4);
+    assert!(scc.is_strongly_connected(0, 2));
+    assert!(scc.is_strongly_connected(3, 5));
+    assert!(!scc.is_strongly_connected(2, 3));
+    assert!(!scc.is_strongly_connected(6, 5));
+    assert!(!scc.is_strongly_connected(0, 8));
+    assert_eq!(scc.get_component(8), None);
+
+    let mut sizes: Vec<_> = scc.get_components().iter().map(|c| c.len()).collect();
+    sizes.sort();
+    assert_eq!(sizes, vec![1, 1, 3, 3]);
+
+    let dag = scc.condensation(&graph);
+    let c = |id| scc.get_component(id).unwrap() as DefaultId;
+
+    assert_eq!(dag.node_count(), 4);
+    assert_eq!(dag.edge_count(), 2);
+    assert!(dag.has_edge(c(0), c(3)));
+    assert!(dag.has_edge(c(6), c(3)));
+    for (start, target) in dag.edge_indices() {
+        assert!(start < target);
+    }
+}
+
+#[test]
+fn test_scc_directed_static() {
+    let mut graph = DiGraphMap::<Void>::new();
+    for i in 0..10 {
+        graph.add_edge(i, (i + 1) % 10, None);
+    }
+    graph.add_edge(10, 0, None);
+
+    let graph = graph.into_static();
+    let scc = StronglyConnComp::new(&graph);
+
+    assert_eq!(scc.get_count(), 2);
+    assert!(scc.is_strongly_connected(0, 9));
+    assert_eq!(scc.get_component(10), Some(0));
+    assert_eq!(scc.get_component(5), Some(1));
+}
+
+fn assert_topological_order(graph: &DiGraphMap<String>, order: &[DefaultId]) {
+    assert_eq!(order.len(), graph.node_count());
+    let position: HashMap<_, _> = order.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    for (start, target) in graph.edge_indices() {
+        assert!(position[&start] < position[&target]);
+    }
+}
+
+fn assert_cycle(graph: &DiGraphMap<String>, cycle: &[DefaultId]) {
+    assert!(!cycle.is_empty());
+    for i in 0..cycle.len() {
+        assert!(graph.has_edge(cycle[i], cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+#[test]
+fn test_topological_sort() {
+    let mut graph = DiGraphMap::<String>::new();
+    graph.add_node(0, Some("compile".to_owned()));
+    graph.add_node(1, Some("link".to_owned()));
+    graph.add_node(2, Some("test".to_owned()));
+    graph.add_node(3, Some("fetch".to_owned()));
+    graph.add_node(4, Some("docs".to_owned()));
+    graph.add_edge(3, 0, None);
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(3, 4, None);
+    graph.add_edge(0, 2, None);
+
+    assert!(is_acyclic(&graph));
+    assert_topological_order(&graph, &topological_sort(&graph).unwrap());
+    assert_topological_order(&graph, &topological_sort_dfs(&graph).unwrap());
+
+    graph.add_edge(2, 3, None);
+
+    assert!(!is_acyclic(&graph));
+    assert_cycle(&graph, &topological_sort(&graph).unwrap_err());
+    assert_cycle(&graph, &topological_sort_dfs(&graph).unwrap_err());
+}
+
+#[test]
+fn test_topological_sort_self_loop() {
+    let mut graph = DiGraphMap::<String>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 1, None);
+
+    assert_eq!(topological_sort(&graph), Err(vec![1]));
+    assert_eq!(topological_sort_dfs(&graph), Err(vec![1]));
+}
+
+#[test]
+fn test_topological_sort_deterministic() {
+    // Nodes without in-edges are emitted in the order of `node_indices`.
+    let mut graph = DiGraphMap::<String>::new();
+    for i in (0..20).rev() {
+        graph.add_node(i, None);
+    }
+    graph.add_edge(19, 0, None);
+
+    let mut expected: Vec<DefaultId> = graph.node_indices().filter(|&n| n != 0).collect();
+    expected.push(0);
+    assert_eq!(topological_sort(&graph), Ok(expected));
+
+    let graph = graph.into_static();
+    let mut expected: Vec<DefaultId> = (1..20).collect();
+    expected.push(0);
+    assert_eq!(topological_sort(&graph), Ok(expected));
+}
+
+#[test]
+fn test_topological_sort_dfs_cross_edge() {
+    // 1 is pushed by 0 before it is reached from 2, which must still come first.
+    let mut graph = DiGraphMap::<String>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(0, 2, None);
+    graph.add_edge(2, 1, None);
+
+    assert_topological_order(&graph, &topological_sort_dfs(&graph).unwrap());
+}
+
+#[test]
+fn test_dfs_events() {
+    let mut graph = DiGraphMap::<Void>::new();
+    graph.add_edge(0, 1, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(2, 0, None);
+    graph.add_node(3, None);
+
+    let mut dfs = Dfs::new(&graph, Some(0));
+    let mut events = Vec::new();
+    while let Some(event) = dfs.next_event() {
+        events.push(event);
+    }
+
+    assert_eq!(
+        events,
+        vec![
+            DfsEvent::Discover(0),
+            DfsEvent::Discover(1),
+            DfsEvent::Discover(2),
+            DfsEvent::BackEdge(2, 0),
+            DfsEvent::Finish(2),
+            DfsEvent::Finish(1),
+            DfsEvent::Finish(0),
+            DfsEvent::Discover(3),
+            DfsEvent::Finish(3),
+        ]
+    );
+}
@@ -113,3 +113,39 @@ fn test_directed() {
 
     assert_eq!(edges, vec![(0, 1), (2, 1), (2, 3), (3, 0), (3, 1), (3, 2)]);
 }
+
+#[test]
+fn test_weighted() {
+    let mut g = rust_graph::DiGraphMap::<Void>::new();
+
+    g.add_edge(0, 1, None);
+    g.add_edge(0, 2, None);
+    g.add_edge(2, 1, None);
+
+    g.update_edge_weight(0, 2, Some(0.5));
+    g.update_edge_weight(2, 1, Some(2.0));
+
+    let g = g.into_static();
+
+    assert!(g.has_edge_weights());
+    assert_eq!(g.get_edge_weight(0, 1), None);
+    assert_eq!(g.get_edge_weight(0, 2), Some(0.5));
+    assert_eq!(g.get_edge_weight(2, 1), Some(2.0));
+    assert_eq!(g.get_edge_weight(1, 2), None);
+}
+
+#[test]
+fn test_weighted_any_value() {
+    let mut g =
+        rust_graph::graph_impl::TypedUnGraphMap::<DefaultId, Void, Void, DefaultId, u32>::new();
+
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 2, None);
+
+    g.update_edge_weight(0, 1, Some(u32::MAX));
+
+    let g = g.into_static();
+
+    assert_eq!(g.get_edge_weight(1, 0), Some(u32::MAX));
+    assert_eq!(g.get_edge_weight(1, 2), None);
+}
@@ -0,0 +1,253 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+extern crate rand;
+extern crate rust_graph;
+extern crate tempfile;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tempfile::TempDir;
+
+use rust_graph::generic::{DefaultId, MutEdgeTrait};
+use rust_graph::graph_impl::{DiDeltaGraph, EdgeVec, TypedUnDeltaGraph, UnDeltaGraph};
+use rust_graph::prelude::*;
+use rust_graph::{DiGraphMap, DiStaticGraph, StaticGraphMmap, UnGraphMap, UnStaticGraph};
+
+#[test]
+fn test_delta_undirected() {
+    // 0 - 1, 0 - 2, 1 - 2
+    let edge_vec = EdgeVec::new(vec![0, 2, 4, 6], vec![1, 2, 0, 2, 0, 1]);
+    let g = UnStaticGraph::<&str>::new(edge_vec, None, None, None);
+    let mut g = UnDeltaGraph::new(g);
+    assert!(!g.has_delta());
+
+    assert!(g.add_edge(1, 3, Some("a")));
+    assert!(!g.add_edge(0, 1, Some("b")));
+    assert!(g.remove_edge(2, 0).is_some());
+    assert!(g.remove_edge(2, 0).is_none());
+    assert!(g.has_delta());
+
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g.neighbors(0).into_owned(), vec![1]);
+    assert_eq!(g.neighbors(1).into_owned(), vec![0, 2, 3]);
+    assert_eq!(g.neighbors(2).into_owned(), vec![1]);
+    assert_eq!(g.neighbors(3).into_owned(), vec![1]);
+    assert_eq!(g.degree(1), 3);
+    assert_eq!(g.get_edge_label(1, 0), Some(&"b"));
+    assert_eq!(g.get_edge_label(3, 1), Some(&"a"));
+    assert_eq!(g.get_edge_label(1, 2), None);
+    assert_eq!(
+        g.edge_indices().collect::<Vec<_>>(),
+        vec![(0, 1), (1, 2), (1, 3)]
+    );
+
+    assert!(g.remove_node(1).is_some());
+    assert!(!g.has_node(1));
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 0);
+
+    // The node comes back without its edges.
+    assert!(g.add_node(1, Some("c")));
+    assert_eq!(g.degree(1), 0);
+    assert_eq!(g.get_node_label(1), Some(&"c"));
+
+    let compacted = g.compact();
+    assert_eq!(compacted.node_count(), 4);
+    assert_eq!(compacted.edge_count(), 0);
+    assert_eq!(compacted.get_node_label(1), Some(&"c"));
+}
+
+#[test]
+fn test_delta_directed() {
+    // 0 -> 1, 0 -> 2, 2 -> 1
+    let edge_vec = EdgeVec::new(vec![0, 2, 2, 3], vec![1, 2, 1]);
+    let in_edge_vec = EdgeVec::new(vec![0, 0, 2, 3], vec![0, 2, 0]);
+    let g = DiStaticGraph::<&str>::new(edge_vec, Some(in_edge_vec), None, None);
+    let mut g = DiDeltaGraph::new(g);
+
+    g.add_edge(1, 0, None);
+    g.add_edge(4, 1, Some("a"));
+    g.remove_edge(0, 2);
+
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g.neighbors(0).into_owned(), vec![1]);
+    assert_eq!(g.in_neighbors(1).into_owned(), vec![0, 2, 4]);
+    assert_eq!(g.in_neighbors(0).into_owned(), vec![1]);
+    assert_eq!(g.in_degree(2), 0);
+    assert!(!g.has_node(3));
+
+    let compacted = g.compact();
+    assert_eq!(compacted.node_count(), 4);
+    assert_eq!(compacted.edge_count(), 4);
+    assert_eq!(compacted.in_neighbors(1).into_owned(), vec![0, 2, 4]);
+    assert_eq!(compacted.get_edge_label(4, 1), Some(&"a"));
+    assert_eq!(
+        compacted.edge_indices().collect::<Vec<_>>(),
+        g.edge_indices().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_delta_mut_labels() {
+    let mut g = UnGraphMap::<&str>::new();
+    g.add_edge(0, 1, Some("a"));
+    g.add_edge(1, 2, Some("b"));
+    let mut g = UnDeltaGraph::new(g.into_static());
+
+    g.get_edge_mut(1, 2).set_label_id(None);
+    assert_eq!(g.get_edge_label(1, 2), None);
+    assert!(g.update_node_label(2, Some("c")));
+    assert_eq!(g.get_node_label(2), Some(&"c"));
+
+    for mut node in g.nodes_mut() {
+        node.set_label_id(None);
+    }
+    assert!(g.node_indices().all(|n| g.get_node_label(n).is_none()));
+    assert_eq!(g.get_edge_label(0, 1), Some(&"a"));
+}
+
+#[test]
+fn test_delta_mmap() {
+    let mut g = UnGraphMap::<String>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 2, None);
+
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("delta").to_str().unwrap().to_owned();
+    g.into_static()
+        .dump_mmap(&prefix)
+        .expect("Dump graph error");
+
+    let g_mmap = StaticGraphMmap::<DefaultId, String>::new(&prefix).expect("Load graph error");
+    let mut g = TypedUnDeltaGraph::<DefaultId, String, String, DefaultId, _>::new(g_mmap);
+    g.add_edge(2, 0, None);
+    g.remove_edge(0, 1);
+
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.neighbors(0).into_owned(), vec![2]);
+    assert_eq!(g.compact().neighbors(2).into_owned(), vec![0, 1]);
+}
+
+#[test]
+fn test_delta_unsorted_base() {
+    // The nodes of a `GraphMap` are not returned in order.
+    let mut g = UnGraphMap::<Void>::new();
+    for i in (0..50).rev() {
+        g.add_edge(i, (i + 7) % 50, None);
+    }
+    let mut g = TypedUnDeltaGraph::<DefaultId, Void, Void, DefaultId, _>::new(g);
+    g.add_node(60, None);
+    g.add_edge(3, 55, None);
+
+    let nodes: Vec<_> = g.node_indices().collect();
+    let mut expected: Vec<DefaultId> = (0..50).collect();
+    expected.extend(vec![55, 60]);
+    assert_eq!(nodes, expected);
+
+    let edges: Vec<_> = g.edge_indices().collect();
+    let mut sorted = edges.clone();
+    sorted.sort();
+    assert_eq!(edges, sorted);
+    assert_eq!(edges.len(), 51);
+}
+
+#[test]
+fn test_delta_compact_weights() {
+    // 0 - 1, 0 - 2, 1 - 2, weighted except for 1 - 2
+    let edge_vec = EdgeVec::new(vec![0, 2, 4, 6], vec![1, 2, 0, 2, 0, 1]);
+    let g = UnStaticGraph::<&str>::new(edge_vec, None, None, None).with_edge_weights(vec![
+        Some(1.0),
+        Some(2.0),
+        Some(1.0),
+        None,
+        Some(2.0),
+        None,
+    ]);
+    let mut g = UnDeltaGraph::new(g);
+
+    // The label update keeps the weight, while the re-inserted and new edges have none.
+    assert!(g.update_edge_label(1, 0, Some("a")));
+    g.remove_edge(0, 2);
+    g.add_edge(2, 0, None);
+    g.add_edge(2, 3, None);
+
+    let compacted = g.compact();
+    assert_eq!(compacted.get_edge_label(0, 1), Some(&"a"));
+    assert_eq!(compacted.get_edge_weight(0, 1), Some(1.0));
+    assert_eq!(compacted.get_edge_weight(1, 0), Some(1.0));
+    assert_eq!(compacted.get_edge_weight(0, 2), None);
+    assert_eq!(compacted.get_edge_weight(1, 2), None);
+    assert_eq!(compacted.get_edge_weight(2, 3), None);
+
+    // Only edges of a weighted base graph carry weights.
+    let edge_vec = EdgeVec::new(vec![0, 1, 2], vec![1, 0]);
+    let g = UnDeltaGraph::new(UnStaticGraph::<&str>::new(edge_vec, None, None, None));
+    assert!(g.compact().get_edge_weights().is_none());
+}
+
+#[test]
+fn test_delta_random_updates() {
+    let mut rng = StdRng::from_seed([7; 32]);
+
+    // `DiGraphMap` is the reference, on which self loops are avoided.
+    let mut expected = DiGraphMap::<Void>::new();
+    for _ in 0..100 {
+        let (s, d) = (rng.gen_range(0, 20), rng.gen_range(0, 20));
+        if s != d {
+            expected.add_edge(s, d, None);
+        }
+    }
+    let mut g = DiDeltaGraph::new(expected.clone().into_static());
+
+    for _ in 0..200 {
+        let (s, d) = (rng.gen_range(0, 25), rng.gen_range(0, 25));
+        match rng.gen_range(0, 4) {
+            0 if s != d => assert_eq!(expected.add_edge(s, d, None), g.add_edge(s, d, None)),
+            1 => assert_eq!(
+                expected.remove_edge(s, d).is_some(),
+                g.remove_edge(s, d).is_some()
+            ),
+            2 => assert_eq!(
+                expected.remove_node(s).is_some(),
+                g.remove_node(s).is_some()
+            ),
+            _ => assert_eq!(expected.add_node(s, None), g.add_node(s, None)),
+        }
+    }
+
+    let mut nodes: Vec<_> = expected.node_indices().collect();
+    nodes.sort();
+    assert_eq!(g.node_count(), expected.node_count());
+    assert_eq!(g.node_indices().collect::<Vec<_>>(), nodes);
+    for n in expected.node_indices() {
+        assert_eq!(g.neighbors(n), expected.neighbors(n));
+        assert_eq!(g.in_neighbors(n), expected.in_neighbors(n));
+        assert_eq!(g.degree(n), expected.degree(n));
+    }
+
+    let compacted = g.compact();
+    for n in expected.node_indices() {
+        assert_eq!(compacted.neighbors(n), expected.neighbors(n));
+        assert_eq!(compacted.in_neighbors(n), expected.in_neighbors(n));
+    }
+}
@@ -22,8 +22,25 @@ extern crate rust_graph;
 
 use rust_graph::prelude::*;
 
+use rust_graph::generic::{Directed, Undirected};
+use rust_graph::graph_gen::{complete_bipartite_graph_unlabeled, random_bipartite_graph_unlabeled};
 use rust_graph::graph_gen::{complete_graph_unlabeled, empty_graph_unlabeled};
+use rust_graph::graph_gen::{cycle_graph_unlabeled, path_graph, path_graph_unlabeled};
+use rust_graph::graph_gen::{grid_2d_graph_unlabeled, grid_3d_graph_unlabeled};
+use rust_graph::graph_gen::{
+    kary_tree_graph_unlabeled, star_graph_unlabeled, wheel_graph_unlabeled,
+};
+use rust_graph::graph_gen::{random_ba_graph, random_ba_graph_unlabeled};
+use rust_graph::graph_gen::{random_ba_graph_with_rng, random_configuration_graph_with_rng};
+use rust_graph::graph_gen::{random_configuration_graph_unlabeled, random_ws_graph_unlabeled};
+use rust_graph::graph_gen::{random_gnm_graph_into_builder, random_sparse_gnp_graph_into_builder};
 use rust_graph::graph_gen::{random_gnm_graph_unlabeled, random_gnp_graph_unlabeled};
+use rust_graph::graph_gen::{random_gnm_graph_with_rng, random_gnp_graph_with_rng};
+use rust_graph::graph_gen::{random_rmat_graph_unlabeled, GRAPH500_RMAT_PROBS};
+use rust_graph::graph_gen::{random_rmat_graph_with_rng, random_ws_graph_with_rng, seeded_rng};
+use rust_graph::graph_gen::{random_sparse_gnp_graph, random_sparse_gnp_graph_unlabeled};
+use rust_graph::graph_gen::{torus_2d_graph_unlabeled, torus_3d_graph_unlabeled};
+use rust_graph::graph_impl::static_graph::StaticGraphBuilder;
 use rust_graph::graph_impl::{DiGraphMap, UnGraphMap};
 
 #[test]
@@ -49,4 +66,247 @@ fn test_random_gnm_graph() {
     let g2: UnGraphMap<u8> = random_gnm_graph_unlabeled(num_of_nodes, num_of_edges);
     assert_eq!(g2.node_count(), num_of_nodes);
     assert_eq!(g2.edge_count(), num_of_edges);
+
+    let clique: DiGraphMap<u8> = random_gnm_graph_unlabeled(20, 20 * 19);
+    assert_eq!(clique, complete_graph_unlabeled(20));
+
+    let clique: UnGraphMap<u8> = random_gnm_graph_unlabeled(20, 20 * 19 / 2);
+    assert_eq!(clique, complete_graph_unlabeled(20));
+}
+
+#[test]
+fn test_random_sparse_gnp_graph() {
+    let num_of_nodes = 100;
+
+    let empty: DiGraphMap<u8> = random_sparse_gnp_graph_unlabeled(num_of_nodes, 0f32);
+    assert_eq!(empty, empty_graph_unlabeled(num_of_nodes));
+
+    let clique: DiGraphMap<u8> = random_sparse_gnp_graph_unlabeled(num_of_nodes, 1f32);
+    assert_eq!(clique, complete_graph_unlabeled(num_of_nodes));
+
+    let clique: UnGraphMap<u8> = random_sparse_gnp_graph_unlabeled(num_of_nodes, 1f32);
+    assert_eq!(clique, complete_graph_unlabeled(num_of_nodes));
+
+    // About 10,000 edges, with a standard deviation of 100.
+    let num_of_nodes = 2000;
+    let g: UnGraphMap<u8, u8> = random_sparse_gnp_graph(num_of_nodes, 0.005f32, vec![0], vec![1]);
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert!(g.edge_count() > 9000 && g.edge_count() < 11000);
+    assert!(g.edge_indices().all(|(s, d)| s != d));
+    assert!(g
+        .edge_indices()
+        .all(|(s, d)| g.get_edge_label(s, d) == Some(&1)));
+}
+
+#[test]
+fn test_random_graph_into_builder() {
+    let mut builder = StaticGraphBuilder::<DefaultId, u8, u8, Directed>::new();
+    random_gnm_graph_into_builder(
+        1000,
+        5000,
+        vec![0, 1],
+        vec![2],
+        &mut builder,
+        &mut seeded_rng(0),
+    ).unwrap();
+    let g = builder.build().unwrap();
+    assert_eq!(g.node_count(), 1000);
+    assert_eq!(g.edge_count(), 5000);
+    assert!(g.node_indices().all(|n| g.get_node_label(n).is_some()));
+
+    let mut builder = StaticGraphBuilder::<DefaultId, u8, u8, Undirected>::new();
+    random_sparse_gnp_graph_into_builder(
+        1000,
+        0f32,
+        vec![],
+        vec![],
+        &mut builder,
+        &mut seeded_rng(0),
+    ).unwrap();
+    let g = builder.build().unwrap();
+    assert_eq!(g.node_count(), 1000);
+    assert_eq!(g.edge_count(), 0);
+
+    let mut builder = StaticGraphBuilder::<DefaultId, u8, u8, Undirected>::new();
+    random_sparse_gnp_graph_into_builder(
+        50,
+        1f32,
+        vec![],
+        vec![],
+        &mut builder,
+        &mut seeded_rng(0),
+    ).unwrap();
+    let g = builder.build().unwrap();
+    assert_eq!(g.edge_count(), 50 * 49 / 2);
+}
+
+#[test]
+fn test_random_ba_graph() {
+    let num_of_nodes = 100;
+    let m = 3;
+
+    let g: UnGraphMap<u8, u8> = random_ba_graph(num_of_nodes, m, vec![0, 1], vec![2, 3]);
+    assert_eq!(g.node_count(), num_of_nodes);
+    assert_eq!(g.edge_count(), m * (num_of_nodes - m));
+    assert!(g.node_indices().all(|n| g.get_node_label(n).is_some()));
+    assert!(g
+        .edge_indices()
+        .all(|(s, d)| g.get_edge_label(s, d).is_some()));
+
+    let g: DiGraphMap<u8> = random_ba_graph_unlabeled(num_of_nodes, m);
+    assert_eq!(g.edge_count(), m * (num_of_nodes - m));
+    assert!((m..num_of_nodes).all(|n| g.degree(DefaultId::new(n)) == m));
+}
+
+#[test]
+fn test_random_ws_graph() {
+    let num_of_nodes = 100;
+    let k = 4;
+
+    let ring: UnGraphMap<u8> = random_ws_graph_unlabeled(num_of_nodes, k, 0f32);
+    assert_eq!(ring.edge_count(), num_of_nodes * k / 2);
+    assert!(ring.node_indices().all(|n| ring.degree(n) == k));
+    assert!(ring.has_edge(0, 99) && ring.has_edge(0, 98));
+
+    let g: UnGraphMap<u8> = random_ws_graph_unlabeled(num_of_nodes, k, 0.5f32);
+    assert_eq!(g.edge_count(), num_of_nodes * k / 2);
+    assert!(g.edge_indices().all(|(s, d)| s != d));
+
+    let g: DiGraphMap<u8> = random_ws_graph_unlabeled(num_of_nodes, k, 1f32);
+    assert_eq!(g.edge_count(), num_of_nodes * k);
+    assert!(g.node_indices().all(|n| g.degree(n) == k));
+}
+
+#[test]
+fn test_random_rmat_graph() {
+    let scale = 8;
+    let edge_factor = 4;
+
+    let g: DiGraphMap<u8> = random_rmat_graph_unlabeled(scale, edge_factor, GRAPH500_RMAT_PROBS);
+    assert_eq!(g.node_count(), 1 << scale);
+    assert!(g.edge_count() <= edge_factor << scale);
+    assert!(g.edge_count() > 0);
+    assert!(g.edge_indices().all(|(s, d)| s != d));
+
+    let max_degree = g.node_indices().map(|n| g.degree(n)).max().unwrap();
+    assert!(max_degree > 4 * edge_factor);
+}
+
+#[test]
+fn test_random_configuration_graph() {
+    let degrees = vec![1; 10];
+    let g: UnGraphMap<u8> = random_configuration_graph_unlabeled(&degrees);
+    assert_eq!(g.node_count(), 10);
+    assert_eq!(g.edge_count(), 5);
+    assert!(g.node_indices().all(|n| g.degree(n) == 1));
+
+    let degrees = vec![5, 4, 3, 3, 2, 2, 1, 1, 1, 0];
+    let g: UnGraphMap<u8> = random_configuration_graph_unlabeled(&degrees);
+    assert!(g.node_indices().all(|n| g.degree(n) <= degrees[n as usize]));
+    assert_eq!(g.degree(9), 0);
+}
+
+#[test]
+fn test_seeded_generators() {
+    let labels = || (vec![0, 1, 2, 3], vec![4, 5, 6, 7]);
+
+    let gnp = |seed| -> DiGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_gnp_graph_with_rng(50, 0.1f32, nl, el, &mut seeded_rng(seed))
+    };
+    let gnm = |seed| -> UnGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_gnm_graph_with_rng(50, 100, nl, el, &mut seeded_rng(seed))
+    };
+    let ba = |seed| -> UnGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_ba_graph_with_rng(50, 2, nl, el, &mut seeded_rng(seed))
+    };
+    let ws = |seed| -> UnGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_ws_graph_with_rng(50, 4, 0.3f32, nl, el, &mut seeded_rng(seed))
+    };
+    let rmat = |seed| -> DiGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_rmat_graph_with_rng(6, 4, GRAPH500_RMAT_PROBS, nl, el, &mut seeded_rng(seed))
+    };
+    let configuration = |seed| -> UnGraphMap<u8, u8> {
+        let (nl, el) = labels();
+        random_configuration_graph_with_rng(&[3; 50], nl, el, &mut seeded_rng(seed))
+    };
+
+    assert_eq!(gnp(7), gnp(7));
+    assert_ne!(gnp(7), gnp(8));
+    assert_eq!(gnm(7), gnm(7));
+    assert_ne!(gnm(7), gnm(8));
+    assert_eq!(ba(7), ba(7));
+    assert_ne!(ba(7), ba(8));
+    assert_eq!(ws(7), ws(7));
+    assert_ne!(ws(7), ws(8));
+    assert_eq!(rmat(7), rmat(7));
+    assert_ne!(rmat(7), rmat(8));
+    assert_eq!(configuration(7), configuration(7));
+    assert_ne!(configuration(7), configuration(8));
+}
+
+#[test]
+fn test_structured_graphs() {
+    let path: DiGraphMap<u8> = path_graph_unlabeled(5);
+    assert_eq!(path.edge_count(), 4);
+    assert!(path.has_edge(0, 1) && !path.has_edge(1, 0));
+
+    let path: UnGraphMap<u8, u8> = path_graph(5, vec![0], vec![1]);
+    assert!(path
+        .node_indices()
+        .all(|n| path.get_node_label(n) == Some(&0)));
+    assert!(path
+        .edge_indices()
+        .all(|(s, d)| path.get_edge_label(s, d) == Some(&1)));
+
+    let cycle: UnGraphMap<u8> = cycle_graph_unlabeled(5);
+    assert_eq!(cycle.edge_count(), 5);
+    assert!(cycle.node_indices().all(|n| cycle.degree(n) == 2));
+    let cycle: DiGraphMap<u8> = cycle_graph_unlabeled(2);
+    assert_eq!(cycle.edge_count(), 1);
+
+    let star: DiGraphMap<u8> = star_graph_unlabeled(5);
+    assert_eq!(star.edge_count(), 4);
+    assert_eq!(star.degree(0), 4);
+
+    let wheel: UnGraphMap<u8> = wheel_graph_unlabeled(6);
+    assert_eq!(wheel.edge_count(), 10);
+    assert_eq!(wheel.degree(0), 5);
+    assert!((1..6).all(|n| wheel.degree(n) == 3));
+
+    let tree: DiGraphMap<u8> = kary_tree_graph_unlabeled(2, 7);
+    assert_eq!(tree.edge_count(), 6);
+    assert!(tree.has_edge(0, 2) && tree.has_edge(2, 5) && tree.has_edge(2, 6));
+
+    let grid: UnGraphMap<u8> = grid_2d_graph_unlabeled(3, 4);
+    assert_eq!(grid.node_count(), 12);
+    assert_eq!(grid.edge_count(), 3 * 3 + 2 * 4);
+    assert!(grid.has_edge(0, 1) && grid.has_edge(0, 4) && !grid.has_edge(3, 4));
+
+    let grid: DiGraphMap<u8> = grid_3d_graph_unlabeled(2, 3, 4);
+    assert_eq!(grid.node_count(), 24);
+    assert_eq!(grid.edge_count(), 3 * 4 + 2 * 2 * 4 + 2 * 3 * 3);
+    assert!(grid.has_edge(0, 12) && grid.has_edge(0, 4) && grid.has_edge(0, 1));
+
+    let torus: UnGraphMap<u8> = torus_2d_graph_unlabeled(3, 4);
+    assert_eq!(torus.edge_count(), 2 * 12);
+    assert!(torus.node_indices().all(|n| torus.degree(n) == 4));
+
+    let torus: UnGraphMap<u8> = torus_3d_graph_unlabeled(3, 3, 3);
+    assert_eq!(torus.edge_count(), 3 * 27);
+    assert!(torus.node_indices().all(|n| torus.degree(n) == 6));
+
+    let bipartite: DiGraphMap<u8> = complete_bipartite_graph_unlabeled(2, 3);
+    assert_eq!(bipartite.node_count(), 5);
+    assert_eq!(bipartite.edge_count(), 6);
+    assert!(bipartite.edge_indices().all(|(s, d)| s < 2 && d >= 2));
+
+    let bipartite: UnGraphMap<u8> = random_bipartite_graph_unlabeled(5, 5, 1f32);
+    assert_eq!(bipartite, complete_bipartite_graph_unlabeled(5, 5));
+    let empty: UnGraphMap<u8> = random_bipartite_graph_unlabeled(5, 5, 0f32);
+    assert_eq!(empty, empty_graph_unlabeled(10));
 }
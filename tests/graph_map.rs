@@ -24,6 +24,7 @@ use std::collections::HashMap;
 
 use rust_graph::generic::{MutEdgeType, MutNodeType};
 use rust_graph::graph_impl::graph_map::{Edge, MutEdge, NodeMap, NodeMapTrait};
+use rust_graph::graph_impl::TypedDiGraphMap;
 use rust_graph::prelude::*;
 use rust_graph::{DiGraphMap, UnGraphMap};
 
@@ -438,3 +439,79 @@ fn test_stats() {
     assert_eq!(g.get_node_label_id_counter().into_map(), expected_counter);
     assert_eq!(g.get_edge_label_id_counter().into_map(), expected_counter)
 }
+
+#[test]
+fn test_edge_weights() {
+    let mut g = DiGraphMap::<Void>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 2, None);
+    g.add_edge(2, 0, None);
+
+    assert!(!g.has_edge_weights());
+    assert!(g.update_edge_weight(0, 1, Some(1.5)));
+    assert!(g.update_edge_weight(1, 2, Some(2.5)));
+    assert!(!g.update_edge_weight(1, 0, Some(3.0)));
+    assert!(g.has_edge_weights());
+
+    assert_eq!(g.get_edge_weight(0, 1), Some(1.5));
+    assert_eq!(g.get_edge_weight(1, 0), None);
+    assert_eq!(g.get_edge_weight(2, 0), None);
+
+    let mut weighted: Vec<_> = g.weighted_edge_indices().collect();
+    weighted.sort_by_key(|&(s, t, _)| (s, t));
+    assert_eq!(weighted, vec![(0, 1, 1.5), (1, 2, 2.5)]);
+
+    g.remove_edge(0, 1);
+    assert_eq!(g.get_edge_weight(0, 1), None);
+    g.remove_node(2);
+    assert!(!g.has_edge_weights());
+
+    let mut g = UnGraphMap::<Void>::new();
+    g.add_edge(0, 1, None);
+    assert!(g.update_edge_weight(1, 0, Some(4.0)));
+    assert_eq!(g.get_edge_weight(0, 1), Some(4.0));
+    assert_eq!(g.get_edge_weight(1, 0), Some(4.0));
+
+    assert!(g.update_edge_weight(0, 1, None));
+    assert_eq!(g.get_edge_weight(1, 0), None);
+}
+
+#[test]
+fn test_edge_properties() {
+    let mut g = TypedDiGraphMap::<DefaultId, Void, Void, DefaultId, String>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(1, 2, None);
+    g.add_edge(2, 0, None);
+
+    g.update_edge_weight(0, 1, Some("a".to_owned()));
+    g.update_edge_weight(1, 2, Some("b".to_owned()));
+    g.update_edge_weight(2, 0, Some("c".to_owned()));
+
+    // Adding an existing edge again keeps its property.
+    g.add_edge(0, 1, None);
+    assert_eq!(g.get_edge_weight(0, 1), Some("a".to_owned()));
+
+    g.remove_node(1);
+    assert_eq!(g.get_edge_weight(2, 0), Some("c".to_owned()));
+
+    g.add_edge(0, 2, None);
+    g.update_edge_weight(0, 2, Some("d".to_owned()));
+    assert_eq!(g.get_edge_weight(0, 2), Some("d".to_owned()));
+    assert_eq!(g.get_edge_weight(2, 0), Some("c".to_owned()));
+
+    let mut result = g.clone().reorder_id(true, false, false);
+    let reordered = result.take_graph().unwrap();
+    let map = result.get_node_id_map().unwrap();
+    for (s, d) in g.edge_indices() {
+        let new_s = DefaultId::new(map.find_index(&s).unwrap());
+        let new_d = DefaultId::new(map.find_index(&d).unwrap());
+        assert_eq!(
+            reordered.get_edge_weight(new_s, new_d),
+            g.get_edge_weight(s, d)
+        );
+    }
+
+    g.remove_edge_weights();
+    assert!(!g.has_edge_weights());
+    assert_eq!(g.get_edge_weight(2, 0), None);
+}
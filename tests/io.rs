@@ -21,9 +21,18 @@
 extern crate rust_graph;
 extern crate tempfile;
 
+use std::fs;
+
+use rust_graph::error::GraphError;
 use rust_graph::graph_gen::{random_gnm_graph, random_gnm_graph_unlabeled};
 use rust_graph::graph_impl::{DiGraphMap, GraphMap, UnGraphMap};
-use rust_graph::io::{read_from_csv, write_to_csv};
+use rust_graph::io::csv::{CSVReader, CSVWriter, ErrorPolicy};
+use rust_graph::io::dot::to_dot;
+use rust_graph::io::{read_from_csv, read_weighted_from_csv, write_to_csv, write_weighted_to_csv};
+use rust_graph::io::graphml::read_graphml_with_id_map;
+use rust_graph::io::{read_graphml, write_gexf, write_graphml};
+use rust_graph::io::{read_metis, read_mtx, read_snap, write_metis, write_mtx, write_snap};
+use rust_graph::map::SetMap;
 use rust_graph::prelude::*;
 
 use tempfile::TempDir;
@@ -122,3 +131,543 @@ fn test_cvs_labeled() {
     );
     assert_eq!(g, g_);
 }
+
+#[test]
+fn test_cvs_weighted() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    let mut g = DiGraphMap::<String>::new();
+    g.add_node(0, Some("a".to_owned()));
+    g.add_node(1, Some("b".to_owned()));
+    g.add_node(2, None);
+    g.add_edge(0, 1, Some("x".to_owned()));
+    g.add_edge(1, 2, None);
+    g.add_edge(2, 0, None);
+    g.update_edge_weight(0, 1, Some(1.5));
+    g.update_edge_weight(1, 2, Some(-2.0));
+
+    let path_to_nodes = tmp_dir_path.join("nodes.csv");
+    let path_to_edges = tmp_dir_path.join("edges.csv");
+    assert!(write_weighted_to_csv(&g, &path_to_nodes, &path_to_edges).is_ok());
+
+    let mut g_ = DiGraphMap::<String>::new();
+    assert!(
+        read_weighted_from_csv(
+            &mut g_,
+            Some(path_to_nodes.clone()),
+            path_to_edges.clone(),
+            None,
+            true,
+            true
+        ).is_ok()
+    );
+    assert_eq!(g, g_);
+
+    // The weight column is ignored when reading unweighted.
+    let mut g_ = DiGraphMap::<String>::new();
+    assert!(
+        read_from_csv(
+            &mut g_,
+            Some(path_to_nodes),
+            path_to_edges,
+            None,
+            true,
+            true
+        ).is_ok()
+    );
+    assert_eq!(g_.edge_count(), 3);
+    assert!(!g_.has_edge_weights());
+}
+
+#[test]
+fn test_cvs_id_map() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    let path_to_nodes = tmp_dir_path.join("nodes_1.csv");
+    let path_to_edges = tmp_dir_path.join("edges_1.csv");
+    fs::write(
+        &path_to_nodes,
+        "nodeId:ID,:LABEL\nhttp://a.org/x,a\n18446744073709551615,b\n",
+    ).unwrap();
+    fs::write(
+        &path_to_edges,
+        ":START_ID,:END_ID,:TYPE\nhttp://a.org/x,18446744073709551615,e\n\
+         18446744073709551615,7c9e6679-7425-40de-944b-e07fc1f90ae7,\n",
+    ).unwrap();
+
+    let mut g = DiGraphMap::<String>::new();
+    let (id_map, _) = CSVReader::new(Some(&path_to_nodes), &path_to_edges)
+        .read_with_id_map(&mut g)
+        .unwrap();
+
+    assert_eq!(id_map.len(), 3);
+    assert_eq!(id_map.find_index(&"http://a.org/x".to_owned()), Some(0));
+    assert_eq!(
+        id_map.find_index(&"18446744073709551615".to_owned()),
+        Some(1)
+    );
+    assert_eq!(
+        id_map.find_index(&"7c9e6679-7425-40de-944b-e07fc1f90ae7".to_owned()),
+        Some(2)
+    );
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.get_node_label(0), Some(&"a".to_owned()));
+    assert_eq!(g.get_edge_label(0, 1), Some(&"e".to_owned()));
+    assert!(g.has_edge(1, 2));
+
+    // Writing with the id map restores the original ids.
+    let path_to_nodes = tmp_dir_path.join("nodes_2.csv");
+    let path_to_edges = tmp_dir_path.join("edges_2.csv");
+    assert!(
+        CSVWriter::new(&g, &path_to_nodes, &path_to_edges)
+            .id_map(&id_map)
+            .write()
+            .is_ok()
+    );
+    assert!(
+        fs::read_to_string(&path_to_edges)
+            .unwrap()
+            .contains("http://a.org/x,18446744073709551615,e")
+    );
+
+    let mut g_ = DiGraphMap::<String>::new();
+    let (id_map_, _) = CSVReader::new(Some(&path_to_nodes), &path_to_edges)
+        .read_with_id_map(&mut g_)
+        .unwrap();
+    assert_eq!(g_.node_count(), 3);
+    assert_eq!(g_.edge_count(), 2);
+
+    // Node order is not preserved, so compare by external id.
+    let external_edges = |g: &DiGraphMap<String>, id_map: &SetMap<String>| {
+        let mut edges: Vec<_> = g
+            .edge_indices()
+            .map(|(s, t)| {
+                (
+                    id_map.get_item(s.id()).unwrap().clone(),
+                    id_map.get_item(t.id()).unwrap().clone(),
+                    g.get_edge_label(s, t).cloned(),
+                )
+            })
+            .collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(external_edges(&g, &id_map), external_edges(&g_, &id_map_));
+    for key in id_map.items() {
+        let id = DefaultId::new(id_map.find_index(key).unwrap());
+        let id_ = DefaultId::new(id_map_.find_index(key).unwrap());
+        assert_eq!(g.get_node_label(id), g_.get_node_label(id_));
+    }
+
+    // Interned ids would collide with the nodes already in the graph.
+    let mut g_ = DiGraphMap::<String>::new();
+    g_.add_node(0, None);
+    match CSVReader::new(Some(&path_to_nodes), &path_to_edges).read_with_id_map(&mut g_) {
+        Err(GraphError::InvalidInput(_)) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+    assert_eq!(g_.node_count(), 1);
+}
+
+#[test]
+fn test_cvs_error_policy() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+
+    let path_to_nodes = tmp_dir_path.join("nodes.csv");
+    let path_to_edges = tmp_dir_path.join("edges.csv");
+    fs::write(&path_to_nodes, "nodeId:ID,:LABEL\n0,n\n0,m\n").unwrap();
+    fs::write(
+        &path_to_edges,
+        ":START_ID,:END_ID,:TYPE\n0,1,a\n1,x,b\n1,2,c\n2,2,\n0,1,a\n3,4,d,e\n",
+    ).unwrap();
+
+    let reader = CSVReader::new(Some(&path_to_nodes), &path_to_edges);
+
+    let mut g = DiGraphMap::<String>::new();
+    let report = reader.read(&mut g).unwrap();
+    assert_eq!(report.rows_read, 8);
+    assert_eq!(report.nodes_added, 3);
+    assert_eq!(report.edges_added, 3);
+    assert_eq!(report.duplicate_nodes, 1);
+    assert_eq!(report.duplicate_edges, 1);
+    assert_eq!(report.self_loops, 1);
+    assert_eq!(report.skipped_rows, 2);
+    assert!(report.skipped.is_empty());
+    assert!(g.has_edge(1, 2));
+    assert!(!g.has_node(3));
+
+    let mut g_ = DiGraphMap::<String>::new();
+    let report_ = reader
+        .clone()
+        .error_policy(ErrorPolicy::Collect)
+        .read(&mut g_)
+        .unwrap();
+    assert_eq!(g, g_);
+    assert_eq!(report_.skipped_rows, 2);
+    assert_eq!(report_.skipped.len(), 2);
+    assert_eq!(report_.skipped[0].path, path_to_edges);
+    assert_eq!(report_.skipped[0].line, 3);
+    assert_eq!(report_.skipped[0].record, "1,x,b");
+    assert_eq!(report_.skipped[1].line, 7);
+    assert_eq!(report_.skipped[1].record, "3,4,d,e");
+
+    let mut g_ = DiGraphMap::<String>::new();
+    let result = reader.error_policy(ErrorPolicy::FailFast).read(&mut g_);
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("line 3"), "{}", error);
+    assert!(!g_.has_node(2));
+}
+
+fn sorted_edges<G: GraphTrait<DefaultId, DefaultId>>(g: &G) -> Vec<(DefaultId, DefaultId)> {
+    let mut edges: Vec<_> = g.edge_indices().collect();
+    edges.sort();
+    edges
+}
+
+#[test]
+fn test_mtx() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.mtx");
+
+    fs::write(
+        &path,
+        "%%MatrixMarket matrix coordinate real symmetric\n\
+         % A comment\n\
+         4 4 3\n\
+         2 1 0.5\n\
+         3 2 1.0\n\
+         3 3 2.0\n",
+    ).unwrap();
+
+    let mut g = UnGraphMap::<Void>::new();
+    assert!(read_mtx(&mut g, &path).is_ok());
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(sorted_edges(&g), vec![(0, 1), (1, 2), (2, 2)]);
+
+    let mut g = DiGraphMap::<Void>::new();
+    assert!(read_mtx(&mut g, &path).is_ok());
+    assert_eq!(
+        sorted_edges(&g),
+        vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 2)]
+    );
+
+    let g: UnGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_mtx(&g, &path).is_ok());
+    let mut g_ = UnGraphMap::<Void>::new();
+    assert!(read_mtx(&mut g_, &path).is_ok());
+    assert_eq!(g, g_);
+
+    let g: DiGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_mtx(&g, &path).is_ok());
+    let mut g_ = DiGraphMap::<Void>::new();
+    assert!(read_mtx(&mut g_, &path).is_ok());
+    assert_eq!(g, g_);
+
+    fs::write(
+        &path,
+        "%%MatrixMarket matrix coordinate pattern general\n3 3 2\n1 2\n",
+    ).unwrap();
+    match read_mtx(&mut DiGraphMap::<Void>::new(), &path) {
+        Err(GraphError::InvalidData(_)) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_metis() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.metis");
+
+    // Node weights and edge weights, and a node without neighbors.
+    fs::write(
+        &path,
+        "% A comment\n\
+         4 2 011\n\
+         5 2 7\n\
+         1 1 7 3 8\n\
+         % Another comment\n\
+         2 2 8\n\
+         4\n",
+    ).unwrap();
+
+    let mut g = UnGraphMap::<Void>::new();
+    assert!(read_metis(&mut g, &path).is_ok());
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(sorted_edges(&g), vec![(0, 1), (1, 2)]);
+
+    let mut g = DiGraphMap::<Void>::new();
+    assert!(read_metis(&mut g, &path).is_ok());
+    assert_eq!(sorted_edges(&g), vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+
+    let g: UnGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_metis(&g, &path).is_ok());
+    let mut g_ = UnGraphMap::<Void>::new();
+    assert!(read_metis(&mut g_, &path).is_ok());
+    assert_eq!(g, g_);
+
+    let g: DiGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    match write_metis(&g, &path) {
+        Err(GraphError::InvalidInput(_)) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_snap() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.txt");
+
+    fs::write(
+        &path,
+        "# Directed graph\n# FromNodeId\tToNodeId\n0\t1\n1 2\n\n2\t0\t1234\n",
+    ).unwrap();
+
+    let mut g = DiGraphMap::<Void>::new();
+    assert!(read_snap(&mut g, &path).is_ok());
+    assert_eq!(sorted_edges(&g), vec![(0, 1), (1, 2), (2, 0)]);
+
+    let g: DiGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_snap(&g, &path).is_ok());
+    let mut g_ = DiGraphMap::<Void>::new();
+    assert!(read_snap(&mut g_, &path).is_ok());
+    assert_eq!(sorted_edges(&g), sorted_edges(&g_));
+
+    let g: UnGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_snap(&g, &path).is_ok());
+    let mut g_ = UnGraphMap::<Void>::new();
+    assert!(read_snap(&mut g_, &path).is_ok());
+    assert_eq!(sorted_edges(&g), sorted_edges(&g_));
+
+    fs::write(&path, "0 x\n").unwrap();
+    match read_snap(&mut DiGraphMap::<Void>::new(), &path) {
+        Err(GraphError::InvalidData(msg)) => assert!(msg.contains("Line 1")),
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn test_graphml() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.graphml");
+
+    let mut g = DiGraphMap::<String>::new();
+    g.add_node(0, Some("<a & \"b\">".to_owned()));
+    g.add_node(1, None);
+    g.add_node(5, Some("c".to_owned()));
+    g.add_edge(0, 1, Some("x".to_owned()));
+    g.add_edge(1, 5, None);
+    g.add_edge(5, 5, Some("y".to_owned()));
+
+    assert!(write_graphml(&g, &path).is_ok());
+    let g_: DiGraphMap<String> = read_graphml(&path).unwrap();
+    assert_eq!(g, g_);
+
+    match read_graphml::<DefaultId, Undirected, _>(&path) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        other => panic!("Unexpected result {:?}", other),
+    }
+
+    // Unlabeled graphs are written without data.
+    let g: UnGraphMap<Void> = random_gnm_graph_unlabeled(20, 50);
+    assert!(write_graphml(&g, &path).is_ok());
+    let g_: UnGraphMap<String> = read_graphml(&path).unwrap();
+    assert_eq!(g_.node_count(), 20);
+    assert_eq!(sorted_edges(&g), sorted_edges(&g_));
+    assert!(!g_.has_node_labels());
+
+    fs::write(
+        &path,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- A comment -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="name" for="all" attr.name="label" attr.type="string"/>
+  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="alice"><data key="name">Alice</data></node>
+    <node id='bob'/>
+    <edge source="alice" target="bob">
+      <data key="weight">1.5</data>
+      <data key="name"><![CDATA[knows & likes]]></data>
+    </edge>
+    <edge source="bob" target="carol"/>
+  </graph>
+</graphml>
+"#,
+    ).unwrap();
+
+    let (g, id_map): (UnGraphMap<String>, _) = read_graphml_with_id_map(&path).unwrap();
+    assert_eq!(id_map.items_vec(), vec!["alice", "bob", "carol"]);
+    assert_eq!(g.get_node_label(0), Some(&"Alice".to_owned()));
+    assert_eq!(g.get_node_label(1), None);
+    assert_eq!(g.get_edge_label(1, 0), Some(&"knows & likes".to_owned()));
+    assert_eq!(sorted_edges(&g), vec![(0, 1), (1, 2)]);
+
+    // A self-closed label is empty, and does not take the text of the data after it.
+    fs::write(
+        &path,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="name" for="all" attr.name="label" attr.type="string"/>
+  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="alice"><data key="name"/></node>
+    <node id="bob"/>
+    <edge source="alice" target="bob">
+      <data key="name"/>
+      <data key="weight">1.5</data>
+    </edge>
+  </graph>
+</graphml>
+"#,
+    ).unwrap();
+
+    let g: UnGraphMap<String> = read_graphml(&path).unwrap();
+    assert_eq!(g.get_node_label(0), Some(&"".to_owned()));
+    assert_eq!(g.get_node_label(1), None);
+    assert_eq!(g.get_edge_label(0, 1), Some(&"".to_owned()));
+}
+
+#[test]
+fn test_gexf() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("graph.gexf");
+
+    let mut g = UnGraphMap::<String, u32>::new();
+    g.add_node(0, Some("a&b".to_owned()));
+    g.add_node(1, None);
+    g.add_edge(0, 1, Some(7));
+
+    assert!(write_gexf(&g, &path).is_ok());
+    let gexf = fs::read_to_string(&path).unwrap();
+    assert!(gexf.contains(r#"<graph defaultedgetype="undirected">"#));
+    assert!(gexf.contains(r#"<node id="0" label="a&amp;b"/>"#));
+    assert!(gexf.contains(r#"<node id="1"/>"#));
+    assert!(gexf.contains(r#"<edge id="0" source="0" target="1" label="7"/>"#));
+}
+
+#[test]
+fn test_dot() {
+    let mut g = DiGraphMap::<&str>::new();
+    g.add_node(0, Some("a\"b"));
+    g.add_edge(0, 1, Some("x"));
+    g.add_edge(1, 2, None);
+    g.add_edge(2, 0, None);
+
+    assert_eq!(
+        to_dot(&g, &[1, 2]).unwrap(),
+        "digraph {\n    \
+         0 [label=\"0: a\\\"b\"];\n    \
+         1 [label=\"1\", style=filled, fillcolor=gold];\n    \
+         2 [label=\"2\", style=filled, fillcolor=gold];\n    \
+         0 -> 1 [label=\"x\"];\n    \
+         1 -> 2 [color=red, penwidth=2];\n    \
+         2 -> 0;\n\
+         }\n"
+    );
+
+    let mut g = UnGraphMap::<u32>::new();
+    g.add_edge(1, 0, None);
+    let dot = to_dot(&g, &[]).unwrap();
+    assert!(dot.starts_with("graph {"));
+    assert!(dot.contains("    0 -- 1;\n"));
+}
+
+#[cfg(feature = "ldbc")]
+#[test]
+fn test_ldbc_file_name_match() {
+    use rust_graph::io::ldbc::node::Node;
+    use rust_graph::io::ldbc::relation::Relation;
+    use std::path::PathBuf;
+
+    let place = Node::new("place", 0, 3, "place_");
+    let located_in = Relation::new(
+        "organisation",
+        "place",
+        "isLocatedIn",
+        0,
+        1,
+        "organisation_isLocatedIn_place_",
+    );
+
+    let place_file = PathBuf::from("data/place_0_0.csv");
+    let located_in_file = PathBuf::from("data/organisation_isLocatedIn_place_0_0.csv");
+
+    assert!(place.is_match(&place_file));
+    assert!(!place.is_match(&located_in_file));
+    assert!(located_in.is_match(&located_in_file));
+    assert!(!located_in.is_match(&place_file));
+
+    // The extension must be exactly `.csv`.
+    assert!(!place.is_match(&PathBuf::from("place_0_0xcsv")));
+    assert!(!place.is_match(&PathBuf::from("place_0_0.csv.bak")));
+}
+
+#[cfg(feature = "ldbc")]
+#[test]
+fn test_ldbc_config() {
+    use rust_graph::io::ldbc::Scheme;
+    use rust_graph::io::read_ldbc_with_config;
+
+    let tmp_dir = TempDir::new().unwrap();
+    let config = tmp_dir.path().join("schema.conf");
+    let data_dir = tmp_dir.path().join("data");
+    fs::create_dir(&data_dir).unwrap();
+
+    fs::write(
+        &config,
+        "# A tiny schema\n\
+         delimiter ,\n\
+         node person 0 - person_\n\
+         node city 0 1 city_\n\
+         relation person city livesIn 0 1 person_livesIn_city_\n\
+         relation person person knows 0 1 person_knows_person_\n",
+    ).unwrap();
+    fs::write(data_dir.join("person_0_0.csv"), "id,name\n1,a\n2,b\n").unwrap();
+    fs::write(data_dir.join("city_0_0.csv"), "id,name\n10,Sydney\n").unwrap();
+    fs::write(data_dir.join("person_livesIn_city_0_0.csv"), "p,c\n1,10\n").unwrap();
+    fs::write(data_dir.join("person_knows_person_0_0.csv"), "p,q\n1,2\n3,1\n").unwrap();
+
+    let g: UnGraphMap<String> = read_ldbc_with_config(&data_dir, &config).unwrap();
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 3);
+
+    let mut node_labels: Vec<_> = g
+        .node_indices()
+        .map(|n| g.get_node_label(n).unwrap().clone())
+        .collect();
+    node_labels.sort();
+    assert_eq!(node_labels, vec!["Sydney", "person", "person", "person"]);
+
+    let mut edge_labels: Vec<_> = g
+        .edge_indices()
+        .map(|(s, d)| g.get_edge_label(s, d).unwrap().clone())
+        .collect();
+    edge_labels.sort();
+    assert_eq!(edge_labels, vec!["knows", "knows", "livesIn"]);
+
+    // The default LDBC schema is a valid config.
+    Scheme::init();
+
+    let invalid_configs = vec![
+        ("node person 0 - person_\nnode person 0 1 person_\n", "Line 2"),
+        ("delimiter ||\n", "Line 1"),
+        ("relation person city livesIn 0 x person_livesIn_city_\n", "Line 1"),
+        ("edge person city\n", "Line 1"),
+    ];
+    for (invalid_config, line) in invalid_configs {
+        match Scheme::from_config_str(invalid_config) {
+            Err(GraphError::InvalidData(msg)) => assert!(msg.contains(line)),
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    fs::write(&config, "delimiter ,\nnode city 0 2 city_\n").unwrap();
+    match read_ldbc_with_config::<DefaultId, Undirected, _, _>(&data_dir, &config) {
+        Err(GraphError::InvalidData(msg)) => assert!(msg.contains("no column 2")),
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * Copyright (c) 2018 UNSW Sydney, Data and Knowledge Group.
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "parallel")]
+
+extern crate rust_graph;
+extern crate tempfile;
+
+use rust_graph::algorithm::parallel::{
+    count_components, par_bfs, par_conn_comp, par_degree_stats, par_triangle_count,
+};
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use rust_graph::algorithm::{ShortestPath, TriangleCount};
+use rust_graph::graph_gen::random_gnm_graph;
+use rust_graph::graph_impl::static_graph::StaticGraphBuilder;
+use rust_graph::graph_impl::{DiGraphMap, EdgeVec, StaticGraphMmap, UnGraphMap};
+use rust_graph::io::csv::{CSVReader, ErrorPolicy};
+use rust_graph::io::write_to_csv;
+use rust_graph::prelude::*;
+use rust_graph::UnStaticGraph;
+
+use tempfile::TempDir;
+
+fn grid(rows: DefaultId, cols: DefaultId) -> UnGraphMap<Void> {
+    let mut graph = UnGraphMap::<Void>::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let id = r * cols + c;
+            graph.add_node(id, None);
+            if c + 1 < cols {
+                graph.add_edge(id, id + 1, None);
+            }
+            if r + 1 < rows {
+                graph.add_edge(id, id + cols, None);
+            }
+            if c + 1 < cols && r + 1 < rows {
+                graph.add_edge(id, id + cols + 1, None);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_par_degree_stats() {
+    // 0 - 1, 0 - 2, 0 - 3 and an isolated node 4.
+    let edge_vec: EdgeVec<DefaultId> = EdgeVec::new(vec![0, 3, 4, 5, 6, 6], vec![1, 2, 3, 0, 0, 0]);
+    let stats = par_degree_stats(&edge_vec);
+
+    assert_eq!(stats.num_nodes, 5);
+    assert_eq!(stats.num_edges, 6);
+    assert_eq!(stats.min_degree, 0);
+    assert_eq!(stats.max_degree, 3);
+    assert_eq!(stats.avg_degree, 6.0 / 5.0);
+    assert_eq!(stats.distribution, vec![1, 3, 0, 1]);
+}
+
+#[test]
+fn test_par_triangle_count() {
+    let graph = grid(20, 30);
+    let expected = TriangleCount::from_graph_map(&graph);
+    let graph = graph.into_static();
+    let tc = par_triangle_count(graph.get_edge_vec());
+
+    assert_eq!(tc.get_count(), expected.get_count());
+    assert_eq!(tc.get_count(), 2 * 19 * 29);
+    for id in 0..600 {
+        assert_eq!(tc.get_node_count(id), expected.get_node_count(id));
+    }
+    assert!((tc.average_clustering() - expected.average_clustering()).abs() < 1e-9);
+    assert_eq!(tc.global_clustering(), expected.global_clustering());
+}
+
+#[test]
+fn test_par_bfs() {
+    let mut graph = grid(15, 15);
+    graph.add_node(225, None);
+    let sp = ShortestPath::bfs(&graph, 7);
+
+    let graph = graph.into_static();
+    let distances = par_bfs(graph.get_edge_vec(), 7);
+
+    assert_eq!(distances.len(), 226);
+    assert_eq!(distances[225], None);
+    for id in 0..225 {
+        assert_eq!(distances[id as usize], sp.distance(id));
+    }
+}
+
+#[test]
+fn test_par_conn_comp() {
+    let mut graph = UnGraphMap::<Void>::new();
+    for i in 0..10 {
+        graph.add_node(i, None);
+    }
+    graph.add_edge(7, 3, None);
+    graph.add_edge(3, 5, None);
+    graph.add_edge(9, 8, None);
+    graph.add_edge(1, 2, None);
+    graph.add_edge(2, 6, None);
+
+    let graph = graph.into_static();
+    let roots = par_conn_comp(graph.get_edge_vec());
+
+    assert_eq!(roots, vec![0, 1, 1, 3, 4, 3, 1, 3, 8, 8]);
+    assert_eq!(count_components(&roots), 5);
+}
+
+#[test]
+fn test_par_mmap() {
+    let graph: UnStaticGraph<Void> = grid(10, 10).into_static();
+
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("parallel").to_str().unwrap().to_owned();
+    graph.dump_mmap(&prefix).expect("Dump graph error");
+    let graph_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+
+    assert_eq!(
+        par_degree_stats(graph_mmap.get_edge_vec()),
+        par_degree_stats(graph.get_edge_vec())
+    );
+    assert_eq!(
+        par_triangle_count(graph_mmap.get_edge_vec()).get_count(),
+        par_triangle_count(graph.get_edge_vec()).get_count()
+    );
+    assert_eq!(
+        par_bfs(graph_mmap.get_edge_vec(), 0),
+        par_bfs(graph.get_edge_vec(), 0)
+    );
+    assert_eq!(
+        count_components(&par_conn_comp(graph_mmap.get_edge_vec())),
+        1
+    );
+}
+
+#[test]
+fn test_par_compressed() {
+    let graph: UnStaticGraph<Void> = grid(10, 10).into_static();
+    let compressed = graph.clone().compress();
+
+    assert_eq!(
+        par_degree_stats(compressed.get_edge_vec()),
+        par_degree_stats(graph.get_edge_vec())
+    );
+    assert_eq!(
+        par_triangle_count(compressed.get_edge_vec()).get_count(),
+        par_triangle_count(graph.get_edge_vec()).get_count()
+    );
+    assert_eq!(
+        par_bfs(compressed.get_edge_vec(), 0),
+        par_bfs(graph.get_edge_vec(), 0)
+    );
+    assert_eq!(
+        par_conn_comp(compressed.get_edge_vec()),
+        par_conn_comp(graph.get_edge_vec())
+    );
+}
+
+#[test]
+fn test_par_csv_read() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path_to_nodes = tmp_dir.path().join("nodes.csv");
+    let path_to_edges = tmp_dir.path().join("edges.csv");
+
+    let node_labels = vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = vec!["x".to_owned(), "y".to_owned(), "z".to_owned()];
+    let g: DiGraphMap<String> = random_gnm_graph(100, 500, node_labels, edge_labels);
+    write_to_csv(&g, &path_to_nodes, &path_to_edges).unwrap();
+
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(&path_to_edges)
+        .unwrap();
+    file.write_all(b"1,x,y\n3,3,\n0,1,z,extra\n").unwrap();
+
+    let reader =
+        CSVReader::new(Some(&path_to_nodes), &path_to_edges).error_policy(ErrorPolicy::Collect);
+    let mut expected = DiGraphMap::<String>::new();
+    let expected_report = reader.read(&mut expected).unwrap();
+    assert_eq!(expected_report.skipped_rows, 2);
+
+    for &chunk_size in &[1, 7, 64, 1 << 20] {
+        let mut g_ = DiGraphMap::<String>::new();
+        let report = reader
+            .clone()
+            .parallel()
+            .chunk_size(chunk_size)
+            .read(&mut g_)
+            .unwrap();
+
+        assert_eq!(g_, expected);
+        assert_eq!(report, expected_report);
+    }
+}
+
+#[test]
+fn test_par_csv_read_into_builder() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path_to_nodes = tmp_dir.path().join("nodes.csv");
+    let path_to_edges = tmp_dir.path().join("edges.csv");
+
+    let node_labels = vec!["a".to_owned(), "b".to_owned()];
+    let edge_labels = vec!["x".to_owned(), "y".to_owned()];
+    let g: UnGraphMap<String> = random_gnm_graph(50, 200, node_labels, edge_labels);
+    write_to_csv(&g, &path_to_nodes, &path_to_edges).unwrap();
+
+    let reader = CSVReader::<DefaultId, String, String>::new(Some(&path_to_nodes), &path_to_edges);
+
+    let mut builder = StaticGraphBuilder::<DefaultId, String, String, Undirected>::new();
+    builder.add_nodes(reader.node_iter().unwrap());
+    builder.add_edges(reader.edge_iter().unwrap()).unwrap();
+    let expected = builder.build().unwrap();
+
+    let mut builder = StaticGraphBuilder::new();
+    let report = reader
+        .parallel()
+        .chunk_size(100)
+        .read_into_builder(&mut builder)
+        .unwrap();
+    assert_eq!(report.nodes_added, 50);
+    assert_eq!(report.edges_added, 200);
+    assert_eq!(builder.build().unwrap(), expected);
+}
@@ -18,21 +18,32 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+extern crate bincode;
+extern crate rand;
 #[macro_use]
 extern crate rust_graph;
 extern crate tempfile;
 
+use std::fs::{self, OpenOptions};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use tempfile::TempDir;
 
-use rust_graph::generic::DefaultId;
-use rust_graph::graph_impl::static_graph::mmap::EdgeVecMmap;
-use rust_graph::graph_impl::static_graph::EdgeVecTrait;
+use rust_graph::generic::{DefaultId, DefaultWeight, Directed, Undirected};
+use rust_graph::graph_impl::static_graph::mmap::{EdgeVecMmap, MmapManifest};
 use rust_graph::graph_impl::static_graph::StaticNode;
+use rust_graph::graph_impl::static_graph::{
+    AdjacencyTrait, CompressedEdgeVec, EdgeVecTrait, StaticGraphBuilder,
+};
 use rust_graph::graph_impl::Edge;
-use rust_graph::graph_impl::EdgeVec;
+use rust_graph::graph_impl::{EdgeVec, TypedUnStaticGraph};
+use rust_graph::io::serde::Deserializer;
 use rust_graph::map::SetMap;
 use rust_graph::prelude::*;
-use rust_graph::{DiStaticGraph, UnStaticGraph};
+use rust_graph::{
+    DiGraphMap, DiStaticGraph, GraphError, StaticGraphMmap, UnGraphMap, UnStaticGraph,
+};
 
 #[test]
 fn test_directed() {
@@ -183,14 +194,14 @@ fn test_edge_vec_mmap() {
     let edgevec = EdgeVec::<DefaultId>::new(offsets, edges);
     edgevec.dump_mmap(&prefix).expect("Dump edgevec error");
 
-    let edgevec_mmap = EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix);
+    let edgevec_mmap = EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix).expect("Load graph error");
 
     assert_eq!(edgevec.num_nodes(), edgevec_mmap.num_nodes());
     for node in 0..edgevec.num_nodes() as DefaultId {
         assert_eq!(edgevec.neighbors(node), edgevec_mmap.neighbors(node))
     }
     for node in 0..edgevec.num_nodes() as DefaultId {
-        for &nbr in edgevec_mmap.neighbors(node) {
+        for &nbr in edgevec_mmap.neighbors_slice(node) {
             assert!(edgevec_mmap.find_edge_label_id(node, nbr).is_none());
         }
     }
@@ -209,7 +220,7 @@ fn test_edge_vec_mmap_label() {
     let edgevec = EdgeVec::<DefaultId>::with_labels(offsets, edges, labels);
     edgevec.dump_mmap(&prefix).expect("Dump edgevec error");
 
-    let edgevec_mmap = EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix);
+    let edgevec_mmap = EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix).expect("Load graph error");
 
     assert_eq!(edgevec.num_nodes(), edgevec_mmap.num_nodes());
     for node in 0..edgevec.num_nodes() as DefaultId {
@@ -218,7 +229,7 @@ fn test_edge_vec_mmap_label() {
 
     let expected_label = [[0, 0, 4, 3], [0, 0, 1, 0], [4, 1, 0, 2], [3, 0, 2, 0]];
     for node in 0..edgevec_mmap.num_nodes() as DefaultId {
-        for &nbr in edgevec_mmap.neighbors(node) {
+        for &nbr in edgevec_mmap.neighbors_slice(node) {
             assert_eq!(
                 *edgevec_mmap.find_edge_label_id(node, nbr).unwrap(),
                 expected_label[node.id()][nbr.id()]
@@ -226,3 +237,540 @@ fn test_edge_vec_mmap_label() {
         }
     }
 }
+
+#[test]
+fn test_static_graph_mmap_weights() {
+    let offsets = vec![0, 2, 3, 4];
+    let edges = vec![1, 2, 2, 0];
+    let weights = vec![Some(1.0), Some(2.0), None, Some(4.0)];
+
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+    let prefix = tmp_dir_path.join("weighted").to_str().unwrap().to_owned();
+
+    let edge_vec = EdgeVec::<DefaultId>::new(offsets, edges);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 4], vec![2, 0, 0, 1]);
+    let g = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None)
+        .with_edge_weights(weights);
+
+    assert_eq!(g.get_edge_weight(0, 2), Some(2.0));
+    assert_eq!(g.get_edge_weight(1, 2), None);
+
+    g.dump_mmap(&prefix).expect("Dump graph error");
+
+    let g_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+
+    assert!(g_mmap.has_edge_weights());
+    for (start, target) in g.edge_indices() {
+        assert_eq!(
+            g.get_edge_weight(start, target),
+            g_mmap.get_edge_weight(start, target)
+        );
+    }
+    assert_eq!(g_mmap.get_edge_weight(2, 1), None);
+}
+
+#[test]
+fn test_static_graph_mmap_weights_any_value() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir_path = tmp_dir.path();
+    let prefix = tmp_dir_path.join("weighted").to_str().unwrap().to_owned();
+
+    let edge_vec = EdgeVec::<DefaultId>::new(vec![0, 1, 3, 4], vec![1, 0, 2, 1]);
+    let g = TypedUnStaticGraph::<DefaultId, Void, Void, DefaultId, u32>::new(
+        edge_vec, None, None, None,
+    ).with_edge_weights(vec![Some(u32::MAX), Some(u32::MAX), None, None]);
+
+    g.dump_mmap(&prefix).expect("Dump graph error");
+
+    let g_mmap = StaticGraphMmap::<DefaultId, Void, Void, DefaultId, u32>::new(&prefix)
+        .expect("Load graph error");
+
+    assert_eq!(g_mmap.get_edge_weight(0, 1), Some(u32::MAX));
+    assert_eq!(g_mmap.get_edge_weight(1, 2), None);
+    assert!(g_mmap.verify().is_ok());
+}
+
+#[test]
+fn test_mmap_missing_files() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("missing").to_str().unwrap().to_owned();
+
+    match EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix) {
+        Err(GraphError::Io(_)) => {}
+        _ => panic!("Loading missing files should fail"),
+    }
+    assert!(StaticGraphMmap::<DefaultId, Void>::new(&prefix).is_err());
+}
+
+#[test]
+fn test_mmap_truncated_files() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir
+        .path()
+        .join("truncated")
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    let edgevec =
+        EdgeVec::<DefaultId>::new(vec![0, 3, 5, 8, 10], vec![1, 2, 3, 0, 2, 0, 1, 3, 0, 2]);
+    edgevec.dump_mmap(&prefix).expect("Dump edgevec error");
+
+    // The size of the edges file is no longer a multiple of the id size.
+    let edges_file = format!("{}.edges", prefix);
+    let len = fs::metadata(&edges_file).unwrap().len();
+    OpenOptions::new()
+        .write(true)
+        .open(&edges_file)
+        .unwrap()
+        .set_len(len - 1)
+        .unwrap();
+
+    match EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix) {
+        Err(GraphError::InvalidData(_)) => {}
+        _ => panic!("Loading a truncated edges file should fail"),
+    }
+
+    // A whole edge is missing, so the offsets no longer match the edges.
+    let id_len = ::std::mem::size_of::<DefaultId>() as u64;
+    OpenOptions::new()
+        .write(true)
+        .open(&edges_file)
+        .unwrap()
+        .set_len(len - id_len)
+        .unwrap();
+
+    match EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix) {
+        Err(GraphError::InvalidData(_)) => {}
+        _ => panic!("Loading inconsistent offsets and edges should fail"),
+    }
+}
+
+#[test]
+fn test_mmap_decreasing_offsets() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir
+        .path()
+        .join("decreasing")
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    // The first and last offsets are consistent with the edges, but node 1 would end
+    // before it starts.
+    let edgevec = EdgeVec::<DefaultId>::new(vec![0, 3, 1, 4], vec![1, 2, 0, 0]);
+    edgevec.dump_mmap(&prefix).expect("Dump edgevec error");
+
+    match EdgeVecMmap::<DefaultId, DefaultId>::new(&prefix) {
+        Err(GraphError::InvalidData(_)) => {}
+        _ => panic!("Loading decreasing offsets should fail"),
+    }
+}
+
+#[test]
+fn test_static_graph_mmap_edgeless() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("edgeless").to_str().unwrap().to_owned();
+
+    let edge_vec = EdgeVec::new(vec![0, 0, 0, 0], Vec::new());
+    let in_edge_vec = EdgeVec::new(vec![0, 0, 0, 0], Vec::new());
+    let g = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+    g.dump_mmap(&prefix).expect("Dump graph error");
+    assert_eq!(
+        fs::metadata(format!("{}_OUT.edges", prefix)).unwrap().len(),
+        0
+    );
+
+    let g_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+    g_mmap.verify().expect("Verify graph error");
+    assert_eq!(g_mmap.node_count(), 3);
+    assert_eq!(g_mmap.edge_count(), 0);
+    assert_eq!(g_mmap.edge_indices().count(), 0);
+    for n in 0..3 {
+        assert_eq!(g_mmap.degree(n), 0);
+        assert_eq!(g_mmap.in_degree(n), 0);
+        assert!(!g_mmap.has_edge(n, (n + 1) % 3));
+    }
+}
+
+#[test]
+fn test_static_graph_mmap_inconsistent_weights() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir
+        .path()
+        .join("inconsistent")
+        .to_str()
+        .unwrap()
+        .to_owned();
+
+    let edge_vec = EdgeVec::<DefaultId>::new(vec![0, 2, 3, 4], vec![1, 2, 2, 0]);
+    let g = UnStaticGraph::<Void>::new(edge_vec, None, None, None)
+        .with_edge_weights(vec![Some(1.0); 4]);
+    g.dump_mmap(&prefix).expect("Dump graph error");
+
+    assert!(StaticGraphMmap::<DefaultId, Void>::new(&prefix).is_ok());
+
+    let weights_file = format!("{}.weights", prefix);
+    OpenOptions::new()
+        .write(true)
+        .open(&weights_file)
+        .unwrap()
+        .set_len(3 * ::std::mem::size_of::<f64>() as u64)
+        .unwrap();
+
+    match StaticGraphMmap::<DefaultId, Void>::new(&prefix) {
+        Err(GraphError::InvalidData(_)) => {}
+        _ => panic!("Loading inconsistent weights should fail"),
+    }
+}
+
+#[test]
+fn test_static_graph_mmap_manifest() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("manifest").to_str().unwrap().to_owned();
+
+    let edge_vec = EdgeVec::<DefaultId>::new(vec![0, 2, 3, 4], vec![1, 2, 2, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 4], vec![2, 0, 0, 1]);
+    let g = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+    g.dump_mmap(&prefix).expect("Dump graph error");
+
+    let manifest: MmapManifest =
+        Deserializer::import(format!("{}_manifest.bin", prefix)).expect("Load manifest error");
+    assert!(manifest.directed);
+    assert_eq!(manifest.num_nodes, 3);
+    assert_eq!(manifest.num_edges, 4);
+    assert_eq!(manifest.files.len(), 4);
+
+    let g_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+    assert_eq!(g_mmap.node_count(), 3);
+
+    // The dump is opened with an `Id` of another width.
+    match StaticGraphMmap::<u16, Void, Void, DefaultId>::new(&prefix) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        _ => panic!("Loading with a different Id width should fail"),
+    }
+
+    // The dump is opened with an `L` of another width.
+    match StaticGraphMmap::<DefaultId, Void, Void, u16>::new(&prefix) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        _ => panic!("Loading with a different L width should fail"),
+    }
+}
+
+#[test]
+fn test_static_graph_mmap_checksum() {
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("checksum").to_str().unwrap().to_owned();
+
+    let edge_vec = EdgeVec::<DefaultId>::new(vec![0, 2, 3, 4], vec![1, 2, 2, 0]);
+    let in_edge_vec = EdgeVec::new(vec![0, 1, 2, 4], vec![2, 0, 0, 1]);
+    let g = DiStaticGraph::<Void>::new(edge_vec, Some(in_edge_vec), None, None);
+    g.dump_mmap(&prefix).expect("Dump graph error");
+
+    // Flip one byte of the edges, which keeps the length of the file.
+    let edges_file = format!("{}_OUT.edges", prefix);
+    let mut bytes = fs::read(&edges_file).unwrap();
+    bytes[0] ^= 0xff;
+    fs::write(&edges_file, bytes).unwrap();
+
+    // The contents are only checked on demand.
+    let g_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+    match g_mmap.verify() {
+        Err(GraphError::InvalidData(_)) => {}
+        _ => panic!("Verifying a corrupted edges file should fail"),
+    }
+
+    // The in-edges of the directed graph are gone.
+    g.dump_mmap(&prefix).expect("Dump graph error");
+    fs::remove_file(format!("{}_IN.offsets", prefix)).unwrap();
+
+    match StaticGraphMmap::<DefaultId, Void>::new(&prefix) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        _ => panic!("Loading a directed graph without in-edges should fail"),
+    }
+
+    g.dump_mmap(&prefix).expect("Dump graph error");
+    let g_mmap = StaticGraphMmap::<DefaultId, Void>::new(&prefix).expect("Load graph error");
+    assert!(g_mmap.verify().is_ok());
+
+    // A dump without manifest is only opened on request, and can not be verified.
+    fs::remove_file(format!("{}_manifest.bin", prefix)).unwrap();
+    match StaticGraphMmap::<DefaultId, Void>::new(&prefix) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        _ => panic!("Loading a dump without manifest should fail"),
+    }
+    let g_mmap =
+        StaticGraphMmap::<DefaultId, Void>::new_lenient(&prefix).expect("Load graph error");
+    assert_eq!(g_mmap.edge_count(), 4);
+    assert!(g_mmap.verify().is_err());
+}
+
+#[test]
+fn test_compressed_edge_vec() {
+    // The neighbors span small and large gaps, and may be smaller than the node itself.
+    let offsets = vec![0, 3, 3, 6, 8, 9];
+    let edges = vec![1, 300, 70000, 0, 2, 1 << 20, 0, 3, 2];
+    let labels = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let edgevec = EdgeVec::<DefaultId>::with_labels(offsets, edges, labels);
+
+    let compressed = CompressedEdgeVec::from_edge_vec(&edgevec);
+    assert!(compressed.num_bytes() < edgevec.num_edges() * ::std::mem::size_of::<DefaultId>());
+    assert_eq!(compressed.to_edge_vec(), edgevec);
+
+    for node in 0..edgevec.num_nodes() as DefaultId {
+        let decoded: Vec<_> = compressed.decode(node).collect();
+        assert_eq!(&decoded[..], edgevec.neighbors_slice(node));
+    }
+}
+
+#[test]
+fn test_compressed_edge_vec_many_nodes() {
+    // More nodes than the interval of the sampled edge offsets, some without neighbors.
+    let num_nodes = 300;
+    let mut offsets = vec![0];
+    let mut edges = Vec::new();
+    for n in 0..num_nodes {
+        for m in 0..(n % 5) as DefaultId {
+            edges.push((n + m * 7) % num_nodes);
+        }
+        let start = offsets[offsets.len() - 1];
+        edges[start..].sort();
+        offsets.push(edges.len());
+    }
+    let labels = (0..edges.len() as DefaultId).collect();
+    let edgevec = EdgeVec::<DefaultId>::with_labels(offsets, edges, labels);
+    let compressed = CompressedEdgeVec::from_edge_vec(&edgevec);
+
+    assert_eq!(compressed.num_nodes(), edgevec.num_nodes());
+    assert_eq!(compressed.num_edges(), edgevec.num_edges());
+    assert_eq!(compressed.to_edge_vec(), edgevec);
+    for node in 0..num_nodes {
+        assert_eq!(compressed.degree(node), edgevec.degree(node));
+        for nbr in edgevec.neighbors_iter(node) {
+            assert_eq!(
+                compressed.find_edge_index(node, nbr),
+                edgevec.find_edge_index(node, nbr)
+            );
+            assert_eq!(
+                compressed.find_edge_label_id(node, nbr),
+                edgevec.find_edge_label_id(node, nbr)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_compressed_edge_vec_malformed() {
+    let edgevec = EdgeVec::<DefaultId>::new(vec![0, 2, 3], vec![0, 300, 0]);
+    let compressed = CompressedEdgeVec::from_edge_vec(&edgevec);
+
+    // Mark the last encoded byte as continued, so that the varint of the last neighbor
+    // runs past the end of the bytes. The trailing byte is the `None` of the labels.
+    let mut buf = bincode::serialize(&compressed).unwrap();
+    let last = buf.len() - 2;
+    buf[last] |= 0x80;
+    let malformed: CompressedEdgeVec<DefaultId> = bincode::deserialize(&buf).unwrap();
+
+    assert_eq!(malformed.degree(1), 1);
+    assert_eq!(malformed.neighbors_iter(1).count(), 0);
+    assert_eq!(
+        malformed.neighbors_iter(0).collect::<Vec<_>>(),
+        vec![0, 300]
+    );
+    assert!(!malformed.has_edge(1, 0));
+}
+
+#[test]
+#[should_panic]
+fn test_compressed_edge_vec_unsorted() {
+    let edgevec = EdgeVec::<DefaultId>::new(vec![0, 2, 2], vec![1, 0]);
+    CompressedEdgeVec::from_edge_vec(&edgevec);
+}
+
+#[test]
+fn test_compressed_undirected() {
+    let edge_vec = EdgeVec::new(vec![0, 2, 4, 6], vec![1, 2, 0, 2, 0, 1]);
+    let g = UnStaticGraph::<Void>::new(edge_vec, None, None, None).compress();
+    let edges: Vec<_> = g.edge_indices().collect();
+    assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2)]);
+    assert_eq!(g.edge_count(), 3);
+}
+
+#[test]
+fn test_compressed_static_graph() {
+    let edge_vec = EdgeVec::with_labels(vec![0, 2, 3, 4], vec![1, 2, 0, 0], vec![0, 1, 0, 1]);
+    let in_edge_vec = EdgeVec::new(vec![0, 2, 3, 4], vec![1, 2, 0, 0]);
+    let g = DiStaticGraph::<&str>::with_labels(
+        edge_vec,
+        Some(in_edge_vec),
+        vec![1, 0, 1],
+        setmap!["a", "b"],
+        setmap!["a", "b"],
+        None,
+        None,
+    ).with_edge_weights(vec![Some(1.0), Some(2.0), None, Some(4.0)]);
+    let compressed = g.clone().compress();
+
+    assert_eq!(compressed.node_count(), g.node_count());
+    assert_eq!(compressed.edge_count(), g.edge_count());
+    assert_eq!(
+        compressed.edge_indices().collect::<Vec<_>>(),
+        g.edge_indices().collect::<Vec<_>>()
+    );
+    for n in g.node_indices() {
+        assert_eq!(compressed.neighbors(n), g.neighbors(n));
+        assert_eq!(compressed.in_neighbors(n), g.in_neighbors(n));
+        assert_eq!(compressed.degree(n), g.degree(n));
+        assert_eq!(compressed.in_degree(n), g.in_degree(n));
+        assert_eq!(compressed.get_node_label(n), g.get_node_label(n));
+    }
+    for (s, d) in g.edge_indices() {
+        assert!(compressed.has_edge(s, d));
+        assert_eq!(compressed.get_edge_label(s, d), g.get_edge_label(s, d));
+        assert_eq!(compressed.get_edge_weight(s, d), g.get_edge_weight(s, d));
+    }
+    assert!(!compressed.has_edge(1, 2));
+
+    // The compressed graph is dumped in the same layout.
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir
+        .path()
+        .join("compressed")
+        .to_str()
+        .unwrap()
+        .to_owned();
+    compressed.dump_mmap(&prefix).expect("Dump graph error");
+
+    let g_mmap = StaticGraphMmap::<DefaultId, String>::new(&prefix).expect("Load graph error");
+    for n in g.node_indices() {
+        assert_eq!(g_mmap.neighbors(n), g.neighbors(n));
+        assert_eq!(g_mmap.in_neighbors(n), g.in_neighbors(n));
+    }
+    for (s, d) in g.edge_indices() {
+        assert_eq!(g_mmap.get_edge_weight(s, d), g.get_edge_weight(s, d));
+    }
+}
+
+#[test]
+fn test_narrow_offsets() {
+    let mut g = DiGraphMap::<Void>::new();
+    g.add_edge(0, 1, None);
+    g.add_edge(0, 2, None);
+    g.add_edge(2, 1, None);
+    g.add_edge(3, 0, None);
+
+    let narrow = g.clone().into_static_with_offset::<u32>();
+    let g = g.into_static();
+    assert_eq!(narrow.get_edge_vec().get_offsets(), &[0, 2, 2, 3, 4]);
+    assert_eq!(
+        narrow.edge_indices().collect::<Vec<_>>(),
+        g.edge_indices().collect::<Vec<_>>()
+    );
+    for n in g.node_indices() {
+        assert_eq!(narrow.neighbors(n), g.neighbors(n));
+        assert_eq!(narrow.in_neighbors(n), g.in_neighbors(n));
+    }
+    assert_eq!(
+        g.get_edge_vec().clone().into_offset_type::<u32>(),
+        *narrow.get_edge_vec()
+    );
+
+    let tmp_dir = TempDir::new().unwrap();
+    let prefix = tmp_dir.path().join("narrow").to_str().unwrap().to_owned();
+    narrow.dump_mmap(&prefix).expect("Dump graph error");
+
+    let g_mmap =
+        StaticGraphMmap::<DefaultId, Void, Void, DefaultId, DefaultWeight, u32>::new(&prefix)
+            .expect("Load graph error");
+    for n in g.node_indices() {
+        assert_eq!(g_mmap.neighbors(n), g.neighbors(n));
+        assert_eq!(g_mmap.in_neighbors(n), g.in_neighbors(n));
+    }
+
+    // The dump is opened with the default `usize` offsets.
+    match StaticGraphMmap::<DefaultId, Void>::new(&prefix) {
+        Err(GraphError::FormatMismatch(_)) => {}
+        _ => panic!("Loading with a different offset width should fail"),
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_narrow_offsets_overflow() {
+    let edges: Vec<DefaultId> = vec![0; 256];
+    EdgeVec::<DefaultId, DefaultId, u8>::new(vec![0, 0], edges);
+}
+
+#[test]
+fn test_static_graph_builder_directed() {
+    let mut rng = StdRng::from_seed([3; 32]);
+    let tmp_dir = TempDir::new().unwrap();
+
+    let mut expected = DiGraphMap::<String>::new();
+    let mut builder = StaticGraphBuilder::<DefaultId, String, String, Directed>::new()
+        .run_size(7)
+        .spill_dir(tmp_dir.path());
+
+    for n in 0..10 {
+        let label = if n % 3 == 0 {
+            None
+        } else {
+            Some(format!("{}", n % 3))
+        };
+        expected.add_node(n, label.clone());
+        builder.add_node(n, label);
+    }
+
+    // Some edges are duplicated, and some end at nodes that are not added above.
+    for _ in 0..100 {
+        let (s, d) = (rng.gen_range(0, 15), rng.gen_range(0, 15));
+        let label = if s < d {
+            Some(format!("{}", s + d))
+        } else {
+            None
+        };
+        if !expected.has_edge(s, d) {
+            expected.add_edge(s, d, label.clone());
+        }
+        builder.add_edge(s, d, label).unwrap();
+    }
+
+    let expected = expected.into_static();
+    let g = builder.build().unwrap();
+    assert_eq!(fs::read_dir(tmp_dir.path()).unwrap().count(), 0);
+
+    assert_eq!(g.node_count(), expected.node_count());
+    assert_eq!(g.edge_count(), expected.edge_count());
+    for n in expected.node_indices() {
+        assert_eq!(g.neighbors(n), expected.neighbors(n));
+        assert_eq!(g.in_neighbors(n), expected.in_neighbors(n));
+        assert_eq!(g.get_node_label(n), expected.get_node_label(n));
+    }
+    for (s, d) in expected.edge_indices() {
+        assert_eq!(g.get_edge_label(s, d), expected.get_edge_label(s, d));
+    }
+}
+
+#[test]
+fn test_static_graph_builder_undirected() {
+    let mut expected = UnGraphMap::<Void>::new();
+    let mut builder = StaticGraphBuilder::<DefaultId, Void, Void, Undirected>::new().run_size(2);
+
+    let edges = vec![(0, 1), (3, 1), (1, 0), (2, 2), (4, 3), (3, 4)];
+    for &(s, d) in edges.iter() {
+        if !expected.has_edge(s, d) {
+            expected.add_edge(s, d, None);
+        }
+    }
+    builder
+        .add_edges(edges.into_iter().map(|(s, d)| (s, d, None)))
+        .unwrap();
+
+    let expected = expected.into_static();
+    let g = builder.build().unwrap();
+
+    assert_eq!(g.node_count(), 5);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g.get_edge_vec(), expected.get_edge_vec());
+}